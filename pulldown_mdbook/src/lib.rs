@@ -1,6 +1,10 @@
 use pulldown_cmark::CowStr;
 use std::path::PathBuf;
 
+pub use mdbook::book::SectionNumber;
+pub use mdbook::config::TextDirection;
+pub use toml;
+
 pub mod markdown;
 pub mod parser;
 
@@ -22,6 +26,23 @@ pub enum Event<'a> {
     Title(CowStr<'a>),
     /// An author of the book.
     Author(CowStr<'a>),
+    /// The description of the book.
+    Description(CowStr<'a>),
+    /// The main language of the book, e.g. `en` or `pt-BR`.
+    Language(CowStr<'a>),
+    /// The direction of text in the book. Derived from the language if not configured.
+    TextDirection(TextDirection),
+    /// The book supports more than one language.
+    Multilingual,
+    /// Keywords describing the book, from the `keywords` key of the renderer's
+    /// `[output.typst]` table.
+    Keywords(Vec<CowStr<'a>>),
+    /// The date of the book, e.g. `2024-05-01`, from the `date` key of the renderer's
+    /// `[output.typst]` table.
+    Date(CowStr<'a>),
+    /// The renderer's `[output.typst]` table, or the table of the renderer given to the
+    /// parser.
+    RendererConfig(&'a toml::value::Table),
     /// Separators can be added before, in-between, and after any other element.
     Separator,
     /// Parsed markdown content.
@@ -32,19 +53,18 @@ pub enum Event<'a> {
 #[derive(Clone, Debug, PartialEq)]
 pub enum Tag<'a> {
     /// A part is used to logically separate different sections of the book. The first
-    /// field is the title. If the part is ordered the second field indicates the number
-    /// of the first chapter.
+    /// field is the title. If the part contains numbered chapters the second field
+    /// indicates the number of the first one.
     Part(Option<CowStr<'a>>, Option<u64>),
 
     /// A chapter represents book content. The first field indicates the status, the
-    /// second field is the name, and the third field is the source. If the part is
-    /// ordered the fourth field indicates the number of the chapter. Chapters can be
-    /// nested.
+    /// second field is the name, the third field is the source, and the fourth field is
+    /// the kind of chapter. Chapters can be nested.
     Chapter(
         ChapterStatus,
         CowStr<'a>,
         Option<ChapterSource<'a>>,
-        Option<u64>,
+        ChapterKind,
     ),
     /// The content of the chapter.
     Content(ContentType),
@@ -66,6 +86,17 @@ pub enum ChapterStatus {
     Draft,
 }
 
+/// The kind of a chapter, following the structure of mdBook's `SUMMARY.md`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChapterKind {
+    /// An unnumbered chapter before the first numbered chapter.
+    Prefix,
+    /// A numbered chapter. The field is the full section number, e.g. `1.2.`.
+    Numbered(SectionNumber),
+    /// An unnumbered chapter after the numbered chapters.
+    Suffix,
+}
+
 /// The type of content.
 #[derive(Clone, Debug, PartialEq, Copy)]
 pub enum ContentType {
@@ -2,30 +2,88 @@
 use crate::*;
 use core::iter;
 use mdbook::{renderer::RenderContext, BookItem, Config, MDBook};
+use std::collections::VecDeque;
+
+/// The renderer whose `[output.*]` table is read by default.
+pub const DEFAULT_RENDERER: &str = "typst";
 
 #[derive(Default, Debug)]
-enum ConfigState {
+enum ConfigState<'a> {
     #[default]
     Start,
     Title,
     AuthorList,
     Author(usize),
+    Metadata(VecDeque<self::Event<'a>>),
     Done,
 }
 
 /// Parse an mdBook configuration into events.
 #[derive(Debug)]
 pub struct ConfigParser<'a> {
-    state: ConfigState,
+    state: ConfigState<'a>,
     config: &'a Config,
+    renderer: &'a str,
 }
 
 impl<'a> ConfigParser<'a> {
     pub fn new(config: &'a Config) -> Self {
+        Self::with_renderer(config, DEFAULT_RENDERER)
+    }
+
+    /// Read the `[output.<renderer>]` table instead of `[output.typst]`.
+    pub fn with_renderer(config: &'a Config, renderer: &'a str) -> Self {
         Self {
             config,
             state: ConfigState::default(),
+            renderer,
+        }
+    }
+
+    /// Events for the book metadata after the title and authors.
+    fn metadata(&self) -> VecDeque<self::Event<'a>> {
+        let book = &self.config.book;
+        let mut events = VecDeque::new();
+        if let Some(description) = &book.description {
+            events.push_back(Event::Description(description.clone().into()));
+        }
+        if let Some(language) = &book.language {
+            events.push_back(Event::Language(language.clone().into()));
+        }
+        if book.text_direction.is_some() || book.language.is_some() {
+            events.push_back(Event::TextDirection(book.realized_text_direction()));
+        }
+        if book.multilingual {
+            events.push_back(Event::Multilingual);
         }
+        let key = |k| format!("output.{}.{k}", self.renderer);
+        match self
+            .config
+            .get_deserialized_opt::<Vec<String>, _>(key("keywords"))
+        {
+            Ok(Some(keywords)) => {
+                events.push_back(Event::Keywords(
+                    keywords.into_iter().map(CowStr::from).collect(),
+                ));
+            }
+            Ok(None) => {}
+            Err(_e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("ignoring invalid keywords: {}", _e);
+            }
+        }
+        // Dates may be TOML dates or strings.
+        if let Some(date) = self.config.get(&key("date")).and_then(|v| {
+            v.as_str()
+                .map(String::from)
+                .or_else(|| v.as_datetime().map(|d| d.to_string()))
+        }) {
+            events.push_back(Event::Date(date.into()));
+        }
+        if let Some(table) = self.config.get_renderer(self.renderer) {
+            events.push_back(Event::RendererConfig(table));
+        }
+        events
     }
 }
 impl<'a> Iterator for ConfigParser<'a> {
@@ -46,7 +104,7 @@ impl<'a> Iterator for ConfigParser<'a> {
                     self.state = ConfigState::AuthorList;
                     Some(Event::Start(Tag::AuthorList))
                 } else {
-                    self.state = ConfigState::Done;
+                    self.state = ConfigState::Metadata(self.metadata());
                     self.next()
                 }
             }
@@ -62,7 +120,7 @@ impl<'a> Iterator for ConfigParser<'a> {
             }
             ConfigState::Author(index) => {
                 if index >= self.config.book.authors.len() {
-                    self.state = ConfigState::Done;
+                    self.state = ConfigState::Metadata(self.metadata());
                     Some(Event::End(Tag::AuthorList))
                 } else {
                     self.state = ConfigState::Author(index + 1);
@@ -75,6 +133,13 @@ impl<'a> Iterator for ConfigParser<'a> {
                     Some(Event::Author(author.clone().into()))
                 }
             }
+            ConfigState::Metadata(ref mut events) => {
+                let event = events.pop_front();
+                if event.is_none() {
+                    self.state = ConfigState::Done;
+                }
+                event
+            }
             ConfigState::Done => None,
         }
     }
@@ -82,13 +147,10 @@ impl<'a> Iterator for ConfigParser<'a> {
 
 // This is super janky. I have a branch breaking this into iterators but am stuck with
 // lifetimes not living long enough for the embedded markdown events.
-fn events_from_items<'a, 'b>(items: &'a [BookItem]) -> Vec<self::Event<'a>>
-where
-    'a: 'b,
-{
-    let (_, events) = items
+fn chapter_events(items: &[BookItem], options: pulldown_cmark::Options) -> Vec<self::Event<'_>> {
+    let (_, _, events) = items
         .iter()
-        .fold((None, vec![]), |mut acc, item| match item {
+        .fold((None, false, vec![]), |mut acc, item| match item {
             BookItem::Chapter(ch) => {
                 let status = if ch.is_draft_chapter() {
                     ChapterStatus::Draft
@@ -100,41 +162,48 @@ where
                     .source_path
                     .as_ref()
                     .map(|x| ChapterSource::Path(x.to_owned()));
+                // Unnumbered chapters are prefix chapters until the first numbered
+                // chapter has been seen.
+                let kind = match &ch.number {
+                    Some(number) => {
+                        acc.1 = true;
+                        ChapterKind::Numbered(number.clone())
+                    }
+                    None if acc.1 => ChapterKind::Suffix,
+                    None => ChapterKind::Prefix,
+                };
 
                 // Chapter start event.
-                acc.1.push(self::Event::Start(self::Tag::Chapter(
+                acc.2.push(self::Event::Start(self::Tag::Chapter(
                     status,
                     name.clone().into(),
                     source.clone(),
-                    None,
+                    kind.clone(),
                 )));
 
                 // Chapter content events.
                 if !ch.content.is_empty() {
-                    let p = TextMergeStream::new(pulldown_cmark::Parser::new_ext(
-                        &ch.content,
-                        pulldown_cmark::Options::ENABLE_TABLES,
-                    ));
+                    let p =
+                        TextMergeStream::new(pulldown_cmark::Parser::new_ext(&ch.content, options));
                     let p = p.map(Event::MarkdownContentEvent);
-                    acc.1.extend(p);
-                    acc.1.push(Event::End(Tag::Content(ContentType::Markdown)));
+                    acc.2.extend(p);
+                    acc.2.push(Event::End(Tag::Content(ContentType::Markdown)));
                 };
 
                 if !ch.sub_items.is_empty() {
-                    let subevents = events_from_items(&ch.sub_items);
-                    acc.1.extend(subevents);
+                    let subevents = chapter_events(&ch.sub_items, options);
+                    acc.2.extend(subevents);
                 }
 
                 // Chapter end event.
-                acc.1
-                    .push(Event::End(Tag::Chapter(status, name.into(), source, None)));
+                acc.2
+                    .push(Event::End(Tag::Chapter(status, name.into(), source, kind)));
                 acc
             }
             BookItem::Separator => {
-                acc.1.push(self::Event::Separator);
+                acc.2.push(self::Event::Separator);
                 acc
             }
-            // TODO: numbering.
             BookItem::PartTitle(x) => {
                 let ev = if let Some(current_title) = acc.0 {
                     // Close the current part and start a new one.
@@ -149,11 +218,61 @@ where
                         None,
                     ))]
                 };
-                acc.1.extend(ev);
-                (Some(x.clone().into()), acc.1)
+                acc.2.extend(ev);
+                (Some(x.clone().into()), acc.1, acc.2)
             }
         });
+    events
+}
 
+/// Number each part with the top-level number of its first numbered chapter.
+fn number_parts(events: &mut [self::Event<'_>]) {
+    let mut start = None;
+    let mut number = None;
+    let mut depth = 0usize;
+    for i in 0..events.len() {
+        match &events[i] {
+            Event::Start(Tag::Part(_, _)) => {
+                start = Some(i);
+                number = None;
+            }
+            Event::Start(Tag::Chapter(_, _, _, kind)) => {
+                if let (0, None, ChapterKind::Numbered(n)) = (depth, number, kind) {
+                    number = n.first().copied().map(u64::from);
+                }
+                depth += 1;
+            }
+            Event::End(Tag::Chapter(_, _, _, _)) => depth = depth.saturating_sub(1),
+            Event::End(Tag::Part(_, _)) => {
+                for j in start.take().into_iter().chain(iter::once(i)) {
+                    if let Event::Start(Tag::Part(_, n)) | Event::End(Tag::Part(_, n)) =
+                        &mut events[j]
+                    {
+                        *n = number;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The Markdown options for chapter content.
+///
+/// Smart punctuation is left to the renderer so mdBook content is typeset the same as
+/// plain Markdown.
+fn markdown_options() -> pulldown_cmark::Options {
+    // mdBook itself parses heading attributes such as `{#id .class}`.
+    pulldown_cmark::Options::ENABLE_TABLES | pulldown_cmark::Options::ENABLE_HEADING_ATTRIBUTES
+}
+
+fn events_from_items(items: &[BookItem], options: pulldown_cmark::Options) -> Vec<self::Event<'_>> {
+    let mut events = wrap_parts(chapter_events(items, options));
+    number_parts(&mut events);
+    events
+}
+
+fn wrap_parts(events: Vec<self::Event<'_>>) -> Vec<self::Event<'_>> {
     // Post-process to insert `Part` events. The mdbook data model kinda has these,
     // kinda does not. We'll be consistent and make all chapters contained in parts.
     let first_start_pos = events
@@ -195,13 +314,13 @@ where
                 // Synthesize starting part, and end the first part where the second
                 // part starts.
                 let (inside, outside) = events.split_at(first_start);
-                return iter::once(Event::Start(Tag::Part(None, None)))
+                iter::once(Event::Start(Tag::Part(None, None)))
                     .chain(inside.iter().cloned())
                     .chain(iter::once(Event::End(Tag::Part(None, None))))
                     .chain(outside.iter().cloned())
                     // Put our new end on.
                     .chain(iter::once(end))
-                    .collect();
+                    .collect()
             } else {
                 // Just the end.
                 events.iter().cloned().chain(iter::once(end)).collect()
@@ -246,37 +365,44 @@ impl<'a> Parser<'a> {
     /// Create a parser from an `MDBook`. This is available when using `mdbook` as a
     /// library.
     pub fn from_mdbook(book: &'a MDBook) -> Self {
-        let config = ConfigParser::new(&book.config);
-        let events = iter::empty()
-            // Configuration
-            .chain(iter::once(self::Event::Start(self::Tag::BookConfiguration)))
-            .chain(iter::once(self::Event::Root(book.root.to_owned().clone())))
-            .chain(config)
-            .chain(iter::once(self::Event::End(self::Tag::BookConfiguration)))
-            // Content
-            .chain(iter::once(self::Event::Start(self::Tag::BookContent)))
-            .chain(events_from_items(&book.book.sections))
-            .chain(iter::once(self::Event::End(self::Tag::BookContent)))
-            .collect();
-        Self(events)
+        Self::from_mdbook_with_renderer(book, DEFAULT_RENDERER)
+    }
+
+    /// Create a parser from an `MDBook`, reading the `[output.<renderer>]` table.
+    pub fn from_mdbook_with_renderer(book: &'a MDBook, renderer: &'a str) -> Self {
+        Self::from_parts(&book.root, &book.config, &book.book.sections, renderer)
     }
 
     /// Create a parser from a `RenderContext`. This is available when using `mdbook` as
     /// a binary.
     pub fn from_rendercontext(ctx: &'a RenderContext) -> Self {
-        let config = ConfigParser::new(&ctx.config);
+        Self::from_rendercontext_with_renderer(ctx, DEFAULT_RENDERER)
+    }
+
+    /// Create a parser from a `RenderContext`, reading the `[output.<renderer>]` table.
+    pub fn from_rendercontext_with_renderer(ctx: &'a RenderContext, renderer: &'a str) -> Self {
+        Self::from_parts(&ctx.root, &ctx.config, &ctx.book.sections, renderer)
+    }
+
+    fn from_parts(
+        root: &std::path::Path,
+        config: &'a Config,
+        sections: &'a [BookItem],
+        renderer: &'a str,
+    ) -> Self {
+        let options = markdown_options();
+        let config = ConfigParser::with_renderer(config, renderer);
         let events = iter::empty()
             // Configuration
             .chain(iter::once(self::Event::Start(self::Tag::BookConfiguration)))
-            .chain(iter::once(self::Event::Root(ctx.root.clone())))
+            .chain(iter::once(self::Event::Root(root.to_owned())))
             .chain(config)
             .chain(iter::once(self::Event::End(self::Tag::BookConfiguration)))
             // Content
             .chain(iter::once(self::Event::Start(self::Tag::BookContent)))
-            .chain(events_from_items(&ctx.book.sections))
+            .chain(events_from_items(sections, options))
             .chain(iter::once(self::Event::End(self::Tag::BookContent)))
             .collect();
-
         Self(events)
     }
 
@@ -292,3 +418,167 @@ impl<'a> Iterator for Parser<'a> {
         self.0.first().cloned()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook::book::Chapter;
+    use similar_asserts::assert_eq;
+
+    fn chapter(name: &str, number: Option<Vec<u32>>, sub_items: Vec<BookItem>) -> BookItem {
+        let mut ch = Chapter::new_draft(name, vec![]);
+        ch.number = number.map(SectionNumber);
+        ch.sub_items = sub_items;
+        BookItem::Chapter(ch)
+    }
+
+    fn kinds<'a>(events: &[Event<'a>]) -> Vec<(CowStr<'a>, ChapterKind)> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                Event::Start(Tag::Chapter(_, name, _, kind)) => Some((name.clone(), kind.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chapter_kinds() {
+        let items = vec![
+            chapter("Intro", None, vec![]),
+            chapter(
+                "One",
+                Some(vec![1]),
+                vec![chapter("One.One", Some(vec![1, 1]), vec![])],
+            ),
+            chapter("Two", Some(vec![2]), vec![]),
+            chapter("Appendix", None, vec![]),
+        ];
+        let events = events_from_items(&items, pulldown_cmark::Options::ENABLE_TABLES);
+        self::assert_eq!(
+            kinds(&events),
+            vec![
+                ("Intro".into(), ChapterKind::Prefix),
+                ("One".into(), ChapterKind::Numbered(SectionNumber(vec![1]))),
+                (
+                    "One.One".into(),
+                    ChapterKind::Numbered(SectionNumber(vec![1, 1]))
+                ),
+                ("Two".into(), ChapterKind::Numbered(SectionNumber(vec![2]))),
+                ("Appendix".into(), ChapterKind::Suffix),
+            ]
+        );
+        // Nested chapters are not wrapped in parts of their own.
+        self::assert_eq!(
+            events
+                .iter()
+                .filter(|e| matches!(e, Event::Start(Tag::Part(_, _))))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn part_numbers() {
+        let items = vec![
+            BookItem::PartTitle("First".into()),
+            chapter("One", Some(vec![1]), vec![]),
+            BookItem::PartTitle("Second".into()),
+            chapter("Two", Some(vec![2]), vec![]),
+        ];
+        let parts = events_from_items(&items, pulldown_cmark::Options::ENABLE_TABLES)
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    Event::Start(Tag::Part(_, _)) | Event::End(Tag::Part(_, _))
+                )
+            })
+            .collect::<Vec<_>>();
+        self::assert_eq!(
+            parts,
+            vec![
+                Event::Start(Tag::Part(Some("First".into()), Some(1))),
+                Event::End(Tag::Part(Some("First".into()), Some(1))),
+                Event::Start(Tag::Part(Some("Second".into()), Some(2))),
+                Event::End(Tag::Part(Some("Second".into()), Some(2))),
+            ]
+        );
+    }
+
+    #[test]
+    fn config_metadata() {
+        let config: Config = r#"
+            [book]
+            title = "Book"
+            description = "About things"
+            language = "ar"
+            multilingual = true
+
+            [output.typst]
+            keywords = ["rust", "typst"]
+            date = 2024-05-01
+        "#
+        .parse()
+        .expect("valid config");
+        self::assert_eq!(
+            ConfigParser::new(&config).collect::<Vec<_>>(),
+            vec![
+                Event::Title("Book".into()),
+                Event::Description("About things".into()),
+                Event::Language("ar".into()),
+                Event::TextDirection(TextDirection::RightToLeft),
+                Event::Multilingual,
+                Event::Keywords(vec!["rust".into(), "typst".into()]),
+                Event::Date("2024-05-01".into()),
+                Event::RendererConfig(config.get_renderer("typst").expect("table")),
+            ]
+        );
+    }
+
+    #[test]
+    fn smart_punctuation() {
+        let config: Config = "[output.typst]\nsmart-punctuation = \"unicode\""
+            .parse()
+            .expect("valid config");
+        let items = vec![BookItem::Chapter(Chapter::new(
+            "One",
+            "\"Hi\" -- there".into(),
+            "one.md",
+            vec![],
+        ))];
+        let parser = Parser::from_parts(std::path::Path::new("/"), &config, &items, "typst");
+        let text = parser
+            .iter()
+            .filter_map(|e| match e {
+                Event::MarkdownContentEvent(pulldown_cmark::Event::Text(t)) => Some(t.to_string()),
+                _ => None,
+            })
+            .collect::<String>();
+        // Punctuation is smartened by the renderer, not when parsing.
+        self::assert_eq!(text, "\"Hi\" -- there");
+    }
+
+    #[test]
+    fn config_renderer() {
+        let config: Config = r#"
+            [output.typst]
+            keywords = ["typst"]
+
+            [output.pdf]
+            keywords = ["pdf"]
+        "#
+        .parse()
+        .expect("valid config");
+        let keywords = ConfigParser::with_renderer(&config, "pdf")
+            .filter(|e| matches!(e, Event::Keywords(_) | Event::RendererConfig(_)))
+            .collect::<Vec<_>>();
+        self::assert_eq!(
+            keywords,
+            vec![
+                Event::Keywords(vec!["pdf".into()]),
+                Event::RendererConfig(config.get_renderer("pdf").expect("table")),
+            ]
+        );
+    }
+}
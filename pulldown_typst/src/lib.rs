@@ -1,7 +1,7 @@
 use std::num::NonZeroU8;
 pub mod markup;
 // TODO: remove this.
-use pulldown_cmark::CowStr;
+pub use pulldown_cmark::CowStr;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Event<'a> {
@@ -38,6 +38,19 @@ pub enum Event<'a> {
         // stroke
         Option<CowStr<'a>>,
     ),
+    /// A module import. The first field is the module source (a path or package
+    /// spec), the second is the list of imported items. An empty list imports the
+    /// module itself, `*` imports everything.
+    ///
+    /// Imports are document-level and should appear before any content.
+    ///
+    /// See <https://typst.app/docs/reference/scripting/#modules>.
+    Import(CowStr<'a>, Vec<CowStr<'a>>),
+    /// An include of another file. The field is the path to include, relative to
+    /// the including file.
+    ///
+    /// See <https://typst.app/docs/reference/scripting/#modules>.
+    Include(CowStr<'a>),
     /// A let binding. First argument is lhs, second is rhs.
     ///
     /// See <https://typst.app/docs/reference/scripting/#bindings>.
@@ -45,7 +58,7 @@ pub enum Event<'a> {
     /// A function call. The first field is the target variable (without `#`), the
     /// second is the function name, and the third is a list of arguments.
     ///
-    /// If calling `document()`, prefer [`DocumentFunctionCall`].
+    /// If calling `document()`, prefer [`Event::DocumentFunctionCall`].
     // TODO: make this strongly typed.
     FunctionCall(Option<CowStr<'a>>, CowStr<'a>, Vec<CowStr<'a>>),
     /// A `document` function call. The field is the list of arguments.
@@ -58,7 +71,7 @@ pub enum Event<'a> {
     DocumentFunctionCall(Vec<CowStr<'a>>),
     /// A set rule.
     ///
-    /// If setting document metadata, prefer [`DocumentSet`].
+    /// If setting document metadata, prefer [`Event::DocumentSet`].
     ///
     /// See <https://typst.app/docs/reference/styling/#set-rules>.
     // TODO: make this a tag.
@@ -66,7 +79,7 @@ pub enum Event<'a> {
     /// A `document` set rule. The first field is the parameter name, the second is the
     /// parameter value.
     ///
-    /// Prefer this over the more general [`Set`] as document set rules must appear
+    /// Prefer this over the more general [`Event::Set`] as document set rules must appear
     /// before any content.
     ///
     /// See <https://typst.app/docs/reference/meta/document>.
@@ -83,6 +96,13 @@ pub enum Tag<'a> {
     /// A paragraph of text and other inline elements.
     Paragraph,
 
+    /// Content that belongs in its own file. The field is the path of the file,
+    /// relative to the main document.
+    ///
+    /// Markup generation writes the content inline; splitting it out into files and
+    /// [`Event::Include`]s is left to the writer.
+    File(CowStr<'a>),
+
     /// A show rule.
     ///
     /// See <https://typst.app/docs/reference/styling/#show-rules>.
@@ -94,9 +114,9 @@ pub enum Tag<'a> {
     ),
 
     /// A heading. The first field indicates the level of the heading, the second if it
-    /// should be included in outline, and the third if it should be included in
-    /// bookmarks.
-    Heading(NonZeroU8, TableOfContents, Bookmarks),
+    /// should be included in outline, the third if it should be included in bookmarks,
+    /// and the fourth if it should be numbered.
+    Heading(NonZeroU8, TableOfContents, Bookmarks, Numbering),
 
     /// A code block. The first argument is the
     /// fenced value if it exists, the second is how it should be displayed.
@@ -120,6 +140,10 @@ pub enum Tag<'a> {
     ///
     /// See <https://typst.app/docs/reference/model/quote/>.
     Quote(QuoteType, QuoteQuotes, Option<CowStr<'a>>),
+    /// A callout box, such as a note or a warning. The first field is the kind, the
+    /// second is the title. Without a title the name of the kind is used, an empty
+    /// title leaves the title out.
+    Callout(CalloutKind, Option<CowStr<'a>>),
     // Span-level tags
     Emphasis,
     Strong,
@@ -136,6 +160,50 @@ pub enum Tag<'a> {
     TableRow,
     /// A table row. Must come after a #[Tag::TableRow].
     TableCell,
+
+    /// A block-level container.
+    ///
+    /// See <https://typst.app/docs/reference/layout/block/>.
+    Block(Frame<'a>),
+    /// An inline container.
+    ///
+    /// See <https://typst.app/docs/reference/layout/box/>.
+    Box(Frame<'a>),
+    /// A rectangle around its content.
+    ///
+    /// See <https://typst.app/docs/reference/visualize/rect/>.
+    Rect(Frame<'a>),
+    /// A grid. Contains only [`Tag::Child`]s, which fill the cells row by row.
+    ///
+    /// See <https://typst.app/docs/reference/layout/grid/>.
+    Grid(Grid),
+    /// A stack. The first field is the direction, the second is the spacing between
+    /// children. Contains only [`Tag::Child`]s.
+    ///
+    /// See <https://typst.app/docs/reference/layout/stack/>.
+    Stack(Direction, Option<Length>),
+    /// A child of a [`Tag::Grid`] or [`Tag::Stack`].
+    Child,
+    /// Content split into columns. The first field is the number of columns, the
+    /// second is the gutter between them.
+    ///
+    /// See <https://typst.app/docs/reference/layout/columns/>.
+    Columns(NonZeroU8, Option<Length>),
+    /// Aligned content.
+    ///
+    /// See <https://typst.app/docs/reference/layout/align/>.
+    Align(Alignment),
+    /// Padded content. The first field is the horizontal padding, the second is the
+    /// vertical padding.
+    ///
+    /// See <https://typst.app/docs/reference/layout/pad/>.
+    Pad(Option<Length>, Option<Length>),
+    /// Content placed relative to its parent container, without affecting the layout
+    /// of other content. The first field is the alignment, the second and third are
+    /// the horizontal and vertical offsets.
+    ///
+    /// See <https://typst.app/docs/reference/layout/place/>.
+    Place(Alignment, Option<Length>, Option<Length>),
 }
 
 /// How to display a code block.
@@ -159,6 +227,13 @@ pub enum TableOfContents {
     Exclude,
 }
 
+/// Heading numbering. `Include` uses the document's numbering, `Exclude` turns it off.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Numbering {
+    Include,
+    Exclude,
+}
+
 /// The pattern to use whren numbering items.
 ///
 /// See <https://typst.app/docs/reference/meta/numbering/>.
@@ -186,6 +261,187 @@ pub enum LinkType {
     Autolink,
 }
 
+/// The kind of a [`Tag::Callout`], covering GitHub alerts and mdbook-admonish
+/// directives.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Copy)]
+pub enum CalloutKind {
+    Note,
+    Abstract,
+    Info,
+    Tip,
+    Success,
+    Question,
+    Important,
+    Warning,
+    Caution,
+    Failure,
+    Danger,
+    Bug,
+    Example,
+    Quote,
+}
+
+impl CalloutKind {
+    /// The name of the kind, used as the default title.
+    pub fn name(self) -> &'static str {
+        match self {
+            CalloutKind::Note => "Note",
+            CalloutKind::Abstract => "Abstract",
+            CalloutKind::Info => "Info",
+            CalloutKind::Tip => "Tip",
+            CalloutKind::Success => "Success",
+            CalloutKind::Question => "Question",
+            CalloutKind::Important => "Important",
+            CalloutKind::Warning => "Warning",
+            CalloutKind::Caution => "Caution",
+            CalloutKind::Failure => "Failure",
+            CalloutKind::Danger => "Danger",
+            CalloutKind::Bug => "Bug",
+            CalloutKind::Example => "Example",
+            CalloutKind::Quote => "Quote",
+        }
+    }
+}
+
+/// A length, e.g. `12pt`. [`Length::Percent`] is relative to the container.
+///
+/// See <https://typst.app/docs/reference/layout/length/>.
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub enum Length {
+    Pt(f64),
+    Mm(f64),
+    Cm(f64),
+    In(f64),
+    Em(f64),
+    Percent(f64),
+}
+
+impl std::fmt::Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Length::Pt(v) => write!(f, "{v}pt"),
+            Length::Mm(v) => write!(f, "{v}mm"),
+            Length::Cm(v) => write!(f, "{v}cm"),
+            Length::In(v) => write!(f, "{v}in"),
+            Length::Em(v) => write!(f, "{v}em"),
+            Length::Percent(v) => write!(f, "{v}%"),
+        }
+    }
+}
+
+/// The size of a grid column or row.
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub enum Sizing {
+    /// Fit the content.
+    Auto,
+    Length(Length),
+    /// A share of the remaining space, e.g. `1fr`.
+    Fraction(f64),
+}
+
+impl std::fmt::Display for Sizing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Sizing::Auto => write!(f, "auto"),
+            Sizing::Length(length) => write!(f, "{length}"),
+            Sizing::Fraction(v) => write!(f, "{v}fr"),
+        }
+    }
+}
+
+/// Alignment along the horizontal axis.
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub enum HorizontalAlignment {
+    Start,
+    End,
+    Left,
+    Center,
+    Right,
+}
+
+/// Alignment along the vertical axis.
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub enum VerticalAlignment {
+    Top,
+    Horizon,
+    Bottom,
+}
+
+/// Alignment along one or both axes, e.g. `center + horizon`. An alignment without
+/// either axis is `start`.
+///
+/// See <https://typst.app/docs/reference/layout/alignment/>.
+#[derive(Clone, Debug, Default, PartialEq, Copy)]
+pub struct Alignment {
+    pub horizontal: Option<HorizontalAlignment>,
+    pub vertical: Option<VerticalAlignment>,
+}
+
+impl std::fmt::Display for Alignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let horizontal = self.horizontal.map(|h| match h {
+            HorizontalAlignment::Start => "start",
+            HorizontalAlignment::End => "end",
+            HorizontalAlignment::Left => "left",
+            HorizontalAlignment::Center => "center",
+            HorizontalAlignment::Right => "right",
+        });
+        let vertical = self.vertical.map(|v| match v {
+            VerticalAlignment::Top => "top",
+            VerticalAlignment::Horizon => "horizon",
+            VerticalAlignment::Bottom => "bottom",
+        });
+        match (horizontal, vertical) {
+            (Some(h), Some(v)) => write!(f, "{h} + {v}"),
+            (Some(a), None) | (None, Some(a)) => write!(f, "{a}"),
+            (None, None) => write!(f, "start"),
+        }
+    }
+}
+
+/// The direction of a [`Tag::Stack`].
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+    TopToBottom,
+    BottomToTop,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Direction::LeftToRight => "ltr",
+            Direction::RightToLeft => "rtl",
+            Direction::TopToBottom => "ttb",
+            Direction::BottomToTop => "btt",
+        })
+    }
+}
+
+/// The size and appearance of a [`Tag::Block`], [`Tag::Box`], or [`Tag::Rect`]. Fields
+/// left as `None` use Typst's defaults.
+///
+/// The fill and stroke are Typst expressions, e.g. `luma(240)` or `1pt + red`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Frame<'a> {
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub inset: Option<Length>,
+    pub fill: Option<CowStr<'a>>,
+    pub stroke: Option<CowStr<'a>>,
+    pub radius: Option<Length>,
+}
+
+/// The tracks of a [`Tag::Grid`]. Empty columns or rows use Typst's defaults.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Grid {
+    pub columns: Vec<Sizing>,
+    pub rows: Vec<Sizing>,
+    /// The gap between cells.
+    pub gutter: Option<Length>,
+}
+
 /// Type specifier for a quote.
 #[derive(Clone, Debug, PartialEq, Copy)]
 pub enum QuoteType {
@@ -1,5 +1,11 @@
-use crate::{Event, LinkType, QuoteQuotes, QuoteType, ShowType, TableCellAlignment, Tag};
-use std::{collections::VecDeque, fmt::Write, io::ErrorKind};
+use crate::{
+    Bookmarks, CalloutKind, Event, Frame, LinkType, Numbering, QuoteQuotes, QuoteType, ShowType,
+    Sizing, TableCellAlignment, TableOfContents, Tag,
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Write,
+};
 
 fn typst_escape(s: &str) -> String {
     s.replace('$', "\\$")
@@ -12,6 +18,86 @@ fn typst_escape(s: &str) -> String {
         .replace('@', "\\@")
 }
 
+/// A function call with `args`, e.g. `#block(width: 100%)`.
+fn call(name: &str, args: Vec<String>) -> String {
+    format!("#{name}({})", args.join(", "))
+}
+
+/// The arguments for a [`Frame`].
+fn frame_args(frame: &Frame) -> Vec<String> {
+    let mut args = vec![];
+    if let Some(width) = frame.width {
+        args.push(format!("width: {width}"));
+    }
+    if let Some(height) = frame.height {
+        args.push(format!("height: {height}"));
+    }
+    if let Some(inset) = frame.inset {
+        args.push(format!("inset: {inset}"));
+    }
+    if let Some(fill) = &frame.fill {
+        args.push(format!("fill: {fill}"));
+    }
+    if let Some(stroke) = &frame.stroke {
+        args.push(format!("stroke: {stroke}"));
+    }
+    if let Some(radius) = frame.radius {
+        args.push(format!("radius: {radius}"));
+    }
+    args
+}
+
+/// An array of grid track sizes. A single track needs a trailing comma to be an array.
+fn tracks(sizes: &[Sizing]) -> String {
+    match sizes {
+        [size] => format!("({size},)"),
+        sizes => format!(
+            "({})",
+            sizes
+                .iter()
+                .map(Sizing::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// How a [`Tag::Callout`] is drawn.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalloutStyle {
+    /// The accent color as a Typst expression, e.g. `rgb("#0969da")`. The box is
+    /// filled with a lighter shade of it.
+    pub color: String,
+    /// The icon placed before the title.
+    pub icon: String,
+}
+
+impl CalloutStyle {
+    /// The default style for `kind`.
+    pub fn default_for(kind: CalloutKind) -> Self {
+        let (color, icon) = match kind {
+            CalloutKind::Note => ("#0969da", "ℹ"),
+            CalloutKind::Abstract => ("#0097a7", "☰"),
+            CalloutKind::Info => ("#0969da", "ℹ"),
+            CalloutKind::Tip => ("#1a7f37", "★"),
+            CalloutKind::Success => ("#1a7f37", "✓"),
+            CalloutKind::Question => ("#bf8700", "?"),
+            CalloutKind::Important => ("#8250df", "!"),
+            CalloutKind::Warning => ("#9a6700", "⚠"),
+            CalloutKind::Caution => ("#d1242f", "⚠"),
+            CalloutKind::Failure => ("#d1242f", "✗"),
+            CalloutKind::Danger => ("#d1242f", "⚡"),
+            CalloutKind::Bug => ("#cf222e", "✱"),
+            CalloutKind::Example => ("#8250df", "▸"),
+            CalloutKind::Quote => ("#6e7781", "❝"),
+        };
+        Self {
+            color: format!("rgb(\"{color}\")"),
+            icon: icon.to_string(),
+        }
+    }
+}
+
 /// Convert Typst events to Typst markup.
 ///
 /// Note: while each item returned by the iterator is a `String`, items may contain
@@ -20,6 +106,7 @@ fn typst_escape(s: &str) -> String {
 pub struct TypstMarkup<'a, T> {
     tag_queue: VecDeque<Tag<'a>>,
     codeblock_queue: VecDeque<()>,
+    callouts: HashMap<CalloutKind, CalloutStyle>,
     iter: T,
 }
 
@@ -31,9 +118,16 @@ where
         Self {
             tag_queue: VecDeque::new(),
             codeblock_queue: VecDeque::new(),
+            callouts: HashMap::new(),
             iter,
         }
     }
+
+    /// Draw callouts of `kind` with `style` instead of the default.
+    pub fn callout_style(mut self, kind: CalloutKind, style: CalloutStyle) -> Self {
+        self.callouts.insert(kind, style);
+        self
+    }
 }
 
 impl<'a, T> Iterator for TypstMarkup<'a, T>
@@ -55,6 +149,10 @@ where
                                 format!("#show {}: set {}({}:{})", selector, ele, k, v).to_string(),
                             )
                         }
+                        // An everything show rule, e.g. `#show: template.with()`.
+                        ShowType::Function if selector.is_empty() => {
+                            Some(format!("#show: {}", func.as_ref().expect("function body")))
+                        }
                         ShowType::Function => Some(
                             format!(
                                 "#show {}:{}",
@@ -64,7 +162,27 @@ where
                             .to_string(),
                         ),
                     },
-                    Tag::Heading(n, _, _) => Some(format!("{} ", "=".repeat(n.get().into()))),
+                    Tag::Heading(
+                        n,
+                        TableOfContents::Include,
+                        Bookmarks::Include,
+                        Numbering::Include,
+                    ) => Some(format!("{} ", "=".repeat(n.get().into()))),
+                    // Heading markup cannot leave headings out of the outline,
+                    // bookmarks, or numbering, so use the function instead.
+                    Tag::Heading(n, ref toc, ref bookmarks, ref numbering) => {
+                        let mut args = vec![format!("level: {n}")];
+                        if *numbering == Numbering::Exclude {
+                            args.push("numbering: none".to_string());
+                        }
+                        if *toc == TableOfContents::Exclude {
+                            args.push("outlined: false".to_string());
+                        }
+                        if *bookmarks == Bookmarks::Exclude {
+                            args.push("bookmarked: false".to_string());
+                        }
+                        Some(format!("#heading({})[", args.join(", ")))
+                    }
                     // TODO: get the number of backticks / tildes somehow.
                     Tag::CodeBlock(ref fence, ref _display) => {
                         let depth = self.codeblock_queue.len();
@@ -78,6 +196,7 @@ where
                                 .unwrap_or_else(|| "".to_string())
                         ))
                     }
+                    Tag::File(_) => None,
                     Tag::BulletList(_, _) => None,
                     Tag::NumberedList(_, _, _) => None,
                     Tag::Item => {
@@ -96,14 +215,14 @@ where
                         LinkType::Url | LinkType::Autolink => Some(format!("#link(\"{url}\")[")),
                     },
                     Tag::Quote(ref ty, ref quotes, ref attribution) => {
-                        let block = match ty {
-                            &QuoteType::Block => "block: true,",
-                            &QuoteType::Inline => "block: false,",
+                        let block = match *ty {
+                            QuoteType::Block => "block: true,",
+                            QuoteType::Inline => "block: false,",
                         };
-                        let quotes = match quotes {
-                            &QuoteQuotes::DoNotWrapInDoubleQuotes => "quotes: false,",
-                            &QuoteQuotes::WrapInDoubleQuotes => "quotes: true,",
-                            &QuoteQuotes::Auto => "quotes: auto,",
+                        let quotes = match *quotes {
+                            QuoteQuotes::DoNotWrapInDoubleQuotes => "quotes: false,",
+                            QuoteQuotes::WrapInDoubleQuotes => "quotes: true,",
+                            QuoteQuotes::Auto => "quotes: auto,",
                         };
                         match attribution {
                             Some(attribution) => Some(format!(
@@ -113,6 +232,27 @@ where
                             None => Some(format!("#quote({} {})[", block, quotes)),
                         }
                     }
+                    Tag::Callout(kind, ref title) => {
+                        let style = self
+                            .callouts
+                            .get(&kind)
+                            .cloned()
+                            .unwrap_or_else(|| CalloutStyle::default_for(kind));
+                        let color = &style.color;
+                        let mut markup = format!(
+                            "#block(fill: {color}.lighten(90%), stroke: (left: 3pt + {color}), \
+                             inset: 8pt, radius: 2pt, width: 100%)[\n"
+                        );
+                        let title = title.as_deref().unwrap_or(kind.name());
+                        if !title.is_empty() {
+                            markup.push_str(&format!(
+                                "#text(fill: {color}, weight: \"bold\")[{} {}]\n\n",
+                                style.icon,
+                                typst_escape(title)
+                            ));
+                        }
+                        Some(markup)
+                    }
                     Tag::Table(ref alignment) => {
                         let alignments = alignment
                             .iter()
@@ -129,6 +269,61 @@ where
                     Tag::TableRow => Some("#row[\n".to_string()),
                     Tag::TableHead => Some("#row[\n".to_string()),
                     Tag::TableCell => Some("#cell[".to_string()),
+                    Tag::Block(ref frame) => Some(format!("{}[", call("block", frame_args(frame)))),
+                    Tag::Box(ref frame) => Some(format!("{}[", call("box", frame_args(frame)))),
+                    Tag::Rect(ref frame) => Some(format!("{}[", call("rect", frame_args(frame)))),
+                    // Children are passed as trailing content blocks.
+                    Tag::Grid(ref grid) => {
+                        let mut args = vec![];
+                        if !grid.columns.is_empty() {
+                            args.push(format!("columns: {}", tracks(&grid.columns)));
+                        }
+                        if !grid.rows.is_empty() {
+                            args.push(format!("rows: {}", tracks(&grid.rows)));
+                        }
+                        if let Some(gutter) = grid.gutter {
+                            args.push(format!("gutter: {gutter}"));
+                        }
+                        Some(call("grid", args))
+                    }
+                    Tag::Stack(dir, spacing) => {
+                        let mut args = vec![format!("dir: {dir}")];
+                        if let Some(spacing) = spacing {
+                            args.push(format!("spacing: {spacing}"));
+                        }
+                        Some(call("stack", args))
+                    }
+                    Tag::Child => Some("[".to_string()),
+                    Tag::Columns(count, gutter) => {
+                        let mut args = vec![count.to_string()];
+                        if let Some(gutter) = gutter {
+                            args.push(format!("gutter: {gutter}"));
+                        }
+                        Some(format!("{}[", call("columns", args)))
+                    }
+                    Tag::Align(alignment) => {
+                        Some(format!("{}[", call("align", vec![alignment.to_string()])))
+                    }
+                    Tag::Pad(x, y) => {
+                        let mut args = vec![];
+                        if let Some(x) = x {
+                            args.push(format!("x: {x}"));
+                        }
+                        if let Some(y) = y {
+                            args.push(format!("y: {y}"));
+                        }
+                        Some(format!("{}[", call("pad", args)))
+                    }
+                    Tag::Place(alignment, dx, dy) => {
+                        let mut args = vec![alignment.to_string()];
+                        if let Some(dx) = dx {
+                            args.push(format!("dx: {dx}"));
+                        }
+                        if let Some(dy) = dy {
+                            args.push(format!("dy: {dy}"));
+                        }
+                        Some(format!("{}[", call("place", args)))
+                    }
                     _ => todo!(),
                 };
 
@@ -142,7 +337,14 @@ where
             Some(Event::End(x)) => {
                 let ret = match x {
                     Tag::Paragraph => Some("]\n".to_string()),
-                    Tag::Heading(_, _, _) => Some("\n".to_string()),
+                    Tag::File(_) => Some("".to_string()),
+                    Tag::Heading(
+                        _,
+                        TableOfContents::Include,
+                        Bookmarks::Include,
+                        Numbering::Include,
+                    ) => Some("\n".to_string()),
+                    Tag::Heading(..) => Some("]\n".to_string()),
                     Tag::Item => Some("\n".to_string()),
                     Tag::Emphasis => Some("]".to_string()),
                     Tag::Strong => Some("]".to_string()),
@@ -162,10 +364,18 @@ where
                         QuoteType::Inline => "]".to_string(),
                         QuoteType::Block => "]\n".to_string(),
                     }),
+                    Tag::Callout(_, _) => Some("]\n".to_string()),
                     Tag::Table(_) => Some("]\n".to_string()),
                     Tag::TableHead => Some("\n]\n".to_string()),
                     Tag::TableRow => Some("\n]\n".to_string()),
                     Tag::TableCell => Some("]".to_string()),
+                    Tag::Block(_)
+                    | Tag::Columns(_, _)
+                    | Tag::Align(_)
+                    | Tag::Pad(_, _)
+                    | Tag::Place(_, _, _) => Some("]\n".to_string()),
+                    Tag::Box(_) | Tag::Rect(_) | Tag::Child => Some("]".to_string()),
+                    Tag::Grid(_) | Tag::Stack(_, _) => Some("\n".to_string()),
                     _ => todo!(),
                 };
 
@@ -216,6 +426,14 @@ where
 
                 Some(format!("#line({})\n", parts.join(", ")))
             }
+            Some(Event::Import(source, items)) => {
+                if items.is_empty() {
+                    Some(format!("#import \"{source}\"\n"))
+                } else {
+                    Some(format!("#import \"{source}\": {}\n", items.join(", ")))
+                }
+            }
+            Some(Event::Include(path)) => Some(format!("#include \"{path}\"\n")),
             Some(Event::Let(lhs, rhs)) => Some(format!("#let {lhs} = {rhs}\n")),
             Some(Event::FunctionCall(v, f, args)) => {
                 let args = args.join(", ");
@@ -252,8 +470,7 @@ where
     W: Write,
 {
     for e in TypstMarkup::new(iter) {
-        w.write_str(&e)
-            .map_err(|e| std::io::Error::new(ErrorKind::Other, e))?;
+        w.write_str(&e).map_err(std::io::Error::other)?;
     }
     Ok(())
 }
@@ -513,6 +730,275 @@ mod tests {
         }
     }
 
+    mod show {
+        use super::*;
+
+        #[test]
+        fn everything() {
+            let tag = Tag::Show(
+                ShowType::Function,
+                "".into(),
+                None,
+                Some("template.with(title: \"Book\")".into()),
+            );
+            let input = vec![Event::Start(tag.clone()), Event::End(tag)];
+            let output = TypstMarkup::new(input.into_iter()).collect::<String>();
+            let expected = "#show: template.with(title: \"Book\")\n";
+            assert_eq!(&output, &expected);
+        }
+    }
+
+    mod heading {
+        use super::*;
+        use std::num::NonZeroU8;
+
+        fn heading(toc: TableOfContents, bookmarks: Bookmarks, numbering: Numbering) -> String {
+            let tag = Tag::Heading(
+                NonZeroU8::new(2).expect("nonzero"),
+                toc,
+                bookmarks,
+                numbering,
+            );
+            let input = vec![
+                Event::Start(tag.clone()),
+                Event::Text("Hi".into()),
+                Event::End(tag),
+            ];
+            TypstMarkup::new(input.into_iter()).collect::<String>()
+        }
+
+        #[test]
+        fn included() {
+            assert_eq!(
+                heading(
+                    TableOfContents::Include,
+                    Bookmarks::Include,
+                    Numbering::Include
+                ),
+                "== Hi\n"
+            );
+        }
+
+        #[test]
+        fn excluded() {
+            assert_eq!(
+                heading(
+                    TableOfContents::Exclude,
+                    Bookmarks::Include,
+                    Numbering::Include
+                ),
+                "#heading(level: 2, outlined: false)[Hi]\n"
+            );
+            assert_eq!(
+                heading(
+                    TableOfContents::Exclude,
+                    Bookmarks::Exclude,
+                    Numbering::Include
+                ),
+                "#heading(level: 2, outlined: false, bookmarked: false)[Hi]\n"
+            );
+        }
+
+        #[test]
+        fn unnumbered() {
+            assert_eq!(
+                heading(
+                    TableOfContents::Exclude,
+                    Bookmarks::Include,
+                    Numbering::Exclude
+                ),
+                "#heading(level: 2, numbering: none, outlined: false)[Hi]\n"
+            );
+        }
+    }
+
+    mod layout {
+        use super::*;
+        use crate::{Alignment, Direction, Grid, HorizontalAlignment, Length, VerticalAlignment};
+        use std::num::NonZeroU8;
+
+        fn markup(tag: Tag, children: &[&str]) -> String {
+            let mut input = vec![Event::Start(tag.clone())];
+            for child in children {
+                if matches!(tag, Tag::Grid(_) | Tag::Stack(_, _)) {
+                    input.push(Event::Start(Tag::Child));
+                    input.push(Event::Text((*child).into()));
+                    input.push(Event::End(Tag::Child));
+                } else {
+                    input.push(Event::Text((*child).into()));
+                }
+            }
+            input.push(Event::End(tag));
+            TypstMarkup::new(input.into_iter()).collect::<String>()
+        }
+
+        #[test]
+        fn frames() {
+            let frame = Frame {
+                width: Some(Length::Percent(100.0)),
+                inset: Some(Length::Pt(8.0)),
+                fill: Some("luma(240)".into()),
+                radius: Some(Length::Em(0.5)),
+                ..Frame::default()
+            };
+            assert_eq!(
+                markup(Tag::Block(frame.clone()), &["Hi"]),
+                "#block(width: 100%, inset: 8pt, fill: luma(240), radius: 0.5em)[Hi]\n"
+            );
+            assert_eq!(markup(Tag::Box(Frame::default()), &["Hi"]), "#box()[Hi]");
+            assert_eq!(
+                markup(
+                    Tag::Rect(Frame {
+                        stroke: Some("1pt + red".into()),
+                        ..Frame::default()
+                    }),
+                    &["Hi"]
+                ),
+                "#rect(stroke: 1pt + red)[Hi]"
+            );
+        }
+
+        #[test]
+        fn grid_and_stack() {
+            let grid = Grid {
+                columns: vec![Sizing::Fraction(1.0), Sizing::Length(Length::Cm(2.5))],
+                rows: vec![Sizing::Auto],
+                gutter: Some(Length::Mm(3.0)),
+            };
+            assert_eq!(
+                markup(Tag::Grid(grid), &["a", "b"]),
+                "#grid(columns: (1fr, 2.5cm), rows: (auto,), gutter: 3mm)[a][b]\n"
+            );
+            assert_eq!(
+                markup(
+                    Tag::Stack(Direction::LeftToRight, Some(Length::In(1.0))),
+                    &["a", "b"]
+                ),
+                "#stack(dir: ltr, spacing: 1in)[a][b]\n"
+            );
+        }
+
+        #[test]
+        fn positioning() {
+            let corner = Alignment {
+                horizontal: Some(HorizontalAlignment::Right),
+                vertical: Some(VerticalAlignment::Top),
+            };
+            assert_eq!(
+                markup(
+                    Tag::Columns(NonZeroU8::new(2).expect("nonzero"), Some(Length::Em(1.0))),
+                    &["Hi"]
+                ),
+                "#columns(2, gutter: 1em)[Hi]\n"
+            );
+            assert_eq!(
+                markup(
+                    Tag::Align(Alignment {
+                        horizontal: Some(HorizontalAlignment::Center),
+                        vertical: None,
+                    }),
+                    &["Hi"]
+                ),
+                "#align(center)[Hi]\n"
+            );
+            assert_eq!(
+                markup(Tag::Pad(Some(Length::Pt(4.0)), None), &["Hi"]),
+                "#pad(x: 4pt)[Hi]\n"
+            );
+            assert_eq!(
+                markup(Tag::Place(corner, None, Some(Length::Pt(-2.0))), &["Hi"]),
+                "#place(right + top, dy: -2pt)[Hi]\n"
+            );
+        }
+    }
+
+    mod import {
+        use super::*;
+
+        #[test]
+        fn module() {
+            let input = vec![Event::Import("utils.typ".into(), vec![])];
+            let output = TypstMarkup::new(input.into_iter()).collect::<String>();
+            let expected = "#import \"utils.typ\"\n";
+            assert_eq!(&output, &expected);
+        }
+
+        #[test]
+        fn items() {
+            let input = vec![Event::Import(
+                "@preview/example:0.1.0".into(),
+                vec!["add".into(), "sub".into()],
+            )];
+            let output = TypstMarkup::new(input.into_iter()).collect::<String>();
+            let expected = "#import \"@preview/example:0.1.0\": add, sub\n";
+            assert_eq!(&output, &expected);
+        }
+    }
+
+    mod callout {
+        use super::*;
+
+        fn callout(title: Option<&str>) -> Vec<Event<'_>> {
+            let tag = Tag::Callout(CalloutKind::Warning, title.map(Into::into));
+            vec![
+                Event::Start(tag.clone()),
+                Event::Text("Careful".into()),
+                Event::End(tag),
+            ]
+        }
+
+        #[test]
+        fn default_title() {
+            let output = TypstMarkup::new(callout(None).into_iter()).collect::<String>();
+            let expected = "#block(fill: rgb(\"#9a6700\").lighten(90%), stroke: (left: 3pt + rgb(\"#9a6700\")), inset: 8pt, radius: 2pt, width: 100%)[\n#text(fill: rgb(\"#9a6700\"), weight: \"bold\")[⚠ Warning]\n\nCareful]\n";
+            assert_eq!(&output, &expected);
+        }
+
+        #[test]
+        fn no_title() {
+            let output = TypstMarkup::new(callout(Some("")).into_iter()).collect::<String>();
+            let expected = "#block(fill: rgb(\"#9a6700\").lighten(90%), stroke: (left: 3pt + rgb(\"#9a6700\")), inset: 8pt, radius: 2pt, width: 100%)[\nCareful]\n";
+            assert_eq!(&output, &expected);
+        }
+
+        #[test]
+        fn custom_style() {
+            let style = CalloutStyle {
+                color: "red".to_string(),
+                icon: "!!".to_string(),
+            };
+            let output = TypstMarkup::new(callout(Some("Hot")).into_iter())
+                .callout_style(CalloutKind::Warning, style)
+                .collect::<String>();
+            let expected = "#block(fill: red.lighten(90%), stroke: (left: 3pt + red), inset: 8pt, radius: 2pt, width: 100%)[\n#text(fill: red, weight: \"bold\")[!! Hot]\n\nCareful]\n";
+            assert_eq!(&output, &expected);
+        }
+    }
+
+    mod include {
+        use super::*;
+
+        #[test]
+        fn path() {
+            let input = vec![Event::Include("chapters/intro.typ".into())];
+            let output = TypstMarkup::new(input.into_iter()).collect::<String>();
+            let expected = "#include \"chapters/intro.typ\"\n";
+            assert_eq!(&output, &expected);
+        }
+
+        #[test]
+        fn file_is_inline() {
+            let input = vec![
+                Event::Start(Tag::File("intro.typ".into())),
+                Event::Text("Hello".into()),
+                Event::End(Tag::File("intro.typ".into())),
+            ];
+            let output = TypstMarkup::new(input.into_iter()).collect::<String>();
+            let expected = "Hello";
+            assert_eq!(&output, &expected);
+        }
+    }
+
     #[test]
     fn table_conversion() {
         let input = vec![
@@ -0,0 +1,271 @@
+//! Stateful converters with lookahead.
+//!
+//! The [`converter!`](crate::converter) macro covers one-in, one-out conversions.
+//! Anything that needs to look ahead, emit several events for one input, drop events,
+//! or carry state between events implements [`Converter`] and is run over an iterator
+//! with [`ConverterIter`].
+
+use std::collections::VecDeque;
+
+use crate::ParserEvent;
+
+/// Convert between markup events with state and lookahead.
+pub trait Converter<'a> {
+    /// Convert one event.
+    ///
+    /// Push zero or more events to `output`. Upcoming events can be inspected, or
+    /// consumed, through `input`.
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    );
+
+    /// Called once after the last event has been converted. Push any buffered events to
+    /// `output`.
+    fn flush(&mut self, _output: &mut VecDeque<ParserEvent<'a>>) {}
+
+    /// Run this converter over `iter`.
+    fn over<I>(self, iter: I) -> ConverterIter<'a, I, Self>
+    where
+        I: Iterator<Item = ParserEvent<'a>>,
+        Self: Sized,
+    {
+        ConverterIter::with(iter, self)
+    }
+}
+
+/// Upcoming events of the stream being converted.
+///
+/// Events consumed through the [`Iterator`] implementation are not passed to
+/// [`Converter::convert`].
+pub struct Input<'c, 'a> {
+    lookahead: &'c mut VecDeque<ParserEvent<'a>>,
+    iter: &'c mut dyn Iterator<Item = ParserEvent<'a>>,
+}
+
+impl<'a> Input<'_, 'a> {
+    /// Look at the next event without consuming it.
+    pub fn peek(&mut self) -> Option<&ParserEvent<'a>> {
+        self.peek_nth(0)
+    }
+
+    /// Look at the event `n` positions ahead without consuming it.
+    pub fn peek_nth(&mut self, n: usize) -> Option<&ParserEvent<'a>> {
+        while self.lookahead.len() <= n {
+            let event = self.iter.next()?;
+            self.lookahead.push_back(event);
+        }
+        self.lookahead.get(n)
+    }
+}
+
+impl<'a> Iterator for Input<'_, 'a> {
+    type Item = ParserEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lookahead.pop_front().or_else(|| self.iter.next())
+    }
+}
+
+/// Run a [`Converter`] over a [`ParserEvent`] iterator.
+#[derive(Debug, Clone)]
+pub struct ConverterIter<'a, I, C> {
+    converter: C,
+    lookahead: VecDeque<ParserEvent<'a>>,
+    output: VecDeque<ParserEvent<'a>>,
+    done: bool,
+    iter: I,
+}
+
+impl<'a, I, C> ConverterIter<'a, I, C>
+where
+    I: Iterator<Item = ParserEvent<'a>>,
+    C: Converter<'a>,
+{
+    /// Run a default-constructed converter over `iter`.
+    pub fn new(iter: I) -> Self
+    where
+        C: Default,
+    {
+        Self::with(iter, C::default())
+    }
+
+    /// Run `converter` over `iter`.
+    pub fn with(iter: I, converter: C) -> Self {
+        Self {
+            converter,
+            lookahead: VecDeque::new(),
+            output: VecDeque::new(),
+            done: false,
+            iter,
+        }
+    }
+}
+
+impl<'a, I, C> Iterator for ConverterIter<'a, I, C>
+where
+    I: Iterator<Item = ParserEvent<'a>>,
+    C: Converter<'a>,
+{
+    type Item = ParserEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Loop rather than recurse so long runs of dropped events cannot overflow the
+        // stack.
+        loop {
+            if let Some(event) = self.output.pop_front() {
+                return Some(event);
+            }
+            if self.done {
+                return None;
+            }
+            match self.lookahead.pop_front().or_else(|| self.iter.next()) {
+                Some(event) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::trace!("converting: {:?}", event);
+
+                    let mut input = Input {
+                        lookahead: &mut self.lookahead,
+                        iter: &mut self.iter,
+                    };
+                    self.converter.convert(event, &mut input, &mut self.output);
+                }
+                None => {
+                    self.converter.flush(&mut self.output);
+                    self.done = true;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "typst"))]
+mod tests {
+    use super::*;
+    use crate::typst;
+    use crate::typst::TypstIter;
+    use similar_asserts::assert_eq;
+
+    use typst::Event as TypstEvent;
+    use ParserEvent::*;
+
+    /// Join adjacent text events and drop empty ones.
+    #[derive(Default)]
+    struct JoinText;
+
+    impl<'a> Converter<'a> for JoinText {
+        fn convert(
+            &mut self,
+            event: ParserEvent<'a>,
+            input: &mut Input<'_, 'a>,
+            output: &mut VecDeque<ParserEvent<'a>>,
+        ) {
+            match event {
+                Typst(TypstEvent::Text(t)) => {
+                    let mut text = t.into_string();
+                    while let Some(Typst(TypstEvent::Text(_))) = input.peek() {
+                        if let Some(Typst(TypstEvent::Text(next))) = input.next() {
+                            text.push_str(&next);
+                        }
+                    }
+                    if !text.is_empty() {
+                        output.push_back(Typst(TypstEvent::Text(text.into())));
+                    }
+                }
+                x => output.push_back(x),
+            }
+        }
+    }
+
+    /// Count events and emit the count at the end.
+    #[derive(Default)]
+    struct Count(usize);
+
+    impl<'a> Converter<'a> for Count {
+        fn convert(
+            &mut self,
+            event: ParserEvent<'a>,
+            _input: &mut Input<'_, 'a>,
+            output: &mut VecDeque<ParserEvent<'a>>,
+        ) {
+            self.0 += 1;
+            output.push_back(event);
+        }
+
+        fn flush(&mut self, output: &mut VecDeque<ParserEvent<'a>>) {
+            output.push_back(Typst(TypstEvent::Text(self.0.to_string().into())));
+        }
+    }
+
+    #[test]
+    fn lookahead() {
+        let events = vec![
+            TypstEvent::Text("a".into()),
+            TypstEvent::Text("b".into()),
+            TypstEvent::Parbreak,
+            TypstEvent::Text("".into()),
+        ];
+        let i = ConverterIter::<_, JoinText>::new(TypstIter(events.into_iter()));
+        self::assert_eq!(
+            i.collect::<Vec<_>>(),
+            vec![
+                Typst(TypstEvent::Text("ab".into())),
+                Typst(TypstEvent::Parbreak)
+            ]
+        );
+    }
+
+    #[test]
+    fn peek_nth() {
+        let events = vec![
+            TypstEvent::Text("a".into()),
+            TypstEvent::Parbreak,
+            TypstEvent::Linebreak,
+        ];
+        let mut iter = TypstIter(events.into_iter());
+        let mut lookahead = VecDeque::new();
+        let mut input = Input {
+            lookahead: &mut lookahead,
+            iter: &mut iter,
+        };
+        self::assert_eq!(input.peek_nth(2), Some(&Typst(TypstEvent::Linebreak)));
+        self::assert_eq!(input.peek_nth(3), None);
+        self::assert_eq!(input.next(), Some(Typst(TypstEvent::Text("a".into()))));
+        self::assert_eq!(input.peek(), Some(&Typst(TypstEvent::Parbreak)));
+    }
+
+    #[test]
+    fn flush() {
+        let events = vec![TypstEvent::Parbreak, TypstEvent::Parbreak];
+        let i = Count::default().over(TypstIter(events.into_iter()));
+        self::assert_eq!(
+            i.collect::<Vec<_>>(),
+            vec![
+                Typst(TypstEvent::Parbreak),
+                Typst(TypstEvent::Parbreak),
+                Typst(TypstEvent::Text("2".into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn many_dropped_events() {
+        struct DropAll;
+
+        impl<'a> Converter<'a> for DropAll {
+            fn convert(
+                &mut self,
+                _event: ParserEvent<'a>,
+                _input: &mut Input<'_, 'a>,
+                _output: &mut VecDeque<ParserEvent<'a>>,
+            ) {
+            }
+        }
+
+        let events = core::iter::repeat_n(TypstEvent::Parbreak, 1_000_000);
+        let i = DropAll.over(TypstIter(events));
+        self::assert_eq!(i.count(), 0);
+    }
+}
@@ -0,0 +1,196 @@
+//! Extension traits for building conversion pipelines.
+//!
+//! Instead of nesting constructors, pipelines can be written top to bottom:
+//!
+//! ```
+//! # #[cfg(all(feature = "markdown", feature = "typst"))]
+//! # {
+//! use pullup::markdown::Parser;
+//! use pullup::{IntoParserEvents, ParserEventExt};
+//!
+//! let markup: String = Parser::new("Hello *world*")
+//!     .into_parser_events()
+//!     .strip_html()
+//!     .markdown_to_typst()
+//!     .to_typst_markup()
+//!     .collect();
+//! assert_eq!(markup, "#par()[Hello #emph[world]]\n");
+//! # }
+//! ```
+
+use crate::convert::{Converter, ConverterIter};
+#[cfg(feature = "markdown")]
+use crate::markdown;
+#[cfg(feature = "mdbook")]
+use crate::mdbook;
+#[cfg(feature = "typst")]
+use crate::typst;
+use crate::ParserEvent;
+
+/// Chainable adaptors for [`ParserEvent`] iterators.
+pub trait ParserEventExt<'a>: Iterator<Item = ParserEvent<'a>> + Sized {
+    /// Apply an iterator adaptor, such as a converter's `new` function.
+    fn then<F, J>(self, f: F) -> J
+    where
+        F: FnOnce(Self) -> J,
+        J: Iterator<Item = ParserEvent<'a>>,
+    {
+        f(self)
+    }
+
+    /// Run a [`Converter`] over the events.
+    fn convert<C>(self, converter: C) -> ConverterIter<'a, Self, C>
+    where
+        C: Converter<'a>,
+    {
+        ConverterIter::with(self, converter)
+    }
+
+    /// Strip out Markdown HTML. See [`StripHtml`](markdown::strip::StripHtml).
+    #[cfg(feature = "markdown")]
+    fn strip_html(self) -> markdown::strip::StripHtml<'a, Self> {
+        markdown::strip::StripHtml::new(self)
+    }
+
+    /// Only keep Markdown events. See [`MarkdownFilter`](markdown::MarkdownFilter).
+    #[cfg(feature = "markdown")]
+    fn markdown_only(self) -> markdown::MarkdownFilter<Self> {
+        markdown::MarkdownFilter(self)
+    }
+
+    /// Panic on non-Markdown events. See [`AssertMarkdown`](markdown::AssertMarkdown).
+    #[cfg(feature = "markdown")]
+    fn assert_markdown(self) -> markdown::AssertMarkdown<Self> {
+        markdown::AssertMarkdown(self)
+    }
+
+    /// Only keep mdBook events. See [`MdbookFilter`](mdbook::MdbookFilter).
+    #[cfg(feature = "mdbook")]
+    fn mdbook_only(self) -> mdbook::MdbookFilter<Self> {
+        mdbook::MdbookFilter(self)
+    }
+
+    /// Panic on non-mdBook events. See [`AssertMdbook`](mdbook::AssertMdbook).
+    #[cfg(feature = "mdbook")]
+    fn assert_mdbook(self) -> mdbook::AssertMdbook<Self> {
+        mdbook::AssertMdbook(self)
+    }
+
+    /// Only keep Typst events. See [`TypstFilter`](typst::TypstFilter).
+    #[cfg(feature = "typst")]
+    fn typst_only(self) -> typst::TypstFilter<Self> {
+        typst::TypstFilter(self)
+    }
+
+    /// Panic on non-Typst events. See [`AssertTypst`](typst::AssertTypst).
+    #[cfg(feature = "typst")]
+    fn assert_typst(self) -> typst::AssertTypst<Self> {
+        typst::AssertTypst(self)
+    }
+
+    /// Convert Markdown events to Typst events with all converters in
+    /// [`markdown::to::typst`], in the documented order.
+    #[cfg(all(feature = "markdown", feature = "typst"))]
+    fn markdown_to_typst(self) -> impl Iterator<Item = ParserEvent<'a>> {
+        markdown::to::typst::convert_all(self)
+    }
+
+    /// Generate Typst markup. Panics on non-Typst events.
+    #[cfg(feature = "typst")]
+    #[allow(clippy::wrong_self_convention)]
+    fn to_typst_markup(self) -> typst::to::markup::TypstMarkup<'a, typst::AssertTypst<Self>> {
+        typst::to::markup::TypstMarkup::new(typst::AssertTypst(self))
+    }
+}
+
+impl<'a, T> ParserEventExt<'a> for T where T: Iterator<Item = ParserEvent<'a>> {}
+
+/// Load format-specific events into [`ParserEvent`]s.
+///
+/// The type parameter is the format-specific event type, so each format's iterators get
+/// their own implementation.
+pub trait IntoParserEvents<E>: Iterator<Item = E> + Sized {
+    /// The resulting [`ParserEvent`] iterator.
+    type IntoIter;
+
+    /// Wrap each event in a [`ParserEvent`].
+    fn into_parser_events(self) -> Self::IntoIter;
+}
+
+#[cfg(feature = "markdown")]
+impl<'a, T> IntoParserEvents<markdown::Event<'a>> for T
+where
+    T: Iterator<Item = markdown::Event<'a>>,
+{
+    type IntoIter = markdown::MarkdownIter<T>;
+
+    fn into_parser_events(self) -> Self::IntoIter {
+        markdown::MarkdownIter(self)
+    }
+}
+
+#[cfg(feature = "mdbook")]
+impl<'a, T> IntoParserEvents<mdbook::Event<'a>> for T
+where
+    T: Iterator<Item = mdbook::Event<'a>>,
+{
+    type IntoIter = mdbook::MdbookIter<T>;
+
+    fn into_parser_events(self) -> Self::IntoIter {
+        mdbook::MdbookIter(self)
+    }
+}
+
+#[cfg(feature = "typst")]
+impl<'a, T> IntoParserEvents<typst::Event<'a>> for T
+where
+    T: Iterator<Item = typst::Event<'a>>,
+{
+    type IntoIter = typst::TypstIter<T>;
+
+    fn into_parser_events(self) -> Self::IntoIter {
+        typst::TypstIter(self)
+    }
+}
+
+#[cfg(all(test, feature = "markdown", feature = "typst"))]
+mod tests {
+    use super::*;
+    use crate::markdown::to::typst::ConvertLinks;
+    use crate::markdown::Parser;
+    use similar_asserts::assert_eq;
+
+    #[test]
+    fn pipeline() {
+        let md = "\
+# Title
+
+<b>bold</b> [link](https://example.com)
+";
+        let markup: String = Parser::new(md)
+            .into_parser_events()
+            .strip_html()
+            .then(ConvertLinks::new)
+            .markdown_to_typst()
+            .to_typst_markup()
+            .collect();
+        self::assert_eq!(
+            markup,
+            "= Title\n#par()[bold #link(\"https://example.com\")[link]]\n"
+        );
+    }
+
+    #[test]
+    fn typst_round_trip() {
+        let events = vec![typst::Event::Parbreak, typst::Event::Linebreak];
+        self::assert_eq!(
+            events
+                .clone()
+                .into_iter()
+                .into_parser_events()
+                .assert_typst()
+                .collect::<Vec<_>>(),
+            events
+        );
+    }
+}
@@ -0,0 +1,357 @@
+//! Shift heading levels, e.g. to nest a document under a chapter heading.
+
+use std::collections::VecDeque;
+#[cfg(feature = "typst")]
+use std::num::NonZeroU8;
+
+use crate::convert::{Converter, ConverterIter, Input};
+use crate::ParserEvent;
+
+#[cfg(feature = "markdown")]
+use crate::markdown;
+#[cfg(all(feature = "markdown", feature = "typst"))]
+use crate::markdown::to::typst::HeadingConverter;
+#[cfg(feature = "mdbook")]
+use crate::mdbook;
+#[cfg(feature = "typst")]
+use crate::typst;
+
+/// The deepest Markdown heading level.
+const DEEPEST: usize = 6;
+
+/// What [`HeadingShiftConverter`] does with headings shifted deeper than level 6, the
+/// deepest Markdown heading.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Clamp them to level 6.
+    #[default]
+    Clamp,
+    /// Keep their level as Typst headings, which can nest arbitrarily deep. Markdown
+    /// headings are converted to Typst headings, or clamped without the `typst`
+    /// feature.
+    Typst,
+    /// Demote them to a paragraph of strong text.
+    Strong,
+}
+
+/// Shift Markdown, mdBook, and Typst headings by an offset.
+///
+/// Headings are never shifted above level 1. Markdown headings converted to Typst by
+/// [`Overflow::Typst`] keep their `{.unnumbered}` and `{.unlisted}` classes, mapped
+/// like [`HeadingConverter`] does.
+#[derive(Debug, Default, Clone)]
+pub struct HeadingShiftConverter {
+    offset: isize,
+    overflow: Overflow,
+    #[cfg(all(feature = "markdown", feature = "typst"))]
+    classes: HeadingConverter,
+}
+
+impl HeadingShiftConverter {
+    /// Shift headings down by `offset` levels, or up if it is negative.
+    pub fn new(offset: isize) -> Self {
+        Self {
+            offset,
+            overflow: Overflow::default(),
+            #[cfg(all(feature = "markdown", feature = "typst"))]
+            classes: HeadingConverter::default(),
+        }
+    }
+
+    /// Handle headings shifted past level 6 with `overflow` instead of clamping them.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Map the classes of Markdown headings converted to Typst with `classes`, e.g. to
+    /// use other class names.
+    #[cfg(all(feature = "markdown", feature = "typst"))]
+    pub fn classes(mut self, classes: HeadingConverter) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    /// Change the offset for the following headings, e.g. when entering a nested
+    /// section.
+    pub fn set_offset(&mut self, offset: isize) {
+        self.offset = offset;
+    }
+
+    /// The shifted `level`, or `None` if the heading is demoted to strong text.
+    fn shift(&self, level: usize) -> Option<usize> {
+        let level = level.saturating_add_signed(self.offset).max(1);
+        match self.overflow {
+            _ if level <= DEEPEST => Some(level),
+            Overflow::Clamp => Some(DEEPEST),
+            Overflow::Typst => Some(level.min(u8::MAX.into())),
+            Overflow::Strong => None,
+        }
+    }
+
+    /// Shift the start or end of a Markdown heading, wrapping Markdown output with
+    /// `wrap`.
+    #[cfg(feature = "markdown")]
+    fn markdown<'a>(
+        &self,
+        start: bool,
+        (level, id, classes): (markdown::HeadingLevel, Option<&'a str>, Vec<&'a str>),
+        wrap: fn(markdown::Event<'a>) -> ParserEvent<'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        let event = |tag| match start {
+            true => markdown::Event::Start(tag),
+            false => markdown::Event::End(tag),
+        };
+        match self.shift(level as usize) {
+            Some(level) if level <= DEEPEST => {
+                let level = markdown::HeadingLevel::try_from(level).expect("valid heading level");
+                output.push_back(wrap(event(markdown::Tag::Heading(level, id, classes))));
+            }
+            #[cfg(feature = "typst")]
+            Some(level) => {
+                let tag = self.classes.heading(typst_level(level), &classes);
+                output.push_back(ParserEvent::Typst(match start {
+                    true => typst::Event::Start(tag),
+                    false => typst::Event::End(tag),
+                }));
+            }
+            #[cfg(not(feature = "typst"))]
+            Some(_) => output.push_back(wrap(event(markdown::Tag::Heading(
+                markdown::HeadingLevel::H6,
+                id,
+                classes,
+            )))),
+            None => {
+                let mut tags = [markdown::Tag::Paragraph, markdown::Tag::Strong];
+                if !start {
+                    tags.reverse();
+                }
+                output.extend(tags.map(|tag| wrap(event(tag))));
+            }
+        }
+    }
+
+    /// Shift the start or end of a Typst heading.
+    #[cfg(feature = "typst")]
+    fn typst<'a>(
+        &self,
+        start: bool,
+        (level, toc, bookmarks, numbering): (
+            NonZeroU8,
+            typst::TableOfContents,
+            typst::Bookmarks,
+            typst::Numbering,
+        ),
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        let event = |tag| {
+            ParserEvent::Typst(match start {
+                true => typst::Event::Start(tag),
+                false => typst::Event::End(tag),
+            })
+        };
+        match self.shift(level.get().into()) {
+            Some(level) => {
+                output.push_back(event(typst::Tag::Heading(
+                    typst_level(level),
+                    toc,
+                    bookmarks,
+                    numbering,
+                )));
+            }
+            None => {
+                let mut tags = [typst::Tag::Paragraph, typst::Tag::Strong];
+                if !start {
+                    tags.reverse();
+                }
+                output.extend(tags.map(event));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "typst")]
+fn typst_level(level: usize) -> NonZeroU8 {
+    u8::try_from(level)
+        .ok()
+        .and_then(NonZeroU8::new)
+        .expect("valid heading level")
+}
+
+impl<'a> Converter<'a> for HeadingShiftConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
+            #[cfg(feature = "markdown")]
+            ParserEvent::Markdown(markdown::Event::Start(markdown::Tag::Heading(l, i, c))) => {
+                self.markdown(true, (l, i, c), ParserEvent::Markdown, output);
+            }
+            #[cfg(feature = "markdown")]
+            ParserEvent::Markdown(markdown::Event::End(markdown::Tag::Heading(l, i, c))) => {
+                self.markdown(false, (l, i, c), ParserEvent::Markdown, output);
+            }
+            #[cfg(feature = "mdbook")]
+            ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(markdown::Event::Start(
+                markdown::Tag::Heading(l, i, c),
+            ))) => {
+                self.markdown(true, (l, i, c), mdbook_content, output);
+            }
+            #[cfg(feature = "mdbook")]
+            ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(markdown::Event::End(
+                markdown::Tag::Heading(l, i, c),
+            ))) => {
+                self.markdown(false, (l, i, c), mdbook_content, output);
+            }
+            #[cfg(feature = "typst")]
+            ParserEvent::Typst(typst::Event::Start(typst::Tag::Heading(l, t, b, n))) => {
+                self.typst(true, (l, t, b, n), output);
+            }
+            #[cfg(feature = "typst")]
+            ParserEvent::Typst(typst::Event::End(typst::Tag::Heading(l, t, b, n))) => {
+                self.typst(false, (l, t, b, n), output);
+            }
+            x => output.push_back(x),
+        }
+    }
+}
+
+#[cfg(feature = "mdbook")]
+fn mdbook_content(event: markdown::Event<'_>) -> ParserEvent<'_> {
+    ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(event))
+}
+
+/// Shift heading levels. See [`HeadingShiftConverter`].
+pub type ShiftHeadings<'a, T> = ConverterIter<'a, T, HeadingShiftConverter>;
+
+#[cfg(all(test, feature = "markdown", feature = "typst"))]
+mod tests {
+    use super::*;
+    use crate::markdown::{HeadingLevel, MarkdownIter, Parser};
+    use similar_asserts::assert_eq;
+
+    use markdown::Event as MdEvent;
+    use markdown::Tag as MdTag;
+    use ParserEvent::*;
+
+    fn shifted(converter: HeadingShiftConverter, md: &str) -> Vec<ParserEvent<'_>> {
+        converter
+            .over(MarkdownIter(Parser::new(md)))
+            .filter(|e| !matches!(e, Markdown(MdEvent::Text(_))))
+            .collect()
+    }
+
+    #[test]
+    fn markdown() {
+        let h = |level| MdTag::Heading(level, None, vec![]);
+        self::assert_eq!(
+            shifted(HeadingShiftConverter::new(-1), "# One\n## Two"),
+            vec![
+                Markdown(MdEvent::Start(h(HeadingLevel::H1))),
+                Markdown(MdEvent::End(h(HeadingLevel::H1))),
+                Markdown(MdEvent::Start(h(HeadingLevel::H1))),
+                Markdown(MdEvent::End(h(HeadingLevel::H1))),
+            ]
+        );
+        self::assert_eq!(
+            shifted(HeadingShiftConverter::new(4), "# One\n#### Four"),
+            vec![
+                Markdown(MdEvent::Start(h(HeadingLevel::H5))),
+                Markdown(MdEvent::End(h(HeadingLevel::H5))),
+                Markdown(MdEvent::Start(h(HeadingLevel::H6))),
+                Markdown(MdEvent::End(h(HeadingLevel::H6))),
+            ]
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let typst_heading = |level| {
+            typst::Tag::Heading(
+                std::num::NonZeroU8::new(level).expect("nonzero"),
+                typst::TableOfContents::Include,
+                typst::Bookmarks::Include,
+                typst::Numbering::Include,
+            )
+        };
+        self::assert_eq!(
+            shifted(
+                HeadingShiftConverter::new(4).overflow(Overflow::Typst),
+                "#### Four"
+            ),
+            vec![
+                Typst(typst::Event::Start(typst_heading(8))),
+                Typst(typst::Event::End(typst_heading(8))),
+            ]
+        );
+        self::assert_eq!(
+            HeadingShiftConverter::new(4)
+                .overflow(Overflow::Typst)
+                .over(MarkdownIter(Parser::new_ext(
+                    "#### Four {.unnumbered}",
+                    markdown::Options::ENABLE_HEADING_ATTRIBUTES
+                )))
+                .filter(|e| !matches!(e, Markdown(MdEvent::Text(_))))
+                .collect::<Vec<_>>(),
+            vec![
+                Typst(typst::Event::Start(typst::Tag::Heading(
+                    std::num::NonZeroU8::new(8).expect("nonzero"),
+                    typst::TableOfContents::Include,
+                    typst::Bookmarks::Include,
+                    typst::Numbering::Exclude,
+                ))),
+                Typst(typst::Event::End(typst::Tag::Heading(
+                    std::num::NonZeroU8::new(8).expect("nonzero"),
+                    typst::TableOfContents::Include,
+                    typst::Bookmarks::Include,
+                    typst::Numbering::Exclude,
+                ))),
+            ]
+        );
+        self::assert_eq!(
+            shifted(
+                HeadingShiftConverter::new(4).overflow(Overflow::Strong),
+                "#### Four"
+            ),
+            vec![
+                Markdown(MdEvent::Start(MdTag::Paragraph)),
+                Markdown(MdEvent::Start(MdTag::Strong)),
+                Markdown(MdEvent::End(MdTag::Strong)),
+                Markdown(MdEvent::End(MdTag::Paragraph)),
+            ]
+        );
+
+        let events = || {
+            typst::TypstIter(
+                vec![
+                    typst::Event::Start(typst_heading(5)),
+                    typst::Event::End(typst_heading(5)),
+                ]
+                .into_iter(),
+            )
+        };
+        self::assert_eq!(
+            HeadingShiftConverter::new(3)
+                .over(events())
+                .collect::<Vec<_>>(),
+            vec![
+                Typst(typst::Event::Start(typst_heading(6))),
+                Typst(typst::Event::End(typst_heading(6))),
+            ]
+        );
+        self::assert_eq!(
+            HeadingShiftConverter::new(3)
+                .overflow(Overflow::Typst)
+                .over(events())
+                .collect::<Vec<_>>(),
+            vec![
+                Typst(typst::Event::Start(typst_heading(8))),
+                Typst(typst::Event::End(typst_heading(8))),
+            ]
+        );
+    }
+}
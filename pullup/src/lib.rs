@@ -1,5 +1,15 @@
 pub mod assert;
+pub mod convert;
+pub mod ext;
 pub mod filter;
+#[cfg(any(feature = "markdown", feature = "typst"))]
+pub mod headings;
+#[cfg(any(feature = "markdown", feature = "typst"))]
+pub mod plaintext;
+#[cfg(any(feature = "markdown", feature = "typst"))]
+pub mod stats;
+pub mod tree;
+pub mod validate;
 
 #[cfg(feature = "markdown")]
 pub mod markdown;
@@ -8,6 +18,8 @@ pub mod mdbook;
 #[cfg(feature = "typst")]
 pub mod typst;
 
+pub use ext::{IntoParserEvents, ParserEventExt};
+
 /// Represents all the types of markup events this crate can operate on.
 ///
 /// Markup adapters:
@@ -28,6 +40,9 @@ pub enum ParserEvent<'a> {
 
 #[macro_export]
 /// Convert between markup events without a buffer or lookahead.
+///
+/// For conversions that need state, lookahead, or emit multiple events, implement
+/// [`Converter`](crate::convert::Converter) instead.
 macro_rules! converter {
     (
         $(#[$attr:meta])*
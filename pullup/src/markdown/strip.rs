@@ -42,7 +42,7 @@ is anybody in there?
 *just nod if you can hear me*
 <del>*foo*</del>
 ";
-            let i = AssertMarkdown(super::StripHtml::new(MarkdownIter(Parser::new(&md))));
+            let i = AssertMarkdown(super::StripHtml::new(MarkdownIter(Parser::new(md))));
             self::assert_eq!(
                 i.collect::<Vec<markdown::Event>>(),
                 vec![
@@ -0,0 +1,174 @@
+//! Builder to customize Markdown to Typst conversion.
+
+use core::marker::PhantomData;
+
+use crate::markdown::to::typst::*;
+use crate::markdown::MarkdownIter;
+use crate::ParserEvent;
+
+#[derive(typed_builder::TypedBuilder)]
+#[builder(build_method(vis="", name=__build))]
+#[builder(field_defaults(default = true))]
+/// Converts Markdown to Typst.
+///
+/// Using the builder one can choose which conversions to apply. By default, all
+/// conversions are enabled. Enabled conversions are applied in the order documented in
+/// [the parent module](crate::markdown::to::typst).
+///
+/// Events for disabled conversions are left in the resulting
+/// [`ParserEvent`](crate::ParserEvent) iterator as Markdown events.
+pub struct Conversion<'a, T> {
+    #[builder(!default)]
+    events: T,
+    callouts: bool,
+    headings: bool,
+    paragraphs: bool,
+    soft_breaks: bool,
+    hard_breaks: bool,
+    #[builder(default = false)]
+    emoji: bool,
+    text: bool,
+    strong: bool,
+    emphasis: bool,
+    blockquotes: bool,
+    lists: bool,
+    code: bool,
+    links: bool,
+    tables: bool,
+    #[builder(default)]
+    _p: PhantomData<&'a ()>,
+}
+
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<
+        'a,
+        T,
+        __callouts: ::typed_builder::Optional<bool>,
+        __headings: ::typed_builder::Optional<bool>,
+        __paragraphs: ::typed_builder::Optional<bool>,
+        __soft_breaks: ::typed_builder::Optional<bool>,
+        __hard_breaks: ::typed_builder::Optional<bool>,
+        __emoji: ::typed_builder::Optional<bool>,
+        __text: ::typed_builder::Optional<bool>,
+        __strong: ::typed_builder::Optional<bool>,
+        __emphasis: ::typed_builder::Optional<bool>,
+        __blockquotes: ::typed_builder::Optional<bool>,
+        __lists: ::typed_builder::Optional<bool>,
+        __code: ::typed_builder::Optional<bool>,
+        __links: ::typed_builder::Optional<bool>,
+        __tables: ::typed_builder::Optional<bool>,
+        ___p: ::typed_builder::Optional<PhantomData<&'a ()>>,
+    >
+    ConversionBuilder<
+        'a,
+        T,
+        (
+            (T,),
+            __callouts,
+            __headings,
+            __paragraphs,
+            __soft_breaks,
+            __hard_breaks,
+            __emoji,
+            __text,
+            __strong,
+            __emphasis,
+            __blockquotes,
+            __lists,
+            __code,
+            __links,
+            __tables,
+            ___p,
+        ),
+    >
+where
+    T: Iterator<Item = crate::markdown::Event<'a>> + 'a,
+{
+    pub fn build(self) -> impl Iterator<Item = ParserEvent<'a>> {
+        let this = self.__build();
+        let mut events: Box<dyn Iterator<Item = ParserEvent<'_>>> =
+            Box::new(MarkdownIter(this.events));
+        if this.callouts {
+            events = Box::new(ConvertCallouts::new(events));
+        }
+        if this.headings {
+            events = Box::new(ConvertHeadings::new(events));
+        }
+        if this.paragraphs {
+            events = Box::new(ConvertParagraphs::new(events));
+        }
+        if this.soft_breaks {
+            events = Box::new(ConvertSoftBreaks::new(events));
+        }
+        if this.hard_breaks {
+            events = Box::new(ConvertHardBreaks::new(events));
+        }
+        if this.emoji {
+            events = Box::new(ConvertEmoji::new(events));
+        }
+        if this.text {
+            events = Box::new(ConvertText::new(events));
+        }
+        if this.strong {
+            events = Box::new(ConvertStrong::new(events));
+        }
+        if this.emphasis {
+            events = Box::new(ConvertEmphasis::new(events));
+        }
+        if this.blockquotes {
+            events = Box::new(ConvertBlockQuotes::new(events));
+        }
+        if this.lists {
+            events = Box::new(ConvertLists::new(events));
+        }
+        if this.code {
+            events = Box::new(ConvertCode::new(events));
+        }
+        if this.links {
+            events = Box::new(ConvertLinks::new(events));
+        }
+        if this.tables {
+            events = Box::new(ConvertTables::new(events));
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markdown;
+    use crate::markdown::Parser;
+    use crate::typst;
+    use similar_asserts::assert_eq;
+
+    #[test]
+    fn all_enabled() {
+        let md = "# Hi\n\nSome *text* and `code`.\n";
+        let markup: String = typst::to::markup::TypstMarkup::new(typst::AssertTypst(
+            Conversion::builder().events(Parser::new(md)).build(),
+        ))
+        .collect();
+        self::assert_eq!(markup, markdown_to_typst(md));
+    }
+
+    #[test]
+    fn disabled_left_as_markdown() {
+        let events = Conversion::builder()
+            .events(Parser::new("*a*"))
+            .emphasis(false)
+            .build()
+            .collect::<Vec<_>>();
+        self::assert_eq!(
+            events,
+            vec![
+                ParserEvent::Typst(typst::Event::Start(typst::Tag::Paragraph)),
+                ParserEvent::Markdown(markdown::Event::Start(markdown::Tag::Emphasis)),
+                ParserEvent::Typst(typst::Event::Text("a".into())),
+                ParserEvent::Markdown(markdown::Event::End(markdown::Tag::Emphasis)),
+                ParserEvent::Typst(typst::Event::End(typst::Tag::Paragraph)),
+            ]
+        );
+    }
+}
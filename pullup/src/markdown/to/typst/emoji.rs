@@ -0,0 +1,222 @@
+//! Expand GitHub emoji shortcodes such as `:rocket:`.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::convert::{Converter, ConverterIter, Input};
+use crate::markdown;
+use crate::markdown::CowStr;
+use crate::typst;
+use crate::ParserEvent;
+
+mod shortcodes;
+
+use shortcodes::SHORTCODES;
+
+/// How [`EmojiConverter`] writes emoji.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EmojiStyle {
+    /// Unicode emoji in the text.
+    #[default]
+    Unicode,
+    /// Typst `emoji` symbols, e.g. `#(emoji.rocket)`, falling back to Unicode for
+    /// shortcodes without one.
+    Typst,
+}
+
+/// Replace GitHub emoji shortcodes in Markdown text outside of code.
+///
+/// Shortcodes without an emoji are left alone. Must run before
+/// [`ConvertText`](super::ConvertText).
+#[derive(Debug, Default)]
+pub struct EmojiConverter {
+    style: EmojiStyle,
+    custom: HashMap<String, String>,
+    code: usize,
+}
+
+impl EmojiConverter {
+    pub fn new(style: EmojiStyle) -> Self {
+        Self {
+            style,
+            ..Self::default()
+        }
+    }
+
+    /// Replace `:code:` with `text`, taking precedence over the built-in shortcodes.
+    pub fn code(mut self, code: impl Into<String>, text: impl Into<String>) -> Self {
+        self.custom.insert(code.into(), text.into());
+        self
+    }
+
+    /// The replacement for shortcode `name`.
+    fn lookup(&self, name: &str) -> Option<Replacement> {
+        if let Some(text) = self.custom.get(name) {
+            return Some(Replacement::Text(text.clone()));
+        }
+        let i = SHORTCODES
+            .binary_search_by(|(code, _, _)| (*code).cmp(name))
+            .ok()?;
+        let (_, unicode, symbol) = SHORTCODES[i];
+        Some(match (self.style, symbol) {
+            (EmojiStyle::Typst, Some(symbol)) => Replacement::Symbol(symbol),
+            _ => Replacement::Text(unicode.to_string()),
+        })
+    }
+
+    /// Split `text` at shortcodes into Markdown text and Typst symbols.
+    fn expand<'a>(&self, text: CowStr<'a>, output: &mut VecDeque<ParserEvent<'a>>) {
+        if !text.contains(':') {
+            output.push_back(ParserEvent::Markdown(markdown::Event::Text(text)));
+            return;
+        }
+        let mut buf = String::new();
+        let mut rest: &str = &text;
+        while let Some(start) = rest.find(':') {
+            let after = &rest[start + 1..];
+            let Some(len) = after.find(':') else {
+                break;
+            };
+            let name = &after[..len];
+            let valid = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-'));
+            match self.lookup(name).filter(|_| valid) {
+                Some(replacement) => {
+                    buf.push_str(&rest[..start]);
+                    match replacement {
+                        Replacement::Text(t) => buf.push_str(&t),
+                        Replacement::Symbol(symbol) => {
+                            if !buf.is_empty() {
+                                output.push_back(ParserEvent::Markdown(markdown::Event::Text(
+                                    std::mem::take(&mut buf).into(),
+                                )));
+                            }
+                            output.push_back(ParserEvent::Typst(typst::Event::Raw(
+                                format!("#(emoji.{symbol})").into(),
+                            )));
+                        }
+                    }
+                    rest = &after[len + 1..];
+                }
+                // The closing colon may open the next shortcode.
+                None => {
+                    buf.push_str(&rest[..=start]);
+                    rest = after;
+                }
+            }
+        }
+        buf.push_str(rest);
+        if !buf.is_empty() {
+            output.push_back(ParserEvent::Markdown(markdown::Event::Text(buf.into())));
+        }
+    }
+}
+
+enum Replacement {
+    Text(String),
+    Symbol(&'static str),
+}
+
+impl<'a> Converter<'a> for EmojiConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
+            ParserEvent::Markdown(markdown::Event::Text(t)) if self.code == 0 => {
+                self.expand(t, output);
+            }
+            event @ ParserEvent::Markdown(markdown::Event::Start(markdown::Tag::CodeBlock(_))) => {
+                self.code += 1;
+                output.push_back(event);
+            }
+            event @ ParserEvent::Markdown(markdown::Event::End(markdown::Tag::CodeBlock(_))) => {
+                self.code = self.code.saturating_sub(1);
+                output.push_back(event);
+            }
+            x => output.push_back(x),
+        }
+    }
+}
+
+/// Replace GitHub emoji shortcodes. See [`EmojiConverter`].
+pub type ConvertEmoji<'a, T> = ConverterIter<'a, T, EmojiConverter>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markdown::{MarkdownIter, Parser};
+    use similar_asserts::assert_eq;
+
+    use markdown::Event as MdEvent;
+    use markdown::Tag as MdTag;
+    use ParserEvent::*;
+
+    #[test]
+    fn unicode() {
+        let md = "Ship it :rocket:! 10:30 :nope: :+1:";
+        let i = ConvertEmoji::new(MarkdownIter(Parser::new(md)));
+
+        self::assert_eq!(
+            i.collect::<Vec<_>>(),
+            vec![
+                Markdown(MdEvent::Start(MdTag::Paragraph)),
+                Markdown(MdEvent::Text(
+                    "Ship it 🚀! 10:30 :nope: 👍".to_string().into()
+                )),
+                Markdown(MdEvent::End(MdTag::Paragraph)),
+            ]
+        );
+    }
+
+    #[test]
+    fn typst_symbols() {
+        let md = "Hot :fire:s and :artist:";
+        let i = EmojiConverter::new(EmojiStyle::Typst).over(MarkdownIter(Parser::new(md)));
+
+        self::assert_eq!(
+            i.collect::<Vec<_>>(),
+            vec![
+                Markdown(MdEvent::Start(MdTag::Paragraph)),
+                Markdown(MdEvent::Text("Hot ".to_string().into())),
+                Typst(typst::Event::Raw("#(emoji.fire)".to_string().into())),
+                Markdown(MdEvent::Text("s and 🧑\u{200d}🎨".to_string().into())),
+                Markdown(MdEvent::End(MdTag::Paragraph)),
+            ]
+        );
+    }
+
+    #[test]
+    fn shortcodes_sorted() {
+        assert!(SHORTCODES.windows(2).all(|w| w[0].0 < w[1].0));
+        self::assert_eq!(SHORTCODES.len(), 1913);
+    }
+
+    #[test]
+    fn custom_codes_and_code() {
+        let md = ":ferris: `:rocket:`\n\n```\n:rocket:\n```\n";
+        let i = EmojiConverter::default()
+            .code("ferris", "🦀")
+            .over(MarkdownIter(Parser::new(md)));
+
+        self::assert_eq!(
+            i.collect::<Vec<_>>(),
+            vec![
+                Markdown(MdEvent::Start(MdTag::Paragraph)),
+                Markdown(MdEvent::Text("🦀 ".to_string().into())),
+                Markdown(MdEvent::Code(":rocket:".into())),
+                Markdown(MdEvent::End(MdTag::Paragraph)),
+                Markdown(MdEvent::Start(MdTag::CodeBlock(
+                    markdown::CodeBlockKind::Fenced("".into())
+                ))),
+                Markdown(MdEvent::Text(":rocket:\n".into())),
+                Markdown(MdEvent::End(MdTag::CodeBlock(
+                    markdown::CodeBlockKind::Fenced("".into())
+                ))),
+            ]
+        );
+    }
+}
@@ -0,0 +1,2070 @@
+//! GitHub emoji shortcodes.
+//!
+//! Generated from the aliases in gemoji 4.1.0 and the Typst `emoji` symbols of codex
+//! 0.1.1, which are also in later versions. Do not edit by hand.
+
+/// GitHub shortcodes sorted by code, their Unicode emoji, and the name of the Typst
+/// `emoji` symbol if there is one.
+pub(super) const SHORTCODES: &[(&str, &str, Option<&str>)] = &[
+    ("+1", "👍", Some("thumb.up")),
+    ("-1", "👎", Some("thumb.down")),
+    ("100", "💯", Some("hundred")),
+    ("1234", "🔢", Some("numbers")),
+    ("1st_place_medal", "🥇", Some("medal.first")),
+    ("2nd_place_medal", "🥈", Some("medal.second")),
+    ("3rd_place_medal", "🥉", Some("medal.third")),
+    ("8ball", "🎱", Some("billiards")),
+    ("a", "🅰\u{fe0f}", Some("a")),
+    ("ab", "🆎", Some("ab")),
+    ("abacus", "🧮", Some("abacus")),
+    ("abc", "🔤", Some("abc")),
+    ("abcd", "🔡", Some("abcd")),
+    ("accept", "🉑", Some("ka")),
+    ("accordion", "🪗", Some("accordion")),
+    ("adhesive_bandage", "🩹", Some("plaster")),
+    ("adult", "🧑", Some("person")),
+    ("aerial_tramway", "🚡", Some("cablecar.small")),
+    ("afghanistan", "🇦🇫", None),
+    ("airplane", "✈\u{fe0f}", Some("airplane")),
+    ("aland_islands", "🇦🇽", None),
+    ("alarm_clock", "⏰", Some("clock.alarm")),
+    ("albania", "🇦🇱", None),
+    ("alembic", "⚗\u{fe0f}", Some("alembic")),
+    ("algeria", "🇩🇿", None),
+    ("alien", "👽", Some("alien")),
+    ("ambulance", "🚑", Some("ambulance")),
+    ("american_samoa", "🇦🇸", None),
+    ("amphora", "🏺", Some("amphora")),
+    ("anatomical_heart", "🫀", Some("heart.real")),
+    ("anchor", "⚓", Some("anchor")),
+    ("andorra", "🇦🇩", None),
+    ("angel", "👼", Some("baby.angel")),
+    ("anger", "💢", Some("anger")),
+    ("angola", "🇦🇴", None),
+    ("angry", "😠", Some("face.angry")),
+    ("anguilla", "🇦🇮", None),
+    ("anguished", "😧", Some("face.anguish")),
+    ("ant", "🐜", Some("ant")),
+    ("antarctica", "🇦🇶", None),
+    ("antigua_barbuda", "🇦🇬", None),
+    ("apple", "🍎", Some("apple.red")),
+    ("aquarius", "♒", Some("zodiac.aquarius")),
+    ("argentina", "🇦🇷", None),
+    ("aries", "♈", Some("zodiac.aries")),
+    ("armenia", "🇦🇲", None),
+    ("arrow_backward", "◀\u{fe0f}", Some("triangle.l")),
+    ("arrow_double_down", "⏬", Some("playback.down")),
+    ("arrow_double_up", "⏫", Some("playback.up")),
+    ("arrow_down", "⬇\u{fe0f}", Some("arrow.b.filled")),
+    ("arrow_down_small", "🔽", Some("triangle.b")),
+    ("arrow_forward", "▶\u{fe0f}", Some("triangle.r")),
+    ("arrow_heading_down", "⤵\u{fe0f}", Some("arrow.b.curve")),
+    ("arrow_heading_up", "⤴\u{fe0f}", Some("arrow.t.curve")),
+    ("arrow_left", "⬅\u{fe0f}", Some("arrow.l.filled")),
+    ("arrow_lower_left", "↙\u{fe0f}", Some("arrow.bl")),
+    ("arrow_lower_right", "↘\u{fe0f}", Some("arrow.br")),
+    ("arrow_right", "➡\u{fe0f}", Some("arrow.r.filled")),
+    ("arrow_right_hook", "↪\u{fe0f}", Some("arrow.r.hook")),
+    ("arrow_up", "⬆\u{fe0f}", Some("arrow.t.filled")),
+    ("arrow_up_down", "↕\u{fe0f}", Some("arrow.t.b")),
+    ("arrow_up_small", "🔼", Some("triangle.t")),
+    ("arrow_upper_left", "↖\u{fe0f}", Some("arrow.tl")),
+    ("arrow_upper_right", "↗\u{fe0f}", Some("arrow.tr")),
+    ("arrows_clockwise", "🔃", Some("playback.repeat.v")),
+    ("arrows_counterclockwise", "🔄", Some("arrows.cycle")),
+    ("art", "🎨", Some("palette")),
+    ("articulated_lorry", "🚛", Some("truck.trailer")),
+    ("artificial_satellite", "🛰\u{fe0f}", Some("satellite")),
+    ("artist", "🧑\u{200d}🎨", None),
+    ("aruba", "🇦🇼", None),
+    ("ascension_island", "🇦🇨", None),
+    ("asterisk", "*\u{fe0f}\u{20e3}", None),
+    ("astonished", "😲", Some("face.astonish")),
+    ("astronaut", "🧑\u{200d}🚀", None),
+    ("athletic_shoe", "👟", Some("shoe.sneaker")),
+    ("atm", "🏧", Some("atm")),
+    ("atom_symbol", "⚛\u{fe0f}", Some("atom")),
+    ("australia", "🇦🇺", None),
+    ("austria", "🇦🇹", None),
+    ("auto_rickshaw", "🛺", Some("car.rickshaw")),
+    ("avocado", "🥑", Some("avocado")),
+    ("axe", "🪓", Some("axe")),
+    ("azerbaijan", "🇦🇿", None),
+    ("b", "🅱\u{fe0f}", Some("b")),
+    ("baby", "👶", Some("baby")),
+    ("baby_bottle", "🍼", Some("babybottle")),
+    ("baby_chick", "🐤", Some("chicken.baby.head")),
+    ("baby_symbol", "🚼", Some("baby.box")),
+    ("back", "🔙", Some("arrow.l.back")),
+    ("bacon", "🥓", Some("bacon")),
+    ("badger", "🦡", Some("badger")),
+    ("badminton", "🏸", Some("badminton")),
+    ("bagel", "🥯", Some("bagel")),
+    ("baggage_claim", "🛄", Some("baggageclaim")),
+    ("baguette_bread", "🥖", Some("baguette")),
+    ("bahamas", "🇧🇸", None),
+    ("bahrain", "🇧🇭", None),
+    ("balance_scale", "⚖\u{fe0f}", Some("scales")),
+    ("bald_man", "👨\u{200d}🦲", None),
+    ("bald_woman", "👩\u{200d}🦲", None),
+    ("ballet_shoes", "🩰", Some("shoe.ballet")),
+    ("balloon", "🎈", Some("balloon")),
+    ("ballot_box", "🗳\u{fe0f}", Some("ballotbox")),
+    ("ballot_box_with_check", "☑\u{fe0f}", Some("ballot.check")),
+    ("bamboo", "🎍", Some("kadomatsu")),
+    ("banana", "🍌", Some("banana")),
+    ("bangbang", "‼\u{fe0f}", Some("excl.double")),
+    ("bangladesh", "🇧🇩", None),
+    ("banjo", "🪕", Some("banjo")),
+    ("bank", "🏦", Some("bank")),
+    ("bar_chart", "📊", Some("chart.bar")),
+    ("barbados", "🇧🇧", None),
+    ("barber", "💈", Some("barberpole")),
+    ("baseball", "⚾", Some("baseball")),
+    ("basket", "🧺", Some("basket")),
+    ("basketball", "🏀", Some("basketball.ball")),
+    ("basketball_man", "⛹\u{fe0f}\u{200d}♂\u{fe0f}", None),
+    ("basketball_woman", "⛹\u{fe0f}\u{200d}♀\u{fe0f}", None),
+    ("bat", "🦇", Some("bat")),
+    ("bath", "🛀", Some("bathtub")),
+    ("bathtub", "🛁", Some("bathtub.foam")),
+    ("battery", "🔋", Some("battery")),
+    ("beach_umbrella", "🏖\u{fe0f}", Some("beach.umbrella")),
+    ("beans", "🫘", Some("beans")),
+    ("bear", "🐻", Some("bear")),
+    ("bearded_person", "🧔", Some("person.beard")),
+    ("beaver", "🦫", Some("beaver")),
+    ("bed", "🛏\u{fe0f}", Some("bed")),
+    ("bee", "🐝", Some("bee")),
+    ("beer", "🍺", Some("beer")),
+    ("beers", "🍻", Some("beer.clink")),
+    ("beetle", "🪲", Some("beetle")),
+    ("beginner", "🔰", Some("shoshinsha")),
+    ("belarus", "🇧🇾", None),
+    ("belgium", "🇧🇪", None),
+    ("belize", "🇧🇿", None),
+    ("bell", "🔔", Some("bell")),
+    ("bell_pepper", "🫑", Some("pepper")),
+    ("bellhop_bell", "🛎\u{fe0f}", Some("bell.ding")),
+    ("benin", "🇧🇯", None),
+    ("bento", "🍱", Some("bento")),
+    ("bermuda", "🇧🇲", None),
+    ("beverage_box", "🧃", Some("juice")),
+    ("bhutan", "🇧🇹", None),
+    ("bicyclist", "🚴", Some("bicyclist")),
+    ("bike", "🚲", Some("bike")),
+    ("biking_man", "🚴\u{200d}♂\u{fe0f}", None),
+    ("biking_woman", "🚴\u{200d}♀\u{fe0f}", None),
+    ("bikini", "👙", Some("bikini")),
+    ("billed_cap", "🧢", Some("basecap")),
+    ("biohazard", "☣\u{fe0f}", Some("biohazard")),
+    ("bird", "🐦", Some("bird")),
+    ("birthday", "🎂", Some("cake")),
+    ("bison", "🦬", Some("bison")),
+    ("biting_lip", "🫦", Some("mouth.bite")),
+    ("black_bird", "🐦\u{200d}⬛", None),
+    ("black_cat", "🐈\u{200d}⬛", None),
+    ("black_circle", "⚫", Some("circle.black")),
+    ("black_flag", "🏴", Some("flag.black")),
+    ("black_heart", "🖤", Some("heart.black")),
+    ("black_joker", "🃏", Some("playingcard.joker")),
+    ("black_large_square", "⬛", Some("square.black")),
+    (
+        "black_medium_small_square",
+        "◾",
+        Some("square.black.small"),
+    ),
+    (
+        "black_medium_square",
+        "◼\u{fe0f}",
+        Some("square.black.medium"),
+    ),
+    ("black_nib", "✒\u{fe0f}", None),
+    ("black_small_square", "▪\u{fe0f}", Some("square.black.tiny")),
+    ("black_square_button", "🔲", Some("button")),
+    ("blond_haired_man", "👱\u{200d}♂\u{fe0f}", None),
+    ("blond_haired_person", "👱", Some("person.blonde")),
+    ("blond_haired_woman", "👱\u{200d}♀\u{fe0f}", None),
+    ("blonde_woman", "👱\u{200d}♀\u{fe0f}", None),
+    ("blossom", "🌼", Some("flower.yellow")),
+    ("blowfish", "🐡", Some("blowfish")),
+    ("blue_book", "📘", Some("book.blue")),
+    ("blue_car", "🚙", Some("car.suv")),
+    ("blue_heart", "💙", Some("heart.blue")),
+    ("blue_square", "🟦", Some("square.blue")),
+    ("blueberries", "🫐", Some("blueberries")),
+    ("blush", "😊", Some("face.happy")),
+    ("boar", "🐗", Some("boar")),
+    ("boat", "⛵", Some("boat.sail")),
+    ("bolivia", "🇧🇴", None),
+    ("bomb", "💣", Some("bomb")),
+    ("bone", "🦴", Some("bone")),
+    ("book", "📖", Some("book.open")),
+    ("bookmark", "🔖", Some("bookmark")),
+    ("bookmark_tabs", "📑", Some("pages.tabs")),
+    ("books", "📚", Some("books")),
+    ("boom", "💥", Some("explosion")),
+    ("boomerang", "🪃", Some("boomerang")),
+    ("boot", "👢", Some("shoe.tall")),
+    ("bosnia_herzegovina", "🇧🇦", None),
+    ("botswana", "🇧🇼", None),
+    ("bouncing_ball_man", "⛹\u{fe0f}\u{200d}♂\u{fe0f}", None),
+    ("bouncing_ball_person", "⛹\u{fe0f}", Some("basketball")),
+    ("bouncing_ball_woman", "⛹\u{fe0f}\u{200d}♀\u{fe0f}", None),
+    ("bouquet", "💐", Some("bouquet")),
+    ("bouvet_island", "🇧🇻", None),
+    ("bow", "🙇", Some("person.bow")),
+    ("bow_and_arrow", "🏹", Some("bow")),
+    ("bowing_man", "🙇\u{200d}♂\u{fe0f}", None),
+    ("bowing_woman", "🙇\u{200d}♀\u{fe0f}", None),
+    ("bowl_with_spoon", "🥣", Some("bowl.spoon")),
+    ("bowling", "🎳", Some("bowling")),
+    ("boxing_glove", "🥊", Some("boxing")),
+    ("boy", "👦", Some("boy")),
+    ("brain", "🧠", Some("brain")),
+    ("brazil", "🇧🇷", None),
+    ("bread", "🍞", Some("bread")),
+    ("breast_feeding", "🤱", Some("feeding.breast")),
+    ("bricks", "🧱", Some("brick")),
+    ("bride_with_veil", "👰\u{200d}♀\u{fe0f}", None),
+    ("bridge_at_night", "🌉", Some("bridge.night")),
+    ("briefcase", "💼", Some("briefcase")),
+    ("british_indian_ocean_territory", "🇮🇴", None),
+    ("british_virgin_islands", "🇻🇬", None),
+    ("broccoli", "🥦", Some("broccoli")),
+    ("broken_heart", "💔", Some("heart.broken")),
+    ("broom", "🧹", Some("broom")),
+    ("brown_circle", "🟤", Some("circle.brown")),
+    ("brown_heart", "🤎", Some("heart.brown")),
+    ("brown_square", "🟫", Some("square.brown")),
+    ("brunei", "🇧🇳", None),
+    ("bubble_tea", "🧋", Some("bubbletea")),
+    ("bubbles", "🫧", Some("bubbles")),
+    ("bucket", "🪣", Some("bucket")),
+    ("bug", "🐛", Some("bug")),
+    ("building_construction", "🏗\u{fe0f}", Some("crane")),
+    ("bulb", "💡", Some("lightbulb")),
+    ("bulgaria", "🇧🇬", None),
+    ("bullettrain_front", "🚅", Some("train.speed.bullet")),
+    ("bullettrain_side", "🚄", Some("train.speed")),
+    ("burkina_faso", "🇧🇫", None),
+    ("burrito", "🌯", Some("burrito")),
+    ("burundi", "🇧🇮", None),
+    ("bus", "🚌", Some("bus")),
+    (
+        "business_suit_levitating",
+        "🕴\u{fe0f}",
+        Some("man.levitate"),
+    ),
+    ("busstop", "🚏", Some("bus.stop")),
+    ("bust_in_silhouette", "👤", Some("silhouette")),
+    ("busts_in_silhouette", "👥", Some("silhouette.double")),
+    ("butter", "🧈", Some("butter")),
+    ("butterfly", "🦋", Some("butterfly")),
+    ("cactus", "🌵", Some("cactus")),
+    ("cake", "🍰", Some("cake.slice")),
+    ("calendar", "📆", Some("calendar.tearoff")),
+    ("call_me_hand", "🤙", Some("hand.call")),
+    ("calling", "📲", Some("phone.arrow")),
+    ("cambodia", "🇰🇭", None),
+    ("camel", "🐫", Some("camel")),
+    ("camera", "📷", Some("camera")),
+    ("camera_flash", "📸", Some("camera.flash")),
+    ("cameroon", "🇨🇲", None),
+    ("camping", "🏕\u{fe0f}", Some("camping")),
+    ("canada", "🇨🇦", None),
+    ("canary_islands", "🇮🇨", None),
+    ("cancer", "♋", Some("zodiac.cancer")),
+    ("candle", "🕯\u{fe0f}", Some("candle")),
+    ("candy", "🍬", Some("candy")),
+    ("canned_food", "🥫", Some("can")),
+    ("canoe", "🛶", Some("boat.canoe")),
+    ("cape_verde", "🇨🇻", None),
+    ("capital_abcd", "🔠", Some("ABCD")),
+    ("capricorn", "♑", Some("zodiac.capri")),
+    ("car", "🚗", Some("car")),
+    ("card_file_box", "🗃\u{fe0f}", Some("filebox")),
+    ("card_index", "📇", Some("cardindex")),
+    ("card_index_dividers", "🗂\u{fe0f}", Some("filedividers")),
+    ("caribbean_netherlands", "🇧🇶", None),
+    ("carousel_horse", "🎠", Some("horse.carousel")),
+    ("carpentry_saw", "🪚", Some("saw")),
+    ("carrot", "🥕", Some("carrot")),
+    ("cartwheeling", "🤸", Some("gymnastics")),
+    ("cat", "🐱", Some("cat.face")),
+    ("cat2", "🐈", Some("cat")),
+    ("cayman_islands", "🇰🇾", None),
+    ("cd", "💿", Some("disc.cd")),
+    ("central_african_republic", "🇨🇫", None),
+    ("ceuta_melilla", "🇪🇦", None),
+    ("chad", "🇹🇩", None),
+    ("chains", "⛓\u{fe0f}", Some("chains")),
+    ("chair", "🪑", Some("chair")),
+    ("champagne", "🍾", Some("champagne")),
+    ("chart", "💹", Some("chart.yen.up")),
+    ("chart_with_downwards_trend", "📉", Some("chart.down")),
+    ("chart_with_upwards_trend", "📈", Some("chart.up")),
+    ("checkered_flag", "🏁", Some("flag.goal")),
+    ("cheese", "🧀", Some("cheese")),
+    ("cherries", "🍒", Some("cherries")),
+    ("cherry_blossom", "🌸", Some("flower.pink")),
+    ("chess_pawn", "♟\u{fe0f}", Some("chess")),
+    ("chestnut", "🌰", Some("chestnut")),
+    ("chicken", "🐔", Some("chicken")),
+    ("child", "🧒", Some("child")),
+    ("children_crossing", "🚸", Some("sign.crossing")),
+    ("chile", "🇨🇱", None),
+    ("chipmunk", "🐿\u{fe0f}", Some("chipmunk")),
+    ("chocolate_bar", "🍫", Some("chocolate")),
+    ("chopsticks", "🥢", Some("chopsticks")),
+    ("christmas_island", "🇨🇽", None),
+    ("christmas_tree", "🎄", Some("tree.xmas")),
+    ("church", "⛪", Some("church")),
+    ("cinema", "🎦", Some("camera.movie.box")),
+    ("circus_tent", "🎪", Some("circus")),
+    ("city_sunrise", "🌇", Some("city.sunset")),
+    ("city_sunset", "🌆", Some("city.dusk")),
+    ("cityscape", "🏙\u{fe0f}", Some("city")),
+    ("cl", "🆑", Some("cl")),
+    ("clamp", "🗜\u{fe0f}", Some("clamp")),
+    ("clap", "👏", Some("hands.clap")),
+    ("clapper", "🎬", Some("clapperboard")),
+    ("classical_building", "🏛\u{fe0f}", Some("museum")),
+    ("climbing", "🧗", Some("climbing")),
+    ("climbing_man", "🧗\u{200d}♂\u{fe0f}", None),
+    ("climbing_woman", "🧗\u{200d}♀\u{fe0f}", None),
+    ("clinking_glasses", "🥂", Some("glass.clink")),
+    ("clipboard", "📋", Some("clipboard")),
+    ("clipperton_island", "🇨🇵", None),
+    ("clock1", "🕐", Some("clock.one")),
+    ("clock10", "🕙", Some("clock.ten")),
+    ("clock1030", "🕥", Some("clock.ten.thirty")),
+    ("clock11", "🕚", Some("clock.eleven")),
+    ("clock1130", "🕦", Some("clock.eleven.thirty")),
+    ("clock12", "🕛", Some("clock.twelve")),
+    ("clock1230", "🕧", Some("clock.twelve.thirty")),
+    ("clock130", "🕜", Some("clock.one.thirty")),
+    ("clock2", "🕑", Some("clock.two")),
+    ("clock230", "🕝", Some("clock.two.thirty")),
+    ("clock3", "🕒", Some("clock.three")),
+    ("clock330", "🕞", Some("clock.three.thirty")),
+    ("clock4", "🕓", Some("clock.four")),
+    ("clock430", "🕟", Some("clock.four.thirty")),
+    ("clock5", "🕔", Some("clock.five")),
+    ("clock530", "🕠", Some("clock.five.thirty")),
+    ("clock6", "🕕", Some("clock.six")),
+    ("clock630", "🕡", Some("clock.six.thirty")),
+    ("clock7", "🕖", Some("clock.seven")),
+    ("clock730", "🕢", Some("clock.seven.thirty")),
+    ("clock8", "🕗", Some("clock.eight")),
+    ("clock830", "🕣", Some("clock.eight.thirty")),
+    ("clock9", "🕘", Some("clock.nine")),
+    ("clock930", "🕤", Some("clock.nine.thirty")),
+    ("closed_book", "📕", Some("book.red")),
+    ("closed_lock_with_key", "🔐", Some("lock.key")),
+    ("closed_umbrella", "🌂", Some("umbrella.closed")),
+    ("cloud", "☁\u{fe0f}", Some("cloud")),
+    ("cloud_with_lightning", "🌩\u{fe0f}", Some("cloud.thunder")),
+    (
+        "cloud_with_lightning_and_rain",
+        "⛈\u{fe0f}",
+        Some("cloud.storm"),
+    ),
+    ("cloud_with_rain", "🌧\u{fe0f}", Some("cloud.rain")),
+    ("cloud_with_snow", "🌨\u{fe0f}", Some("cloud.snow")),
+    ("clown_face", "🤡", Some("face.clown")),
+    ("clubs", "♣\u{fe0f}", Some("suit.club")),
+    ("cn", "🇨🇳", None),
+    ("coat", "🧥", Some("coat")),
+    ("cockroach", "🪳", Some("cockroach")),
+    ("cocktail", "🍸", Some("cocktail.martini")),
+    ("coconut", "🥥", Some("coconut")),
+    ("cocos_islands", "🇨🇨", None),
+    ("coffee", "☕", Some("coffee")),
+    ("coffin", "⚰\u{fe0f}", Some("coffin")),
+    ("coin", "🪙", Some("coin")),
+    ("cold_face", "🥶", Some("face.cold")),
+    ("cold_sweat", "😰", Some("face.fear.sweat")),
+    ("collision", "💥", Some("explosion")),
+    ("colombia", "🇨🇴", None),
+    ("comet", "☄\u{fe0f}", Some("comet")),
+    ("comoros", "🇰🇲", None),
+    ("compass", "🧭", Some("compass")),
+    ("computer", "💻", Some("laptop")),
+    ("computer_mouse", "🖱\u{fe0f}", Some("computermouse")),
+    ("confetti_ball", "🎊", Some("confetti")),
+    ("confounded", "😖", Some("face.suffer")),
+    ("confused", "😕", Some("face.unhappy")),
+    ("congo_brazzaville", "🇨🇬", None),
+    ("congo_kinshasa", "🇨🇩", None),
+    ("congratulations", "㊗\u{fe0f}", Some("shuku")),
+    ("construction", "🚧", Some("construction")),
+    ("construction_worker", "👷", Some("builder")),
+    ("construction_worker_man", "👷\u{200d}♂\u{fe0f}", None),
+    ("construction_worker_woman", "👷\u{200d}♀\u{fe0f}", None),
+    ("control_knobs", "🎛\u{fe0f}", Some("mixer")),
+    ("convenience_store", "🏪", Some("store.small")),
+    ("cook", "🧑\u{200d}🍳", None),
+    ("cook_islands", "🇨🇰", None),
+    ("cookie", "🍪", Some("cookie")),
+    ("cool", "🆒", Some("cool")),
+    ("cop", "👮", Some("policeofficer")),
+    ("copyright", "©\u{fe0f}", Some("copyright")),
+    ("coral", "🪸", Some("coral")),
+    ("corn", "🌽", Some("corn")),
+    ("costa_rica", "🇨🇷", None),
+    ("cote_divoire", "🇨🇮", None),
+    ("couch_and_lamp", "🛋\u{fe0f}", Some("couch")),
+    ("couple", "👫", Some("handholding.woman.man")),
+    ("couple_with_heart", "💑", Some("couple")),
+    (
+        "couple_with_heart_man_man",
+        "👨\u{200d}❤\u{fe0f}\u{200d}👨",
+        None,
+    ),
+    (
+        "couple_with_heart_woman_man",
+        "👩\u{200d}❤\u{fe0f}\u{200d}👨",
+        None,
+    ),
+    (
+        "couple_with_heart_woman_woman",
+        "👩\u{200d}❤\u{fe0f}\u{200d}👩",
+        None,
+    ),
+    ("couplekiss", "💏", Some("kiss")),
+    (
+        "couplekiss_man_man",
+        "👨\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨",
+        None,
+    ),
+    (
+        "couplekiss_man_woman",
+        "👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨",
+        None,
+    ),
+    (
+        "couplekiss_woman_woman",
+        "👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩",
+        None,
+    ),
+    ("cow", "🐮", Some("cow.face")),
+    ("cow2", "🐄", Some("cow")),
+    ("cowboy_hat_face", "🤠", Some("face.cowboy")),
+    ("crab", "🦀", Some("crab")),
+    ("crayon", "🖍\u{fe0f}", Some("crayon")),
+    ("credit_card", "💳", Some("card.credit")),
+    ("crescent_moon", "🌙", Some("moon.crescent")),
+    ("cricket", "🦗", Some("cricket")),
+    ("cricket_game", "🏏", Some("cricketbat")),
+    ("croatia", "🇭🇷", None),
+    ("crocodile", "🐊", Some("crocodile")),
+    ("croissant", "🥐", Some("croissant")),
+    ("crossed_fingers", "🤞", Some("fingers.cross")),
+    ("crossed_flags", "🎌", Some("flags.jp.crossed")),
+    ("crossed_swords", "⚔\u{fe0f}", Some("swords")),
+    ("crown", "👑", Some("crown")),
+    ("crutch", "🩼", Some("crutch")),
+    ("cry", "😢", Some("face.tear")),
+    ("crying_cat_face", "😿", Some("cat.face.cry")),
+    ("crystal_ball", "🔮", Some("crystal")),
+    ("cuba", "🇨🇺", None),
+    ("cucumber", "🥒", Some("cucumber")),
+    ("cup_with_straw", "🥤", Some("cup.straw")),
+    ("cupcake", "🧁", Some("cupcake")),
+    ("cupid", "💘", Some("heart.arrow")),
+    ("curacao", "🇨🇼", None),
+    ("curling_stone", "🥌", Some("curling")),
+    ("curly_haired_man", "👨\u{200d}🦱", None),
+    ("curly_haired_woman", "👩\u{200d}🦱", None),
+    ("curly_loop", "➰", None),
+    ("currency_exchange", "💱", Some("forex")),
+    ("curry", "🍛", Some("curry")),
+    ("cursing_face", "🤬", Some("face.symbols")),
+    ("custard", "🍮", Some("custard")),
+    ("customs", "🛃", Some("customs")),
+    ("cut_of_meat", "🥩", Some("meat")),
+    ("cyclone", "🌀", Some("cyclone")),
+    ("cyprus", "🇨🇾", None),
+    ("czech_republic", "🇨🇿", None),
+    ("dagger", "🗡\u{fe0f}", Some("knife.dagger")),
+    ("dancer", "💃", Some("dancing.woman")),
+    ("dancers", "👯", None),
+    ("dancing_men", "👯\u{200d}♂\u{fe0f}", None),
+    ("dancing_women", "👯\u{200d}♀\u{fe0f}", None),
+    ("dango", "🍡", Some("skewer.dango")),
+    ("dark_sunglasses", "🕶\u{fe0f}", Some("glasses.sun")),
+    ("dart", "🎯", Some("darts")),
+    ("dash", "💨", Some("cloud.dust")),
+    ("date", "📅", Some("calendar")),
+    ("de", "🇩🇪", None),
+    ("deaf_man", "🧏\u{200d}♂\u{fe0f}", None),
+    ("deaf_person", "🧏", Some("person.deaf")),
+    ("deaf_woman", "🧏\u{200d}♀\u{fe0f}", None),
+    ("deciduous_tree", "🌳", Some("tree.deciduous")),
+    ("deer", "🦌", Some("deer")),
+    ("denmark", "🇩🇰", None),
+    ("department_store", "🏬", Some("store.big")),
+    ("derelict_house", "🏚\u{fe0f}", Some("house.derelict")),
+    ("desert", "🏜\u{fe0f}", Some("desert")),
+    ("desert_island", "🏝\u{fe0f}", Some("beach.palm")),
+    ("desktop_computer", "🖥\u{fe0f}", Some("computer")),
+    ("detective", "🕵\u{fe0f}", Some("detective")),
+    ("diamond_shape_with_a_dot_inside", "💠", Some("diamond.dot")),
+    ("diamonds", "♦\u{fe0f}", Some("suit.diamond")),
+    ("diego_garcia", "🇩🇬", None),
+    ("disappointed", "😞", Some("face.down")),
+    ("disappointed_relieved", "😥", Some("face.tear.relief")),
+    ("disguised_face", "🥸", Some("face.disguise")),
+    ("diving_mask", "🤿", Some("diving")),
+    ("diya_lamp", "🪔", Some("lamp.diya")),
+    ("dizzy", "💫", Some("star.arc")),
+    ("dizzy_face", "😵", Some("face.dizzy")),
+    ("djibouti", "🇩🇯", None),
+    ("dna", "🧬", Some("helix")),
+    ("do_not_litter", "🚯", Some("litter.not")),
+    ("dodo", "🦤", Some("dodo")),
+    ("dog", "🐶", Some("dog.face")),
+    ("dog2", "🐕", Some("dog")),
+    ("dollar", "💵", Some("money.dollar")),
+    ("dolls", "🎎", Some("ningyo")),
+    ("dolphin", "🐬", Some("dolphin")),
+    ("dominica", "🇩🇲", None),
+    ("dominican_republic", "🇩🇴", None),
+    ("donkey", "🫏", None),
+    ("door", "🚪", Some("door")),
+    ("dotted_line_face", "🫥", Some("face.dotted")),
+    ("doughnut", "🍩", Some("donut")),
+    ("dove", "🕊\u{fe0f}", Some("dove.peace")),
+    ("dragon", "🐉", Some("dragon")),
+    ("dragon_face", "🐲", Some("dragon.face")),
+    ("dress", "👗", Some("dress")),
+    ("dromedary_camel", "🐪", Some("camel.dromedar")),
+    ("drooling_face", "🤤", Some("face.drool")),
+    ("drop_of_blood", "🩸", Some("blood")),
+    ("droplet", "💧", Some("drop")),
+    ("drum", "🥁", Some("drum")),
+    ("duck", "🦆", Some("duck")),
+    ("dumpling", "🥟", Some("dumpling")),
+    ("dvd", "📀", Some("disc.dvd")),
+    ("e-mail", "📧", Some("email")),
+    ("eagle", "🦅", Some("eagle")),
+    ("ear", "👂", Some("ear")),
+    ("ear_of_rice", "🌾", Some("rice.ear")),
+    ("ear_with_hearing_aid", "🦻", Some("ear.aid")),
+    ("earth_africa", "🌍", Some("globe.eu.af")),
+    ("earth_americas", "🌎", Some("globe.am")),
+    ("earth_asia", "🌏", Some("globe.as.au")),
+    ("ecuador", "🇪🇨", None),
+    ("egg", "🥚", Some("egg")),
+    ("eggplant", "🍆", Some("aubergine")),
+    ("egypt", "🇪🇬", None),
+    ("eight", "8\u{fe0f}\u{20e3}", None),
+    ("eight_pointed_black_star", "✴\u{fe0f}", Some("star.box")),
+    ("eight_spoked_asterisk", "✳\u{fe0f}", Some("ast.box")),
+    ("eject_button", "⏏\u{fe0f}", Some("playback.eject")),
+    ("el_salvador", "🇸🇻", None),
+    ("electric_plug", "🔌", Some("powerplug")),
+    ("elephant", "🐘", Some("elephant")),
+    ("elevator", "🛗", Some("elevator")),
+    ("elf", "🧝", Some("elf")),
+    ("elf_man", "🧝\u{200d}♂\u{fe0f}", None),
+    ("elf_woman", "🧝\u{200d}♀\u{fe0f}", None),
+    ("email", "📧", Some("email")),
+    ("empty_nest", "🪹", Some("nest.empty")),
+    ("end", "🔚", Some("arrow.l.end")),
+    (
+        "england",
+        "🏴\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}",
+        None,
+    ),
+    ("envelope", "✉\u{fe0f}", Some("mail")),
+    ("envelope_with_arrow", "📩", Some("mail.arrow")),
+    ("equatorial_guinea", "🇬🇶", None),
+    ("eritrea", "🇪🇷", None),
+    ("es", "🇪🇸", None),
+    ("estonia", "🇪🇪", None),
+    ("ethiopia", "🇪🇹", None),
+    ("eu", "🇪🇺", None),
+    ("euro", "💶", Some("money.euro")),
+    ("european_castle", "🏰", Some("castle.eu")),
+    ("european_post_office", "🏤", Some("post.eu")),
+    ("european_union", "🇪🇺", None),
+    ("evergreen_tree", "🌲", Some("tree.evergreen")),
+    ("exclamation", "❗", Some("excl")),
+    ("exploding_head", "🤯", Some("face.explode")),
+    ("expressionless", "😑", Some("face.straight")),
+    ("eye", "👁\u{fe0f}", Some("eye")),
+    ("eye_speech_bubble", "👁\u{fe0f}\u{200d}🗨\u{fe0f}", None),
+    ("eyeglasses", "👓", Some("glasses")),
+    ("eyes", "👀", Some("eyes")),
+    ("face_exhaling", "😮\u{200d}💨", None),
+    ("face_holding_back_tears", "🥹", Some("face.tear.withheld")),
+    ("face_in_clouds", "😶\u{200d}🌫\u{fe0f}", None),
+    ("face_with_diagonal_mouth", "🫤", Some("face.diagonal")),
+    ("face_with_head_bandage", "🤕", Some("face.bandage")),
+    (
+        "face_with_open_eyes_and_hand_over_mouth",
+        "🫢",
+        Some("face.speak.not"),
+    ),
+    ("face_with_peeking_eye", "🫣", Some("face.peek")),
+    ("face_with_spiral_eyes", "😵\u{200d}💫", None),
+    ("face_with_thermometer", "🤒", Some("face.fever")),
+    ("facepalm", "🤦", Some("person.facepalm")),
+    ("facepunch", "👊", Some("fist.front")),
+    ("factory", "🏭", Some("factory")),
+    ("factory_worker", "🧑\u{200d}🏭", None),
+    ("fairy", "🧚", Some("fairy")),
+    ("fairy_man", "🧚\u{200d}♂\u{fe0f}", None),
+    ("fairy_woman", "🧚\u{200d}♀\u{fe0f}", None),
+    ("falafel", "🧆", Some("falafel")),
+    ("falkland_islands", "🇫🇰", None),
+    ("fallen_leaf", "🍂", Some("leaf.fall")),
+    ("family", "👪", Some("family")),
+    ("family_man_boy", "👨\u{200d}👦", None),
+    ("family_man_boy_boy", "👨\u{200d}👦\u{200d}👦", None),
+    ("family_man_girl", "👨\u{200d}👧", None),
+    ("family_man_girl_boy", "👨\u{200d}👧\u{200d}👦", None),
+    ("family_man_girl_girl", "👨\u{200d}👧\u{200d}👧", None),
+    ("family_man_man_boy", "👨\u{200d}👨\u{200d}👦", None),
+    (
+        "family_man_man_boy_boy",
+        "👨\u{200d}👨\u{200d}👦\u{200d}👦",
+        None,
+    ),
+    ("family_man_man_girl", "👨\u{200d}👨\u{200d}👧", None),
+    (
+        "family_man_man_girl_boy",
+        "👨\u{200d}👨\u{200d}👧\u{200d}👦",
+        None,
+    ),
+    (
+        "family_man_man_girl_girl",
+        "👨\u{200d}👨\u{200d}👧\u{200d}👧",
+        None,
+    ),
+    ("family_man_woman_boy", "👨\u{200d}👩\u{200d}👦", None),
+    (
+        "family_man_woman_boy_boy",
+        "👨\u{200d}👩\u{200d}👦\u{200d}👦",
+        None,
+    ),
+    ("family_man_woman_girl", "👨\u{200d}👩\u{200d}👧", None),
+    (
+        "family_man_woman_girl_boy",
+        "👨\u{200d}👩\u{200d}👧\u{200d}👦",
+        None,
+    ),
+    (
+        "family_man_woman_girl_girl",
+        "👨\u{200d}👩\u{200d}👧\u{200d}👧",
+        None,
+    ),
+    ("family_woman_boy", "👩\u{200d}👦", None),
+    ("family_woman_boy_boy", "👩\u{200d}👦\u{200d}👦", None),
+    ("family_woman_girl", "👩\u{200d}👧", None),
+    ("family_woman_girl_boy", "👩\u{200d}👧\u{200d}👦", None),
+    ("family_woman_girl_girl", "👩\u{200d}👧\u{200d}👧", None),
+    ("family_woman_woman_boy", "👩\u{200d}👩\u{200d}👦", None),
+    (
+        "family_woman_woman_boy_boy",
+        "👩\u{200d}👩\u{200d}👦\u{200d}👦",
+        None,
+    ),
+    ("family_woman_woman_girl", "👩\u{200d}👩\u{200d}👧", None),
+    (
+        "family_woman_woman_girl_boy",
+        "👩\u{200d}👩\u{200d}👧\u{200d}👦",
+        None,
+    ),
+    (
+        "family_woman_woman_girl_girl",
+        "👩\u{200d}👩\u{200d}👧\u{200d}👧",
+        None,
+    ),
+    ("farmer", "🧑\u{200d}🌾", None),
+    ("faroe_islands", "🇫🇴", None),
+    ("fast_forward", "⏩", Some("playback.forward")),
+    ("fax", "📠", Some("fax")),
+    ("fearful", "😨", Some("face.fear")),
+    ("feather", "🪶", Some("feather")),
+    ("feet", "🐾", Some("prints.paw")),
+    ("female_detective", "🕵\u{fe0f}\u{200d}♀\u{fe0f}", None),
+    ("female_sign", "♀\u{fe0f}", None),
+    ("ferris_wheel", "🎡", Some("ferriswheel")),
+    ("ferry", "⛴\u{fe0f}", Some("ship.ferry")),
+    ("field_hockey", "🏑", Some("hockey")),
+    ("fiji", "🇫🇯", None),
+    ("file_cabinet", "🗄\u{fe0f}", Some("cabinet.file")),
+    ("file_folder", "📁", Some("folder")),
+    ("film_projector", "📽\u{fe0f}", Some("projector")),
+    ("film_strip", "🎞\u{fe0f}", Some("film")),
+    ("finland", "🇫🇮", None),
+    ("fire", "🔥", Some("fire")),
+    ("fire_engine", "🚒", Some("fireengine")),
+    ("fire_extinguisher", "🧯", Some("extinguisher")),
+    ("firecracker", "🧨", Some("firecracker")),
+    ("firefighter", "🧑\u{200d}🚒", None),
+    ("fireworks", "🎆", Some("fireworks")),
+    ("first_quarter_moon", "🌓", Some("moon.wax.two")),
+    (
+        "first_quarter_moon_with_face",
+        "🌛",
+        Some("moon.wax.two.face"),
+    ),
+    ("fish", "🐟", Some("fish")),
+    ("fish_cake", "🍥", Some("cake.fish")),
+    ("fishing_pole_and_fish", "🎣", Some("fishing")),
+    ("fist", "✊", Some("fist.raised")),
+    ("fist_left", "🤛", Some("fist.l")),
+    ("fist_oncoming", "👊", Some("fist.front")),
+    ("fist_raised", "✊", Some("fist.raised")),
+    ("fist_right", "🤜", Some("fist.r")),
+    ("five", "5\u{fe0f}\u{20e3}", None),
+    ("flags", "🎏", Some("koinobori")),
+    ("flamingo", "🦩", Some("flamingo")),
+    ("flashlight", "🔦", Some("flashlight")),
+    ("flat_shoe", "🥿", Some("shoe.flat")),
+    ("flatbread", "🫓", Some("flatbread")),
+    ("fleur_de_lis", "⚜\u{fe0f}", Some("fleur")),
+    ("flight_arrival", "🛬", Some("airplane.landing")),
+    ("flight_departure", "🛫", Some("airplane.takeoff")),
+    ("flipper", "🐬", Some("dolphin")),
+    ("floppy_disk", "💾", Some("floppy")),
+    ("flower_playing_cards", "🎴", Some("playingcard.flower")),
+    ("flushed", "😳", Some("face.flush")),
+    ("flute", "🪈", None),
+    ("fly", "🪰", Some("fly")),
+    ("flying_disc", "🥏", Some("frisbee")),
+    ("flying_saucer", "🛸", Some("ufo")),
+    ("fog", "🌫\u{fe0f}", Some("fog")),
+    ("foggy", "🌁", Some("bridge.fog")),
+    ("folding_hand_fan", "🪭", None),
+    ("fondue", "🫕", Some("fondue")),
+    ("foot", "🦶", Some("foot")),
+    ("football", "🏈", Some("football.am")),
+    ("footprints", "👣", Some("prints.foot")),
+    ("fork_and_knife", "🍴", Some("cutlery")),
+    ("fortune_cookie", "🥠", Some("cookie.fortune")),
+    ("fountain", "⛲", Some("fountain")),
+    ("fountain_pen", "🖋\u{fe0f}", Some("pen.fountain")),
+    ("four", "4\u{fe0f}\u{20e3}", None),
+    ("four_leaf_clover", "🍀", Some("leaf.clover.four")),
+    ("fox_face", "🦊", Some("fox")),
+    ("fr", "🇫🇷", None),
+    ("framed_picture", "🖼\u{fe0f}", Some("painting")),
+    ("free", "🆓", Some("free")),
+    ("french_guiana", "🇬🇫", None),
+    ("french_polynesia", "🇵🇫", None),
+    ("french_southern_territories", "🇹🇫", None),
+    ("fried_egg", "🍳", Some("cooking")),
+    ("fried_shrimp", "🍤", Some("shrimp.fried")),
+    ("fries", "🍟", Some("fries")),
+    ("frog", "🐸", Some("frog.face")),
+    ("frowning", "😦", Some("face.concern")),
+    ("frowning_face", "☹\u{fe0f}", Some("face.frown")),
+    ("frowning_man", "🙍\u{200d}♂\u{fe0f}", None),
+    ("frowning_person", "🙍", Some("person.frown")),
+    ("frowning_woman", "🙍\u{200d}♀\u{fe0f}", None),
+    ("fu", "🖕", Some("finger.m")),
+    ("fuelpump", "⛽", Some("fuelpump")),
+    ("full_moon", "🌕", Some("moon.full")),
+    ("full_moon_with_face", "🌝", Some("moon.full.face")),
+    ("funeral_urn", "⚱\u{fe0f}", Some("urn")),
+    ("gabon", "🇬🇦", None),
+    ("gambia", "🇬🇲", None),
+    ("game_die", "🎲", Some("die")),
+    ("garlic", "🧄", Some("garlic")),
+    ("gb", "🇬🇧", None),
+    ("gear", "⚙\u{fe0f}", Some("gear")),
+    ("gem", "💎", Some("gem")),
+    ("gemini", "♊", Some("zodiac.gemini")),
+    ("genie", "🧞", Some("genie")),
+    ("genie_man", "🧞\u{200d}♂\u{fe0f}", None),
+    ("genie_woman", "🧞\u{200d}♀\u{fe0f}", None),
+    ("georgia", "🇬🇪", None),
+    ("ghana", "🇬🇭", None),
+    ("ghost", "👻", Some("ghost")),
+    ("gibraltar", "🇬🇮", None),
+    ("gift", "🎁", Some("present")),
+    ("gift_heart", "💝", Some("heart.ribbon")),
+    ("ginger_root", "🫚", None),
+    ("giraffe", "🦒", Some("giraffe")),
+    ("girl", "👧", Some("girl")),
+    ("globe_with_meridians", "🌐", Some("globe.meridian")),
+    ("gloves", "🧤", Some("gloves")),
+    ("goal_net", "🥅", Some("goal")),
+    ("goat", "🐐", Some("goat")),
+    ("goggles", "🥽", Some("goggles")),
+    ("golf", "⛳", Some("flag.golf")),
+    ("golfing", "🏌\u{fe0f}", Some("golfing")),
+    ("golfing_man", "🏌\u{fe0f}\u{200d}♂\u{fe0f}", None),
+    ("golfing_woman", "🏌\u{fe0f}\u{200d}♀\u{fe0f}", None),
+    ("goose", "🪿", None),
+    ("gorilla", "🦍", Some("gorilla")),
+    ("grapes", "🍇", Some("grapes")),
+    ("greece", "🇬🇷", None),
+    ("green_apple", "🍏", Some("apple.green")),
+    ("green_book", "📗", Some("book.green")),
+    ("green_circle", "🟢", Some("circle.green")),
+    ("green_heart", "💚", Some("heart.green")),
+    ("green_salad", "🥗", Some("salad")),
+    ("green_square", "🟩", Some("square.green")),
+    ("greenland", "🇬🇱", None),
+    ("grenada", "🇬🇩", None),
+    ("grey_exclamation", "❕", Some("excl.white")),
+    ("grey_heart", "🩶", None),
+    ("grey_question", "❔", Some("quest.white")),
+    ("grimacing", "😬", Some("face.teeth")),
+    ("grin", "😁", Some("face.beam")),
+    ("grinning", "😀", Some("face.grin")),
+    ("guadeloupe", "🇬🇵", None),
+    ("guam", "🇬🇺", None),
+    ("guard", "💂", Some("guard.man")),
+    ("guardsman", "💂\u{200d}♂\u{fe0f}", None),
+    ("guardswoman", "💂\u{200d}♀\u{fe0f}", None),
+    ("guatemala", "🇬🇹", None),
+    ("guernsey", "🇬🇬", None),
+    ("guide_dog", "🦮", Some("dog.guide")),
+    ("guinea", "🇬🇳", None),
+    ("guinea_bissau", "🇬🇼", None),
+    ("guitar", "🎸", Some("guitar")),
+    ("gun", "🔫", Some("pistol")),
+    ("guyana", "🇬🇾", None),
+    ("hair_pick", "🪮", None),
+    ("haircut", "💇", Some("haircut")),
+    ("haircut_man", "💇\u{200d}♂\u{fe0f}", None),
+    ("haircut_woman", "💇\u{200d}♀\u{fe0f}", None),
+    ("haiti", "🇭🇹", None),
+    ("hamburger", "🍔", Some("burger")),
+    ("hammer", "🔨", Some("hammer")),
+    ("hammer_and_pick", "⚒\u{fe0f}", Some("hammer.pick")),
+    ("hammer_and_wrench", "🛠\u{fe0f}", Some("hammer.wrench")),
+    ("hamsa", "🪬", Some("hamsa")),
+    ("hamster", "🐹", Some("hamster.face")),
+    ("hand", "✋", Some("hand.raised")),
+    ("hand_over_mouth", "🤭", Some("face.cover")),
+    (
+        "hand_with_index_finger_and_thumb_crossed",
+        "🫰",
+        Some("fingers.snap"),
+    ),
+    ("handbag", "👜", Some("handbag")),
+    ("handball_person", "🤾", Some("handball")),
+    ("handshake", "🤝", Some("hands.shake")),
+    ("hankey", "💩", Some("poo")),
+    ("hash", "#\u{fe0f}\u{20e3}", None),
+    ("hatched_chick", "🐥", Some("chicken.baby")),
+    ("hatching_chick", "🐣", Some("chicken.baby.egg")),
+    ("headphones", "🎧", Some("headphone")),
+    ("headstone", "🪦", Some("tombstone")),
+    ("health_worker", "🧑\u{200d}⚕\u{fe0f}", None),
+    ("hear_no_evil", "🙉", Some("monkey.hear.not")),
+    ("heard_mcdonald_islands", "🇭🇲", None),
+    ("heart", "❤\u{fe0f}", Some("heart")),
+    ("heart_decoration", "💟", Some("heart.box")),
+    ("heart_eyes", "😍", Some("face.heart")),
+    ("heart_eyes_cat", "😻", Some("cat.face.heart")),
+    ("heart_hands", "🫶", Some("hands.heart")),
+    ("heart_on_fire", "❤\u{fe0f}\u{200d}🔥", None),
+    ("heartbeat", "💓", Some("heart.beat")),
+    ("heartpulse", "💗", Some("heart.grow")),
+    ("hearts", "♥\u{fe0f}", Some("suit.heart")),
+    ("heavy_check_mark", "✔\u{fe0f}", Some("checkmark.heavy")),
+    ("heavy_division_sign", "➗", None),
+    ("heavy_dollar_sign", "💲", Some("dollar")),
+    ("heavy_equals_sign", "🟰", None),
+    ("heavy_exclamation_mark", "❗", Some("excl")),
+    ("heavy_heart_exclamation", "❣\u{fe0f}", Some("heart.excl")),
+    ("heavy_minus_sign", "➖", None),
+    ("heavy_multiplication_x", "✖\u{fe0f}", None),
+    ("heavy_plus_sign", "➕", None),
+    ("hedgehog", "🦔", Some("hedgehog")),
+    ("helicopter", "🚁", Some("helicopter")),
+    ("herb", "🌿", Some("leaf.herb")),
+    ("hibiscus", "🌺", Some("flower.hibiscus")),
+    ("high_brightness", "🔆", Some("brightness.high")),
+    ("high_heel", "👠", Some("shoe.heel")),
+    ("hiking_boot", "🥾", Some("shoe.hike")),
+    ("hindu_temple", "🛕", Some("temple")),
+    ("hippopotamus", "🦛", Some("hippo")),
+    ("hocho", "🔪", Some("knife")),
+    ("hole", "🕳\u{fe0f}", Some("hole")),
+    ("honduras", "🇭🇳", None),
+    ("honey_pot", "🍯", Some("honey")),
+    ("honeybee", "🐝", Some("bee")),
+    ("hong_kong", "🇭🇰", None),
+    ("hook", "🪝", Some("hook")),
+    ("horse", "🐴", Some("horse.face")),
+    ("horse_racing", "🏇", Some("horse.race")),
+    ("hospital", "🏥", Some("hospital")),
+    ("hot_face", "🥵", Some("face.heat")),
+    ("hot_pepper", "🌶\u{fe0f}", Some("pepper.hot")),
+    ("hotdog", "🌭", Some("hotdog")),
+    ("hotel", "🏨", Some("hotel")),
+    ("hotsprings", "♨\u{fe0f}", Some("hotspring")),
+    ("hourglass", "⌛", Some("hourglass")),
+    ("hourglass_flowing_sand", "⏳", Some("hourglass.flow")),
+    ("house", "🏠", Some("house")),
+    ("house_with_garden", "🏡", Some("house.garden")),
+    ("houses", "🏘\u{fe0f}", Some("house.multiple")),
+    ("hugs", "🤗", Some("face.hug")),
+    ("hungary", "🇭🇺", None),
+    ("hushed", "😯", Some("face.surprise")),
+    ("hut", "🛖", Some("hut")),
+    ("hyacinth", "🪻", None),
+    ("ice_cream", "🍨", Some("icecream")),
+    ("ice_cube", "🧊", Some("ice")),
+    ("ice_hockey", "🏒", Some("icehockey")),
+    ("ice_skate", "⛸\u{fe0f}", Some("shoe.ice")),
+    ("icecream", "🍦", Some("icecream.soft")),
+    ("iceland", "🇮🇸", None),
+    ("id", "🆔", Some("id")),
+    ("identification_card", "🪪", Some("card.id")),
+    ("ideograph_advantage", "🉐", Some("toku")),
+    ("imp", "👿", Some("face.devil.frown")),
+    ("inbox_tray", "📥", Some("tray.inbox")),
+    ("incoming_envelope", "📨", Some("tray.mail")),
+    ("index_pointing_at_the_viewer", "🫵", Some("finger.front")),
+    ("india", "🇮🇳", None),
+    ("indonesia", "🇮🇩", None),
+    ("infinity", "♾\u{fe0f}", None),
+    ("information_desk_person", "💁", Some("person.sassy")),
+    ("information_source", "ℹ\u{fe0f}", Some("info")),
+    ("innocent", "😇", Some("face.halo")),
+    ("interrobang", "⁉\u{fe0f}", Some("excl.quest")),
+    ("iphone", "📱", Some("phone")),
+    ("iran", "🇮🇷", None),
+    ("iraq", "🇮🇶", None),
+    ("ireland", "🇮🇪", None),
+    ("isle_of_man", "🇮🇲", None),
+    ("israel", "🇮🇱", None),
+    ("it", "🇮🇹", None),
+    ("izakaya_lantern", "🏮", Some("izakaya")),
+    ("jack_o_lantern", "🎃", Some("pumpkin.lantern")),
+    ("jamaica", "🇯🇲", None),
+    ("japan", "🗾", Some("map.jp")),
+    ("japanese_castle", "🏯", Some("castle.jp")),
+    ("japanese_goblin", "👺", Some("tengu")),
+    ("japanese_ogre", "👹", Some("oni")),
+    ("jar", "🫙", Some("jar")),
+    ("jeans", "👖", Some("jeans")),
+    ("jellyfish", "🪼", None),
+    ("jersey", "🇯🇪", None),
+    ("jigsaw", "🧩", Some("jigsaw")),
+    ("jordan", "🇯🇴", None),
+    ("joy", "😂", Some("face.joy")),
+    ("joy_cat", "😹", Some("cat.face.joy")),
+    ("joystick", "🕹\u{fe0f}", Some("joystick")),
+    ("jp", "🇯🇵", None),
+    ("judge", "🧑\u{200d}⚖\u{fe0f}", None),
+    ("juggling_person", "🤹", Some("juggling")),
+    ("kaaba", "🕋", Some("kaaba")),
+    ("kangaroo", "🦘", Some("kangaroo")),
+    ("kazakhstan", "🇰🇿", None),
+    ("kenya", "🇰🇪", None),
+    ("key", "🔑", Some("key")),
+    ("keyboard", "⌨\u{fe0f}", Some("keyboard")),
+    ("keycap_ten", "🔟", Some("ten")),
+    ("khanda", "🪯", None),
+    ("kick_scooter", "🛴", Some("scooter")),
+    ("kimono", "👘", Some("dress.kimono")),
+    ("kiribati", "🇰🇮", None),
+    ("kiss", "💋", Some("kissmark")),
+    ("kissing", "😗", Some("face.kiss")),
+    ("kissing_cat", "😽", Some("cat.face.kiss")),
+    ("kissing_closed_eyes", "😚", Some("face.kiss.blush")),
+    ("kissing_heart", "😘", Some("face.kiss.heart")),
+    ("kissing_smiling_eyes", "😙", Some("face.kiss.smile")),
+    ("kite", "🪁", Some("kite")),
+    ("kiwi_fruit", "🥝", Some("kiwi")),
+    ("kneeling_man", "🧎\u{200d}♂\u{fe0f}", None),
+    ("kneeling_person", "🧎", Some("person.kneel")),
+    ("kneeling_woman", "🧎\u{200d}♀\u{fe0f}", None),
+    ("knife", "🔪", Some("knife")),
+    ("knot", "🪢", Some("knot")),
+    ("koala", "🐨", Some("koala")),
+    ("koko", "🈁", Some("koko")),
+    ("kosovo", "🇽🇰", None),
+    ("kr", "🇰🇷", None),
+    ("kuwait", "🇰🇼", None),
+    ("kyrgyzstan", "🇰🇬", None),
+    ("lab_coat", "🥼", Some("coat.lab")),
+    ("label", "🏷\u{fe0f}", Some("label")),
+    ("lacrosse", "🥍", Some("lacrosse")),
+    ("ladder", "🪜", Some("ladder")),
+    ("lady_beetle", "🐞", Some("beetle.lady")),
+    ("lantern", "🏮", Some("izakaya")),
+    ("laos", "🇱🇦", None),
+    ("large_blue_circle", "🔵", Some("circle.blue")),
+    ("large_blue_diamond", "🔷", Some("diamond.blue")),
+    ("large_orange_diamond", "🔶", Some("diamond.orange")),
+    ("last_quarter_moon", "🌗", Some("moon.wane.two")),
+    (
+        "last_quarter_moon_with_face",
+        "🌜",
+        Some("moon.wane.three.face"),
+    ),
+    ("latin_cross", "✝\u{fe0f}", Some("faith.christ")),
+    ("latvia", "🇱🇻", None),
+    ("laughing", "😆", Some("face.squint")),
+    ("leafy_green", "🥬", Some("spinach")),
+    ("leaves", "🍃", Some("leaf.wind")),
+    ("lebanon", "🇱🇧", None),
+    ("ledger", "📒", Some("book.spiral")),
+    ("left_luggage", "🛅", Some("leftluggage")),
+    ("left_right_arrow", "↔\u{fe0f}", Some("arrow.l.r")),
+    ("left_speech_bubble", "🗨\u{fe0f}", Some("bubble.speech.l")),
+    (
+        "leftwards_arrow_with_hook",
+        "↩\u{fe0f}",
+        Some("arrow.l.hook"),
+    ),
+    ("leftwards_hand", "🫲", Some("hand.l")),
+    ("leftwards_pushing_hand", "🫷", None),
+    ("leg", "🦵", Some("leg")),
+    ("lemon", "🍋", Some("lemon")),
+    ("leo", "♌", Some("zodiac.leo")),
+    ("leopard", "🐆", Some("leopard")),
+    ("lesotho", "🇱🇸", None),
+    ("level_slider", "🎚\u{fe0f}", Some("slider")),
+    ("liberia", "🇱🇷", None),
+    ("libra", "♎", Some("zodiac.libra")),
+    ("libya", "🇱🇾", None),
+    ("liechtenstein", "🇱🇮", None),
+    ("light_blue_heart", "🩵", None),
+    ("light_rail", "🚈", Some("train.light")),
+    ("link", "🔗", Some("chain")),
+    ("lion", "🦁", Some("lion")),
+    ("lips", "👄", Some("mouth")),
+    ("lipstick", "💄", Some("lipstick")),
+    ("lithuania", "🇱🇹", None),
+    ("lizard", "🦎", Some("lizard")),
+    ("llama", "🦙", Some("llama")),
+    ("lobster", "🦞", Some("lobster")),
+    ("lock", "🔒", Some("lock")),
+    ("lock_with_ink_pen", "🔏", Some("lock.pen")),
+    ("lollipop", "🍭", Some("lollipop")),
+    ("long_drum", "🪘", Some("drum.big")),
+    ("loop", "➿", None),
+    ("lotion_bottle", "🧴", Some("lotion")),
+    ("lotus", "🪷", Some("flower.lotus")),
+    ("lotus_position", "🧘", Some("person.lotus")),
+    ("lotus_position_man", "🧘\u{200d}♂\u{fe0f}", None),
+    ("lotus_position_woman", "🧘\u{200d}♀\u{fe0f}", None),
+    ("loud_sound", "🔊", Some("speaker.waves")),
+    ("loudspeaker", "📢", Some("megaphone")),
+    ("love_hotel", "🏩", Some("hotel.love")),
+    ("love_letter", "💌", Some("letter.love")),
+    ("love_you_gesture", "🤟", Some("hand.love")),
+    ("low_battery", "🪫", Some("battery.low")),
+    ("low_brightness", "🔅", Some("brightness.low")),
+    ("luggage", "🧳", Some("luggage")),
+    ("lungs", "🫁", Some("lungs")),
+    ("luxembourg", "🇱🇺", None),
+    ("lying_face", "🤥", Some("face.lie")),
+    ("m", "Ⓜ\u{fe0f}", Some("metro")),
+    ("macau", "🇲🇴", None),
+    ("macedonia", "🇲🇰", None),
+    ("madagascar", "🇲🇬", None),
+    ("mag", "🔍", Some("magnify.l")),
+    ("mag_right", "🔎", Some("magnify.r")),
+    ("mage", "🧙", Some("mage")),
+    ("mage_man", "🧙\u{200d}♂\u{fe0f}", None),
+    ("mage_woman", "🧙\u{200d}♀\u{fe0f}", None),
+    ("magic_wand", "🪄", Some("wand")),
+    ("magnet", "🧲", Some("magnet")),
+    ("mahjong", "🀄", Some("mahjong.dragon.red")),
+    ("mailbox", "📫", Some("mailbox.closed.full")),
+    ("mailbox_closed", "📪", Some("mailbox.closed.empty")),
+    ("mailbox_with_mail", "📬", Some("mailbox.open.full")),
+    ("mailbox_with_no_mail", "📭", Some("mailbox.open.empty")),
+    ("malawi", "🇲🇼", None),
+    ("malaysia", "🇲🇾", None),
+    ("maldives", "🇲🇻", None),
+    ("male_detective", "🕵\u{fe0f}\u{200d}♂\u{fe0f}", None),
+    ("male_sign", "♂\u{fe0f}", None),
+    ("mali", "🇲🇱", None),
+    ("malta", "🇲🇹", None),
+    ("mammoth", "🦣", Some("mammoth")),
+    ("man", "👨", Some("man")),
+    ("man_artist", "👨\u{200d}🎨", None),
+    ("man_astronaut", "👨\u{200d}🚀", None),
+    ("man_beard", "🧔\u{200d}♂\u{fe0f}", None),
+    ("man_cartwheeling", "🤸\u{200d}♂\u{fe0f}", None),
+    ("man_cook", "👨\u{200d}🍳", None),
+    ("man_dancing", "🕺", Some("dancing.man")),
+    ("man_facepalming", "🤦\u{200d}♂\u{fe0f}", None),
+    ("man_factory_worker", "👨\u{200d}🏭", None),
+    ("man_farmer", "👨\u{200d}🌾", None),
+    ("man_feeding_baby", "👨\u{200d}🍼", None),
+    ("man_firefighter", "👨\u{200d}🚒", None),
+    ("man_health_worker", "👨\u{200d}⚕\u{fe0f}", None),
+    ("man_in_manual_wheelchair", "👨\u{200d}🦽", None),
+    ("man_in_motorized_wheelchair", "👨\u{200d}🦼", None),
+    ("man_in_tuxedo", "🤵\u{200d}♂\u{fe0f}", None),
+    ("man_judge", "👨\u{200d}⚖\u{fe0f}", None),
+    ("man_juggling", "🤹\u{200d}♂\u{fe0f}", None),
+    ("man_mechanic", "👨\u{200d}🔧", None),
+    ("man_office_worker", "👨\u{200d}💼", None),
+    ("man_pilot", "👨\u{200d}✈\u{fe0f}", None),
+    ("man_playing_handball", "🤾\u{200d}♂\u{fe0f}", None),
+    ("man_playing_water_polo", "🤽\u{200d}♂\u{fe0f}", None),
+    ("man_scientist", "👨\u{200d}🔬", None),
+    ("man_shrugging", "🤷\u{200d}♂\u{fe0f}", None),
+    ("man_singer", "👨\u{200d}🎤", None),
+    ("man_student", "👨\u{200d}🎓", None),
+    ("man_teacher", "👨\u{200d}🏫", None),
+    ("man_technologist", "👨\u{200d}💻", None),
+    ("man_with_gua_pi_mao", "👲", Some("man.guapimao")),
+    ("man_with_probing_cane", "👨\u{200d}🦯", None),
+    ("man_with_turban", "👳\u{200d}♂\u{fe0f}", None),
+    ("man_with_veil", "👰\u{200d}♂\u{fe0f}", None),
+    ("mandarin", "🍊", Some("tangerine")),
+    ("mango", "🥭", Some("mango")),
+    ("mans_shoe", "👞", Some("shoe")),
+    ("mantelpiece_clock", "🕰\u{fe0f}", Some("clock.old")),
+    ("manual_wheelchair", "🦽", Some("wheelchair")),
+    ("maple_leaf", "🍁", Some("leaf.maple")),
+    ("maracas", "🪇", None),
+    ("marshall_islands", "🇲🇭", None),
+    ("martial_arts_uniform", "🥋", Some("martialarts")),
+    ("martinique", "🇲🇶", None),
+    ("mask", "😷", Some("face.mask")),
+    ("massage", "💆", Some("person.massage")),
+    ("massage_man", "💆\u{200d}♂\u{fe0f}", None),
+    ("massage_woman", "💆\u{200d}♀\u{fe0f}", None),
+    ("mate", "🧉", Some("mate")),
+    ("mauritania", "🇲🇷", None),
+    ("mauritius", "🇲🇺", None),
+    ("mayotte", "🇾🇹", None),
+    ("meat_on_bone", "🍖", Some("meat.bone")),
+    ("mechanic", "🧑\u{200d}🔧", None),
+    ("mechanical_arm", "🦾", Some("arm.mech")),
+    ("mechanical_leg", "🦿", Some("leg.mech")),
+    ("medal_military", "🎖\u{fe0f}", Some("medal.military")),
+    ("medal_sports", "🏅", Some("medal.sports")),
+    ("medical_symbol", "⚕\u{fe0f}", Some("aesculapius")),
+    ("mega", "📣", Some("megaphone.simple")),
+    ("melon", "🍈", Some("melon")),
+    ("melting_face", "🫠", Some("face.melt")),
+    ("memo", "📝", Some("page.pencil")),
+    ("men_wrestling", "🤼\u{200d}♂\u{fe0f}", None),
+    ("mending_heart", "❤\u{fe0f}\u{200d}🩹", None),
+    ("menorah", "🕎", Some("faith.menorah")),
+    ("mens", "🚹", Some("man.box")),
+    ("mermaid", "🧜\u{200d}♀\u{fe0f}", None),
+    ("merman", "🧜\u{200d}♂\u{fe0f}", None),
+    ("merperson", "🧜", Some("merperson")),
+    ("metal", "🤘", Some("hand.rock")),
+    ("metro", "🚇", Some("train.metro")),
+    ("mexico", "🇲🇽", None),
+    ("microbe", "🦠", Some("microbe")),
+    ("micronesia", "🇫🇲", None),
+    ("microphone", "🎤", Some("microphone")),
+    ("microscope", "🔬", Some("microscope")),
+    ("middle_finger", "🖕", Some("finger.m")),
+    ("military_helmet", "🪖", Some("helmet.military")),
+    ("milk_glass", "🥛", Some("glass.milk")),
+    ("milky_way", "🌌", Some("milkyway")),
+    ("minibus", "🚐", Some("bus.small")),
+    ("minidisc", "💽", Some("disc.mini")),
+    ("mirror", "🪞", Some("mirror")),
+    ("mirror_ball", "🪩", Some("discoball")),
+    ("mobile_phone_off", "📴", Some("phone.off")),
+    ("moldova", "🇲🇩", None),
+    ("monaco", "🇲🇨", None),
+    ("money_mouth_face", "🤑", Some("face.money")),
+    ("money_with_wings", "💸", Some("money.wings")),
+    ("moneybag", "💰", Some("money.bag")),
+    ("mongolia", "🇲🇳", None),
+    ("monkey", "🐒", Some("monkey")),
+    ("monkey_face", "🐵", Some("monkey.face")),
+    ("monocle_face", "🧐", Some("face.monocle")),
+    ("monorail", "🚝", Some("train.mono")),
+    ("montenegro", "🇲🇪", None),
+    ("montserrat", "🇲🇸", None),
+    ("moon", "🌔", Some("moon.wax.three")),
+    ("moon_cake", "🥮", Some("cake.moon")),
+    ("moose", "🫎", None),
+    ("morocco", "🇲🇦", None),
+    ("mortar_board", "🎓", Some("mortarboard")),
+    ("mosque", "🕌", Some("mosque")),
+    ("mosquito", "🦟", Some("mosquito")),
+    ("motor_boat", "🛥\u{fe0f}", Some("boat.motor")),
+    ("motor_scooter", "🛵", Some("scooter.motor")),
+    ("motorcycle", "🏍\u{fe0f}", Some("motorcycle")),
+    ("motorized_wheelchair", "🦼", Some("wheelchair.motor")),
+    ("motorway", "🛣\u{fe0f}", Some("motorway")),
+    ("mount_fuji", "🗻", Some("mountain.fuji")),
+    ("mountain", "⛰\u{fe0f}", Some("mountain")),
+    ("mountain_bicyclist", "🚵", Some("bicyclist.mountain")),
+    ("mountain_biking_man", "🚵\u{200d}♂\u{fe0f}", None),
+    ("mountain_biking_woman", "🚵\u{200d}♀\u{fe0f}", None),
+    ("mountain_cableway", "🚠", Some("cablecar")),
+    ("mountain_railway", "🚞", Some("train.mountain")),
+    ("mountain_snow", "🏔\u{fe0f}", Some("mountain.snow")),
+    ("mouse", "🐭", Some("mouse.face")),
+    ("mouse2", "🐁", Some("mouse")),
+    ("mouse_trap", "🪤", Some("mousetrap")),
+    ("movie_camera", "🎥", Some("camera.movie")),
+    ("moyai", "🗿", Some("moyai")),
+    ("mozambique", "🇲🇿", None),
+    ("mrs_claus", "🤶", Some("santa.woman")),
+    ("muscle", "💪", Some("arm.muscle")),
+    ("mushroom", "🍄", Some("mushroom")),
+    ("musical_keyboard", "🎹", Some("piano")),
+    ("musical_note", "🎵", Some("notes")),
+    ("musical_score", "🎼", Some("musicalscore")),
+    ("mute", "🔇", Some("speaker.not")),
+    ("mx_claus", "🧑\u{200d}🎄", None),
+    ("myanmar", "🇲🇲", None),
+    ("nail_care", "💅", Some("nails.polish")),
+    ("name_badge", "📛", Some("namebadge")),
+    ("namibia", "🇳🇦", None),
+    ("national_park", "🏞\u{fe0f}", Some("park")),
+    ("nauru", "🇳🇷", None),
+    ("nauseated_face", "🤢", Some("face.nausea")),
+    ("nazar_amulet", "🧿", Some("nazar")),
+    ("necktie", "👔", Some("necktie")),
+    ("negative_squared_cross_mark", "❎", Some("crossmark.box")),
+    ("nepal", "🇳🇵", None),
+    ("nerd_face", "🤓", Some("face.nerd")),
+    ("nest_with_eggs", "🪺", Some("nest.eggs")),
+    ("nesting_dolls", "🪆", Some("matryoshka")),
+    ("netherlands", "🇳🇱", None),
+    ("neutral_face", "😐", Some("face.neutral")),
+    ("new", "🆕", Some("new")),
+    ("new_caledonia", "🇳🇨", None),
+    ("new_moon", "🌑", Some("moon.new")),
+    ("new_moon_with_face", "🌚", Some("moon.new.face")),
+    ("new_zealand", "🇳🇿", None),
+    ("newspaper", "📰", Some("newspaper")),
+    ("newspaper_roll", "🗞\u{fe0f}", Some("newspaper.rolled")),
+    ("next_track_button", "⏭\u{fe0f}", Some("playback.skip")),
+    ("ng", "🆖", Some("ng")),
+    ("ng_man", "🙅\u{200d}♂\u{fe0f}", None),
+    ("ng_woman", "🙅\u{200d}♀\u{fe0f}", None),
+    ("nicaragua", "🇳🇮", None),
+    ("niger", "🇳🇪", None),
+    ("nigeria", "🇳🇬", None),
+    ("night_with_stars", "🌃", Some("city.night")),
+    ("nine", "9\u{fe0f}\u{20e3}", None),
+    ("ninja", "🥷", Some("ninja")),
+    ("niue", "🇳🇺", None),
+    ("no_bell", "🔕", Some("bell.not")),
+    ("no_bicycles", "🚳", Some("bike.not")),
+    ("no_entry", "⛔", Some("noentry")),
+    ("no_entry_sign", "🚫", Some("prohibited")),
+    ("no_good", "🙅", Some("person.no")),
+    ("no_good_man", "🙅\u{200d}♂\u{fe0f}", None),
+    ("no_good_woman", "🙅\u{200d}♀\u{fe0f}", None),
+    ("no_mobile_phones", "📵", Some("phone.not")),
+    ("no_mouth", "😶", Some("face.blank")),
+    ("no_pedestrians", "🚷", Some("pedestrian.not")),
+    ("no_smoking", "🚭", Some("cigarette.not")),
+    ("non-potable_water", "🚱", Some("tap.not")),
+    ("norfolk_island", "🇳🇫", None),
+    ("north_korea", "🇰🇵", None),
+    ("northern_mariana_islands", "🇲🇵", None),
+    ("norway", "🇳🇴", None),
+    ("nose", "👃", Some("nose")),
+    ("notebook", "📓", Some("notebook")),
+    (
+        "notebook_with_decorative_cover",
+        "📔",
+        Some("notebook.deco"),
+    ),
+    ("notes", "🎶", Some("notes.triple")),
+    ("nut_and_bolt", "🔩", Some("bolt")),
+    ("o", "⭕", Some("circle.stroked")),
+    ("o2", "🅾\u{fe0f}", Some("o")),
+    ("ocean", "🌊", Some("wave")),
+    ("octopus", "🐙", Some("octopus")),
+    ("oden", "🍢", Some("skewer.oden")),
+    ("office", "🏢", Some("office")),
+    ("office_worker", "🧑\u{200d}💼", None),
+    ("oil_drum", "🛢\u{fe0f}", Some("oil")),
+    ("ok", "🆗", Some("ok")),
+    ("ok_hand", "👌", Some("hand.ok")),
+    ("ok_man", "🙆\u{200d}♂\u{fe0f}", None),
+    ("ok_person", "🙆", Some("person.ok")),
+    ("ok_woman", "🙆\u{200d}♀\u{fe0f}", None),
+    ("old_key", "🗝\u{fe0f}", Some("key.old")),
+    ("older_adult", "🧓", Some("person.old")),
+    ("older_man", "👴", Some("man.old")),
+    ("older_woman", "👵", Some("woman.old")),
+    ("olive", "🫒", Some("olive")),
+    ("om", "🕉\u{fe0f}", Some("faith.om")),
+    ("oman", "🇴🇲", None),
+    ("on", "🔛", Some("arrow.l.r.on")),
+    ("oncoming_automobile", "🚘", Some("car.front")),
+    ("oncoming_bus", "🚍", Some("bus.front")),
+    ("oncoming_police_car", "🚔", Some("car.police.front")),
+    ("oncoming_taxi", "🚖", Some("taxi.front")),
+    ("one", "1\u{fe0f}\u{20e3}", None),
+    ("one_piece_swimsuit", "🩱", Some("swimsuit")),
+    ("onion", "🧅", Some("onion")),
+    ("open_book", "📖", Some("book.open")),
+    ("open_file_folder", "📂", Some("folder.open")),
+    ("open_hands", "👐", Some("hands.open")),
+    ("open_mouth", "😮", Some("face.wow")),
+    ("open_umbrella", "☂\u{fe0f}", Some("umbrella.open")),
+    ("ophiuchus", "⛎", Some("zodiac.ophi")),
+    ("orange", "🍊", Some("tangerine")),
+    ("orange_book", "📙", Some("book.orange")),
+    ("orange_circle", "🟠", Some("circle.orange")),
+    ("orange_heart", "🧡", Some("heart.orange")),
+    ("orange_square", "🟧", Some("square.orange")),
+    ("orangutan", "🦧", Some("orangutan")),
+    ("orthodox_cross", "☦\u{fe0f}", Some("faith.orthodox")),
+    ("otter", "🦦", Some("otter")),
+    ("outbox_tray", "📤", Some("tray.outbox")),
+    ("owl", "🦉", Some("owl")),
+    ("ox", "🐂", Some("ox")),
+    ("oyster", "🦪", Some("oyster")),
+    ("package", "📦", Some("package")),
+    ("page_facing_up", "📄", Some("page")),
+    ("page_with_curl", "📃", Some("page.curl")),
+    ("pager", "📟", Some("pager")),
+    ("paintbrush", "🖌\u{fe0f}", Some("brush")),
+    ("pakistan", "🇵🇰", None),
+    ("palau", "🇵🇼", None),
+    ("palestinian_territories", "🇵🇸", None),
+    ("palm_down_hand", "🫳", Some("hand.b")),
+    ("palm_tree", "🌴", Some("tree.palm")),
+    ("palm_up_hand", "🫴", Some("hand.t")),
+    ("palms_up_together", "🤲", Some("hands.palms")),
+    ("panama", "🇵🇦", None),
+    ("pancakes", "🥞", Some("pancakes")),
+    ("panda_face", "🐼", Some("panda")),
+    ("paperclip", "📎", Some("clip")),
+    ("paperclips", "🖇\u{fe0f}", Some("clips")),
+    ("papua_new_guinea", "🇵🇬", None),
+    ("parachute", "🪂", Some("parachute")),
+    ("paraguay", "🇵🇾", None),
+    ("parasol_on_ground", "⛱\u{fe0f}", Some("umbrella.sun")),
+    ("parking", "🅿\u{fe0f}", Some("parking")),
+    ("parrot", "🦜", Some("parrot")),
+    (
+        "part_alternation_mark",
+        "〽\u{fe0f}",
+        Some("partalteration"),
+    ),
+    ("partly_sunny", "⛅", Some("cloud.sun")),
+    ("partying_face", "🥳", Some("face.party")),
+    ("passenger_ship", "🛳\u{fe0f}", Some("ship.cruise")),
+    ("passport_control", "🛂", Some("bordercontrol")),
+    ("pause_button", "⏸\u{fe0f}", Some("playback.pause")),
+    ("paw_prints", "🐾", Some("prints.paw")),
+    ("pea_pod", "🫛", None),
+    ("peace_symbol", "☮\u{fe0f}", Some("faith.peace")),
+    ("peach", "🍑", Some("peach")),
+    ("peacock", "🦚", Some("peacock")),
+    ("peanuts", "🥜", Some("peanuts")),
+    ("pear", "🍐", Some("pear")),
+    ("pen", "🖊\u{fe0f}", Some("pen.ball")),
+    ("pencil", "📝", Some("page.pencil")),
+    ("pencil2", "✏\u{fe0f}", Some("pencil")),
+    ("penguin", "🐧", Some("penguin")),
+    ("pensive", "😔", Some("face.sad")),
+    ("people_holding_hands", "🧑\u{200d}🤝\u{200d}🧑", None),
+    ("people_hugging", "🫂", Some("silhouette.hug")),
+    ("performing_arts", "🎭", Some("masks")),
+    ("persevere", "😣", Some("face.frust")),
+    ("person_bald", "🧑\u{200d}🦲", None),
+    ("person_curly_hair", "🧑\u{200d}🦱", None),
+    ("person_feeding_baby", "🧑\u{200d}🍼", None),
+    ("person_fencing", "🤺", Some("fencing")),
+    ("person_in_manual_wheelchair", "🧑\u{200d}🦽", None),
+    ("person_in_motorized_wheelchair", "🧑\u{200d}🦼", None),
+    ("person_in_tuxedo", "🤵", Some("man.tuxedo")),
+    ("person_red_hair", "🧑\u{200d}🦰", None),
+    ("person_white_hair", "🧑\u{200d}🦳", None),
+    ("person_with_crown", "🫅", Some("person.crown")),
+    ("person_with_probing_cane", "🧑\u{200d}🦯", None),
+    ("person_with_turban", "👳", Some("man.turban")),
+    ("person_with_veil", "👰", Some("bride")),
+    ("peru", "🇵🇪", None),
+    ("petri_dish", "🧫", Some("petri")),
+    ("philippines", "🇵🇭", None),
+    ("phone", "☎\u{fe0f}", Some("phone.classic")),
+    ("pick", "⛏\u{fe0f}", Some("pick")),
+    ("pickup_truck", "🛻", Some("car.pickup")),
+    ("pie", "🥧", Some("pie")),
+    ("pig", "🐷", Some("pig.face")),
+    ("pig2", "🐖", Some("pig")),
+    ("pig_nose", "🐽", Some("pig.nose")),
+    ("pill", "💊", Some("pill")),
+    ("pilot", "🧑\u{200d}✈\u{fe0f}", None),
+    ("pinata", "🪅", Some("pinata")),
+    ("pinched_fingers", "🤌", Some("fingers.pinch")),
+    ("pinching_hand", "🤏", Some("hand.pinch")),
+    ("pineapple", "🍍", Some("pineapple")),
+    ("ping_pong", "🏓", Some("pingpong")),
+    ("pink_heart", "🩷", None),
+    ("pirate_flag", "🏴\u{200d}☠\u{fe0f}", None),
+    ("pisces", "♓", Some("zodiac.pisces")),
+    ("pitcairn_islands", "🇵🇳", None),
+    ("pizza", "🍕", Some("pizza")),
+    ("placard", "🪧", Some("placard")),
+    ("place_of_worship", "🛐", Some("faith.worship")),
+    ("plate_with_cutlery", "🍽\u{fe0f}", Some("plate.cutlery")),
+    ("play_or_pause_button", "⏯\u{fe0f}", Some("playback.toggle")),
+    ("playground_slide", "🛝", Some("slide")),
+    ("pleading_face", "🥺", Some("face.plead")),
+    ("plunger", "🪠", Some("plunger")),
+    ("point_down", "👇", Some("finger.b")),
+    ("point_left", "👈", Some("finger.l")),
+    ("point_right", "👉", Some("finger.r")),
+    ("point_up", "☝\u{fe0f}", Some("finger.t.alt")),
+    ("point_up_2", "👆", Some("finger.t")),
+    ("poland", "🇵🇱", None),
+    ("polar_bear", "🐻\u{200d}❄\u{fe0f}", None),
+    ("police_car", "🚓", Some("car.police")),
+    ("police_officer", "👮", Some("policeofficer")),
+    ("policeman", "👮\u{200d}♂\u{fe0f}", None),
+    ("policewoman", "👮\u{200d}♀\u{fe0f}", None),
+    ("poodle", "🐩", Some("dog.poodle")),
+    ("poop", "💩", Some("poo")),
+    ("popcorn", "🍿", Some("popcorn")),
+    ("portugal", "🇵🇹", None),
+    ("post_office", "🏣", Some("post.jp")),
+    ("postal_horn", "📯", Some("horn.postal")),
+    ("postbox", "📮", Some("postbox")),
+    ("potable_water", "🚰", Some("tap")),
+    ("potato", "🥔", Some("potato")),
+    ("potted_plant", "🪴", Some("plant")),
+    ("pouch", "👝", Some("pouch")),
+    ("poultry_leg", "🍗", Some("chicken.leg")),
+    ("pound", "💷", Some("money.pound")),
+    ("pouring_liquid", "🫗", Some("glass.pour")),
+    ("pout", "😡", Some("face.angry.red")),
+    ("pouting_cat", "😾", Some("cat.face.angry")),
+    ("pouting_face", "🙎", Some("person.angry")),
+    ("pouting_man", "🙎\u{200d}♂\u{fe0f}", None),
+    ("pouting_woman", "🙎\u{200d}♀\u{fe0f}", None),
+    ("pray", "🙏", Some("hands.folded")),
+    ("prayer_beads", "📿", Some("beads")),
+    ("pregnant_man", "🫃", Some("man.pregnant")),
+    ("pregnant_person", "🫄", Some("person.pregnant")),
+    ("pregnant_woman", "🤰", Some("woman.pregnant")),
+    ("pretzel", "🥨", Some("pretzel")),
+    (
+        "previous_track_button",
+        "⏮\u{fe0f}",
+        Some("playback.restart"),
+    ),
+    ("prince", "🤴", Some("man.crown")),
+    ("princess", "👸", Some("woman.crown")),
+    ("printer", "🖨\u{fe0f}", Some("printer")),
+    ("probing_cane", "🦯", Some("cane")),
+    ("puerto_rico", "🇵🇷", None),
+    ("punch", "👊", Some("fist.front")),
+    ("purple_circle", "🟣", Some("circle.purple")),
+    ("purple_heart", "💜", Some("heart.purple")),
+    ("purple_square", "🟪", Some("square.purple")),
+    ("purse", "👛", Some("purse")),
+    ("pushpin", "📌", Some("pin")),
+    ("put_litter_in_its_place", "🚮", Some("litter")),
+    ("qatar", "🇶🇦", None),
+    ("question", "❓", Some("quest")),
+    ("rabbit", "🐰", Some("rabbit.face")),
+    ("rabbit2", "🐇", Some("rabbit")),
+    ("raccoon", "🦝", Some("raccoon")),
+    ("racehorse", "🐎", Some("horse")),
+    ("racing_car", "🏎\u{fe0f}", Some("car.racing")),
+    ("radio", "📻", Some("radio")),
+    ("radio_button", "🔘", Some("button.radio")),
+    ("radioactive", "☢\u{fe0f}", Some("radioactive")),
+    ("rage", "😡", Some("face.angry.red")),
+    ("railway_car", "🚃", Some("train.car")),
+    ("railway_track", "🛤\u{fe0f}", Some("railway")),
+    ("rainbow", "🌈", Some("rainbow")),
+    ("rainbow_flag", "🏳\u{fe0f}\u{200d}🌈", None),
+    ("raised_back_of_hand", "🤚", Some("hand.raised.alt")),
+    ("raised_eyebrow", "🤨", Some("face.skeptic")),
+    ("raised_hand", "✋", Some("hand.raised")),
+    (
+        "raised_hand_with_fingers_splayed",
+        "🖐\u{fe0f}",
+        Some("hand.splay"),
+    ),
+    ("raised_hands", "🙌", Some("hands.raised")),
+    ("raising_hand", "🙋", Some("person.raise")),
+    ("raising_hand_man", "🙋\u{200d}♂\u{fe0f}", None),
+    ("raising_hand_woman", "🙋\u{200d}♀\u{fe0f}", None),
+    ("ram", "🐏", Some("ram")),
+    ("ramen", "🍜", Some("bowl.steam")),
+    ("rat", "🐀", Some("rat")),
+    ("razor", "🪒", Some("razor")),
+    ("receipt", "🧾", Some("receipt")),
+    ("record_button", "⏺\u{fe0f}", Some("playback.record")),
+    ("recycle", "♻\u{fe0f}", Some("recycling")),
+    ("red_car", "🚗", Some("car")),
+    ("red_circle", "🔴", Some("circle.red")),
+    ("red_envelope", "🧧", Some("hongbao")),
+    ("red_haired_man", "👨\u{200d}🦰", None),
+    ("red_haired_woman", "👩\u{200d}🦰", None),
+    ("red_square", "🟥", Some("square.red")),
+    ("registered", "®\u{fe0f}", Some("reg")),
+    ("relaxed", "☺\u{fe0f}", Some("face.friendly")),
+    ("relieved", "😌", Some("face.relief")),
+    ("reminder_ribbon", "🎗\u{fe0f}", Some("ribbon.remind")),
+    ("repeat", "🔁", Some("playback.repeat")),
+    ("repeat_one", "🔂", Some("playback.repeat.once")),
+    ("rescue_worker_helmet", "⛑\u{fe0f}", Some("helmet.cross")),
+    ("restroom", "🚻", Some("restroom")),
+    ("reunion", "🇷🇪", None),
+    ("revolving_hearts", "💞", Some("heart.revolve")),
+    ("rewind", "⏪", Some("playback.rewind")),
+    ("rhinoceros", "🦏", Some("rhino")),
+    ("ribbon", "🎀", Some("ribbon")),
+    ("rice", "🍚", Some("rice")),
+    ("rice_ball", "🍙", Some("rice.onigiri")),
+    ("rice_cracker", "🍘", Some("rice.cracker")),
+    ("rice_scene", "🎑", Some("tsukimi")),
+    ("right_anger_bubble", "🗯\u{fe0f}", Some("bubble.anger.r")),
+    ("rightwards_hand", "🫱", Some("hand.r")),
+    ("rightwards_pushing_hand", "🫸", None),
+    ("ring", "💍", Some("ring")),
+    ("ring_buoy", "🛟", Some("ringbuoy")),
+    ("ringed_planet", "🪐", Some("planet")),
+    ("robot", "🤖", Some("robot")),
+    ("rock", "🪨", Some("rock")),
+    ("rocket", "🚀", Some("rocket")),
+    ("rofl", "🤣", Some("face.rofl")),
+    ("roll_eyes", "🙄", Some("face.eyeroll")),
+    ("roll_of_paper", "🧻", Some("toiletpaper")),
+    ("roller_coaster", "🎢", Some("rollercoaster")),
+    ("roller_skate", "🛼", Some("shoe.roller")),
+    ("romania", "🇷🇴", None),
+    ("rooster", "🐓", Some("chicken.male")),
+    ("rose", "🌹", Some("flower.rose")),
+    ("rosette", "🏵\u{fe0f}", Some("rosette")),
+    ("rotating_light", "🚨", Some("siren")),
+    ("round_pushpin", "📍", Some("pin.round")),
+    ("rowboat", "🚣", Some("boat.row")),
+    ("rowing_man", "🚣\u{200d}♂\u{fe0f}", None),
+    ("rowing_woman", "🚣\u{200d}♀\u{fe0f}", None),
+    ("ru", "🇷🇺", None),
+    ("rugby_football", "🏉", Some("rugby")),
+    ("runner", "🏃", Some("running")),
+    ("running", "🏃", Some("running")),
+    ("running_man", "🏃\u{200d}♂\u{fe0f}", None),
+    ("running_shirt_with_sash", "🎽", Some("shirt.sports")),
+    ("running_woman", "🏃\u{200d}♀\u{fe0f}", None),
+    ("rwanda", "🇷🇼", None),
+    ("sa", "🈂\u{fe0f}", Some("sa")),
+    ("safety_pin", "🧷", Some("safetypin")),
+    ("safety_vest", "🦺", Some("safetyvest")),
+    ("sagittarius", "♐", Some("zodiac.sagit")),
+    ("sailboat", "⛵", Some("boat.sail")),
+    ("sake", "🍶", Some("sake")),
+    ("salt", "🧂", Some("salt")),
+    ("saluting_face", "🫡", Some("face.salute")),
+    ("samoa", "🇼🇸", None),
+    ("san_marino", "🇸🇲", None),
+    ("sandal", "👡", Some("shoe.sandal.heel")),
+    ("sandwich", "🥪", Some("sandwich")),
+    ("santa", "🎅", Some("santa.man")),
+    ("sao_tome_principe", "🇸🇹", None),
+    ("sari", "🥻", Some("dress.sari")),
+    ("sassy_man", "💁\u{200d}♂\u{fe0f}", None),
+    ("sassy_woman", "💁\u{200d}♀\u{fe0f}", None),
+    ("satellite", "📡", Some("satdish")),
+    ("satisfied", "😆", Some("face.squint")),
+    ("saudi_arabia", "🇸🇦", None),
+    ("sauna_man", "🧖\u{200d}♂\u{fe0f}", None),
+    ("sauna_person", "🧖", Some("person.steam")),
+    ("sauna_woman", "🧖\u{200d}♀\u{fe0f}", None),
+    ("sauropod", "🦕", Some("dino.pod")),
+    ("saxophone", "🎷", Some("saxophone")),
+    ("scarf", "🧣", Some("scarf")),
+    ("school", "🏫", Some("school")),
+    ("school_satchel", "🎒", Some("backpack")),
+    ("scientist", "🧑\u{200d}🔬", None),
+    ("scissors", "✂\u{fe0f}", Some("scissors")),
+    ("scorpion", "🦂", Some("scorpion")),
+    ("scorpius", "♏", Some("zodiac.scorpio")),
+    (
+        "scotland",
+        "🏴\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}",
+        None,
+    ),
+    ("scream", "😱", Some("face.shock")),
+    ("scream_cat", "🙀", Some("cat.face.shock")),
+    ("screwdriver", "🪛", Some("screwdriver")),
+    ("scroll", "📜", Some("scroll")),
+    ("seal", "🦭", Some("seal")),
+    ("seat", "💺", Some("seat")),
+    ("secret", "㊙\u{fe0f}", Some("hi")),
+    ("see_no_evil", "🙈", Some("monkey.see.not")),
+    ("seedling", "🌱", Some("seedling")),
+    ("selfie", "🤳", Some("arm.selfie")),
+    ("senegal", "🇸🇳", None),
+    ("serbia", "🇷🇸", None),
+    ("service_dog", "🐕\u{200d}🦺", None),
+    ("seven", "7\u{fe0f}\u{20e3}", None),
+    ("sewing_needle", "🪡", Some("needle")),
+    ("seychelles", "🇸🇨", None),
+    ("shaking_face", "🫨", None),
+    ("shallow_pan_of_food", "🥘", Some("paella")),
+    ("shamrock", "☘\u{fe0f}", Some("leaf.clover.three")),
+    ("shark", "🦈", Some("shark")),
+    ("shaved_ice", "🍧", Some("icecream.shaved")),
+    ("sheep", "🐑", Some("sheep")),
+    ("shell", "🐚", Some("shell.spiral")),
+    ("shield", "🛡\u{fe0f}", Some("shield")),
+    ("shinto_shrine", "⛩\u{fe0f}", Some("shrine")),
+    ("ship", "🚢", Some("ship")),
+    ("shirt", "👕", Some("shirt.t")),
+    ("shit", "💩", Some("poo")),
+    ("shoe", "👞", Some("shoe")),
+    ("shopping", "🛍\u{fe0f}", Some("shopping")),
+    ("shopping_cart", "🛒", Some("cart")),
+    ("shorts", "🩳", Some("shorts")),
+    ("shower", "🚿", Some("shower")),
+    ("shrimp", "🦐", Some("shrimp")),
+    ("shrug", "🤷", Some("person.shrug")),
+    ("shushing_face", "🤫", Some("face.shush")),
+    ("sierra_leone", "🇸🇱", None),
+    ("signal_strength", "📶", Some("phone.signal")),
+    ("singapore", "🇸🇬", None),
+    ("singer", "🧑\u{200d}🎤", None),
+    ("sint_maarten", "🇸🇽", None),
+    ("six", "6\u{fe0f}\u{20e3}", None),
+    ("six_pointed_star", "🔯", Some("faith.star.dot")),
+    ("skateboard", "🛹", Some("skateboard")),
+    ("ski", "🎿", Some("shoe.ski")),
+    ("skier", "⛷\u{fe0f}", Some("skiing")),
+    ("skull", "💀", Some("skull")),
+    ("skull_and_crossbones", "☠\u{fe0f}", Some("skull.bones")),
+    ("skunk", "🦨", Some("skunk")),
+    ("sled", "🛷", Some("sled")),
+    ("sleeping", "😴", Some("face.sleep")),
+    ("sleeping_bed", "🛌", Some("bed.person")),
+    ("sleepy", "😪", Some("face.sleepy")),
+    ("slightly_frowning_face", "🙁", Some("face.frown.slight")),
+    ("slightly_smiling_face", "🙂", Some("face.smile.slight")),
+    ("slot_machine", "🎰", Some("slots")),
+    ("sloth", "🦥", Some("sloth")),
+    ("slovakia", "🇸🇰", None),
+    ("slovenia", "🇸🇮", None),
+    ("small_airplane", "🛩\u{fe0f}", Some("airplane.small")),
+    ("small_blue_diamond", "🔹", Some("diamond.blue.small")),
+    ("small_orange_diamond", "🔸", Some("diamond.orange.small")),
+    ("small_red_triangle", "🔺", Some("triangle.t.red")),
+    ("small_red_triangle_down", "🔻", Some("triangle.b.red")),
+    ("smile", "😄", Some("face.smile")),
+    ("smile_cat", "😸", Some("cat.face.laugh")),
+    ("smiley", "😃", Some("face.open")),
+    ("smiley_cat", "😺", Some("cat.face.smile")),
+    ("smiling_face_with_tear", "🥲", Some("face.smile.tear")),
+    ("smiling_face_with_three_hearts", "🥰", Some("face.hearts")),
+    ("smiling_imp", "😈", Some("face.devil.smile")),
+    ("smirk", "😏", Some("face.smirk")),
+    ("smirk_cat", "😼", Some("cat.face.smirk")),
+    ("smoking", "🚬", Some("cigarette")),
+    ("snail", "🐌", Some("snail")),
+    ("snake", "🐍", Some("snake")),
+    ("sneezing_face", "🤧", Some("face.sneeze")),
+    ("snowboarder", "🏂", Some("snowboarding")),
+    ("snowflake", "❄\u{fe0f}", Some("snowflake")),
+    ("snowman", "⛄", Some("snowman")),
+    ("snowman_with_snow", "☃\u{fe0f}", Some("snowman.snow")),
+    ("soap", "🧼", Some("soap")),
+    ("sob", "😭", Some("face.cry")),
+    ("soccer", "⚽", Some("football")),
+    ("socks", "🧦", Some("socks")),
+    ("softball", "🥎", Some("softball")),
+    ("solomon_islands", "🇸🇧", None),
+    ("somalia", "🇸🇴", None),
+    ("soon", "🔜", Some("arrow.r.soon")),
+    ("sos", "🆘", Some("sos")),
+    ("sound", "🔉", Some("speaker.wave")),
+    ("south_africa", "🇿🇦", None),
+    ("south_georgia_south_sandwich_islands", "🇬🇸", None),
+    ("south_sudan", "🇸🇸", None),
+    ("space_invader", "👾", Some("alien.monster")),
+    ("spades", "♠\u{fe0f}", Some("suit.spade")),
+    ("spaghetti", "🍝", Some("spaghetti")),
+    ("sparkle", "❇\u{fe0f}", Some("sparkle.box")),
+    ("sparkler", "🎇", Some("sparkler")),
+    ("sparkles", "✨", Some("sparkles")),
+    ("sparkling_heart", "💖", Some("heart.spark")),
+    ("speak_no_evil", "🙊", Some("monkey.speak.not")),
+    ("speaker", "🔈", Some("speaker")),
+    ("speaking_head", "🗣\u{fe0f}", Some("silhouette.speak")),
+    ("speech_balloon", "💬", Some("bubble.speech.r")),
+    ("speedboat", "🚤", Some("boat.speed")),
+    ("spider", "🕷\u{fe0f}", Some("spider")),
+    ("spider_web", "🕸\u{fe0f}", Some("spiderweb")),
+    ("spiral_calendar", "🗓\u{fe0f}", Some("calendar.spiral")),
+    ("spiral_notepad", "🗒\u{fe0f}", Some("notepad")),
+    ("sponge", "🧽", Some("sponge")),
+    ("spoon", "🥄", Some("spoon")),
+    ("squid", "🦑", Some("squid")),
+    ("sri_lanka", "🇱🇰", None),
+    ("st_barthelemy", "🇧🇱", None),
+    ("st_helena", "🇸🇭", None),
+    ("st_kitts_nevis", "🇰🇳", None),
+    ("st_lucia", "🇱🇨", None),
+    ("st_martin", "🇲🇫", None),
+    ("st_pierre_miquelon", "🇵🇲", None),
+    ("st_vincent_grenadines", "🇻🇨", None),
+    ("stadium", "🏟\u{fe0f}", Some("stadium")),
+    ("standing_man", "🧍\u{200d}♂\u{fe0f}", None),
+    ("standing_person", "🧍", Some("person.stand")),
+    ("standing_woman", "🧍\u{200d}♀\u{fe0f}", None),
+    ("star", "⭐", Some("star")),
+    ("star2", "🌟", Some("star.glow")),
+    ("star_and_crescent", "☪\u{fe0f}", Some("faith.islam")),
+    ("star_of_david", "✡\u{fe0f}", Some("faith.judaism")),
+    ("star_struck", "🤩", Some("face.stars")),
+    ("stars", "🌠", Some("star.shoot")),
+    ("station", "🚉", Some("train.stop")),
+    ("statue_of_liberty", "🗽", Some("liberty")),
+    ("steam_locomotive", "🚂", Some("train.steam")),
+    ("stethoscope", "🩺", Some("stethoscope")),
+    ("stew", "🍲", Some("soup")),
+    ("stop_button", "⏹\u{fe0f}", Some("playback.stop")),
+    ("stop_sign", "🛑", Some("sign.stop")),
+    ("stopwatch", "⏱\u{fe0f}", Some("watch.stop")),
+    ("straight_ruler", "📏", Some("ruler")),
+    ("strawberry", "🍓", Some("strawberry")),
+    ("stuck_out_tongue", "😛", Some("face.tongue")),
+    (
+        "stuck_out_tongue_closed_eyes",
+        "😝",
+        Some("face.tongue.squint"),
+    ),
+    (
+        "stuck_out_tongue_winking_eye",
+        "😜",
+        Some("face.tongue.wink"),
+    ),
+    ("student", "🧑\u{200d}🎓", None),
+    ("studio_microphone", "🎙\u{fe0f}", Some("microphone.studio")),
+    ("stuffed_flatbread", "🥙", Some("kebab")),
+    ("sudan", "🇸🇩", None),
+    (
+        "sun_behind_large_cloud",
+        "🌥\u{fe0f}",
+        Some("cloud.sun.hidden"),
+    ),
+    ("sun_behind_rain_cloud", "🌦\u{fe0f}", Some("cloud.sun.rain")),
+    ("sun_behind_small_cloud", "🌤\u{fe0f}", Some("sun.cloud")),
+    ("sun_with_face", "🌞", Some("sun.face")),
+    ("sunflower", "🌻", Some("flower.sun")),
+    ("sunglasses", "😎", Some("face.cool")),
+    ("sunny", "☀\u{fe0f}", Some("sun")),
+    ("sunrise", "🌅", Some("sunrise")),
+    ("sunrise_over_mountains", "🌄", Some("mountain.sunrise")),
+    ("superhero", "🦸", Some("superhero")),
+    ("superhero_man", "🦸\u{200d}♂\u{fe0f}", None),
+    ("superhero_woman", "🦸\u{200d}♀\u{fe0f}", None),
+    ("supervillain", "🦹", Some("supervillain")),
+    ("supervillain_man", "🦹\u{200d}♂\u{fe0f}", None),
+    ("supervillain_woman", "🦹\u{200d}♀\u{fe0f}", None),
+    ("surfer", "🏄", Some("surfing")),
+    ("surfing_man", "🏄\u{200d}♂\u{fe0f}", None),
+    ("surfing_woman", "🏄\u{200d}♀\u{fe0f}", None),
+    ("suriname", "🇸🇷", None),
+    ("sushi", "🍣", Some("sushi")),
+    ("suspension_railway", "🚟", Some("train.suspend")),
+    ("svalbard_jan_mayen", "🇸🇯", None),
+    ("swan", "🦢", Some("swan")),
+    ("swaziland", "🇸🇿", None),
+    ("sweat", "😓", Some("face.down.sweat")),
+    ("sweat_drops", "💦", Some("drops")),
+    ("sweat_smile", "😅", Some("face.smile.sweat")),
+    ("sweden", "🇸🇪", None),
+    ("sweet_potato", "🍠", Some("potato.sweet")),
+    ("swim_brief", "🩲", Some("briefs")),
+    ("swimmer", "🏊", Some("swimming")),
+    ("swimming_man", "🏊\u{200d}♂\u{fe0f}", None),
+    ("swimming_woman", "🏊\u{200d}♀\u{fe0f}", None),
+    ("switzerland", "🇨🇭", None),
+    ("symbols", "🔣", Some("symbols")),
+    ("synagogue", "🕍", Some("synagogue")),
+    ("syria", "🇸🇾", None),
+    ("syringe", "💉", Some("syringe")),
+    ("t-rex", "🦖", Some("dino.rex")),
+    ("taco", "🌮", Some("taco")),
+    ("tada", "🎉", Some("party")),
+    ("taiwan", "🇹🇼", None),
+    ("tajikistan", "🇹🇯", None),
+    ("takeout_box", "🥡", Some("takeout")),
+    ("tamale", "🫔", Some("tamale")),
+    ("tanabata_tree", "🎋", Some("tanabata")),
+    ("tangerine", "🍊", Some("tangerine")),
+    ("tanzania", "🇹🇿", None),
+    ("taurus", "♉", Some("zodiac.taurus")),
+    ("taxi", "🚕", Some("taxi")),
+    ("tea", "🍵", Some("teacup")),
+    ("teacher", "🧑\u{200d}🏫", None),
+    ("teapot", "🫖", Some("teapot")),
+    ("technologist", "🧑\u{200d}💻", None),
+    ("teddy_bear", "🧸", Some("teddy")),
+    ("telephone", "☎\u{fe0f}", Some("phone.classic")),
+    ("telephone_receiver", "📞", Some("phone.receiver")),
+    ("telescope", "🔭", Some("telescope")),
+    ("tennis", "🎾", Some("tennis")),
+    ("tent", "⛺", Some("tent")),
+    ("test_tube", "🧪", Some("testtube")),
+    ("thailand", "🇹🇭", None),
+    ("thermometer", "🌡\u{fe0f}", Some("thermometer")),
+    ("thinking", "🤔", Some("face.think")),
+    ("thong_sandal", "🩴", Some("shoe.thong")),
+    ("thought_balloon", "💭", Some("bubble.thought")),
+    ("thread", "🧵", Some("thread")),
+    ("three", "3\u{fe0f}\u{20e3}", None),
+    ("thumbsdown", "👎", Some("thumb.down")),
+    ("thumbsup", "👍", Some("thumb.up")),
+    ("ticket", "🎫", Some("ticket.travel")),
+    ("tickets", "🎟\u{fe0f}", Some("ticket.event")),
+    ("tiger", "🐯", Some("tiger.face")),
+    ("tiger2", "🐅", Some("tiger")),
+    ("timer_clock", "⏲\u{fe0f}", Some("clock.timer")),
+    ("timor_leste", "🇹🇱", None),
+    ("tipping_hand_man", "💁\u{200d}♂\u{fe0f}", None),
+    ("tipping_hand_person", "💁", Some("person.sassy")),
+    ("tipping_hand_woman", "💁\u{200d}♀\u{fe0f}", None),
+    ("tired_face", "😫", Some("face.distress")),
+    ("tm", "™\u{fe0f}", Some("tm")),
+    ("togo", "🇹🇬", None),
+    ("toilet", "🚽", Some("toilet")),
+    ("tokelau", "🇹🇰", None),
+    ("tokyo_tower", "🗼", Some("tower.tokyo")),
+    ("tomato", "🍅", Some("tomato")),
+    ("tonga", "🇹🇴", None),
+    ("tongue", "👅", Some("tongue")),
+    ("toolbox", "🧰", Some("toolbox")),
+    ("tooth", "🦷", Some("tooth")),
+    ("toothbrush", "🪥", Some("toothbrush")),
+    ("top", "🔝", Some("arrow.t.top")),
+    ("tophat", "🎩", Some("hat.top")),
+    ("tornado", "🌪\u{fe0f}", Some("tornado")),
+    ("tr", "🇹🇷", None),
+    ("trackball", "🖲\u{fe0f}", Some("trackball")),
+    ("tractor", "🚜", Some("tractor")),
+    ("traffic_light", "🚥", Some("trafficlight.h")),
+    ("train", "🚋", Some("train.tram.car")),
+    ("train2", "🚆", Some("train")),
+    ("tram", "🚊", Some("train.tram")),
+    ("transgender_flag", "🏳\u{fe0f}\u{200d}⚧\u{fe0f}", None),
+    ("transgender_symbol", "⚧\u{fe0f}", Some("transgender")),
+    ("triangular_flag_on_post", "🚩", Some("flag.red")),
+    ("triangular_ruler", "📐", Some("ruler.triangle")),
+    ("trident", "🔱", Some("trident")),
+    ("trinidad_tobago", "🇹🇹", None),
+    ("tristan_da_cunha", "🇹🇦", None),
+    ("triumph", "😤", Some("face.triumph")),
+    ("troll", "🧌", Some("troll")),
+    ("trolleybus", "🚎", Some("bus.trolley")),
+    ("trophy", "🏆", Some("trophy")),
+    ("tropical_drink", "🍹", Some("cocktail.tropical")),
+    ("tropical_fish", "🐠", Some("fish.tropical")),
+    ("truck", "🚚", Some("truck")),
+    ("trumpet", "🎺", Some("trumpet")),
+    ("tshirt", "👕", Some("shirt.t")),
+    ("tulip", "🌷", Some("flower.tulip")),
+    ("tumbler_glass", "🥃", Some("glass.tumbler")),
+    ("tunisia", "🇹🇳", None),
+    ("turkey", "🦃", Some("turkey")),
+    ("turkmenistan", "🇹🇲", None),
+    ("turks_caicos_islands", "🇹🇨", None),
+    ("turtle", "🐢", Some("turtle")),
+    ("tuvalu", "🇹🇻", None),
+    ("tv", "📺", Some("tv")),
+    ("twisted_rightwards_arrows", "🔀", Some("playback.shuffle")),
+    ("two", "2\u{fe0f}\u{20e3}", None),
+    ("two_hearts", "💕", Some("heart.double")),
+    ("two_men_holding_hands", "👬", Some("handholding.man.man")),
+    (
+        "two_women_holding_hands",
+        "👭",
+        Some("handholding.woman.woman"),
+    ),
+    ("u5272", "🈹", Some("kachi")),
+    ("u5408", "🈴", Some("go")),
+    ("u55b6", "🈺", Some("yo")),
+    ("u6307", "🈯", Some("yubi")),
+    ("u6708", "🈷\u{fe0f}", Some("gachi")),
+    ("u6709", "🈶", Some("yuryo")),
+    ("u6e80", "🈵", None),
+    ("u7121", "🈚", Some("muryo")),
+    ("u7533", "🈸", Some("shin")),
+    ("u7981", "🈲", Some("kon")),
+    ("u7a7a", "🈳", Some("kara")),
+    ("uganda", "🇺🇬", None),
+    ("uk", "🇬🇧", None),
+    ("ukraine", "🇺🇦", None),
+    ("umbrella", "☔", Some("umbrella.rain")),
+    ("unamused", "😒", Some("face.meh")),
+    ("underage", "🔞", Some("eighteen.not")),
+    ("unicorn", "🦄", Some("unicorn")),
+    ("united_arab_emirates", "🇦🇪", None),
+    ("united_nations", "🇺🇳", None),
+    ("unlock", "🔓", Some("lock.open")),
+    ("up", "🆙", Some("up")),
+    ("upside_down_face", "🙃", Some("face.inv")),
+    ("uruguay", "🇺🇾", None),
+    ("us", "🇺🇸", None),
+    ("us_outlying_islands", "🇺🇲", None),
+    ("us_virgin_islands", "🇻🇮", None),
+    ("uzbekistan", "🇺🇿", None),
+    ("v", "✌\u{fe0f}", Some("hand.peace")),
+    ("vampire", "🧛", Some("vampire")),
+    ("vampire_man", "🧛\u{200d}♂\u{fe0f}", None),
+    ("vampire_woman", "🧛\u{200d}♀\u{fe0f}", None),
+    ("vanuatu", "🇻🇺", None),
+    ("vatican_city", "🇻🇦", None),
+    ("venezuela", "🇻🇪", None),
+    ("vertical_traffic_light", "🚦", Some("trafficlight.v")),
+    ("vhs", "📼", Some("cassette")),
+    ("vibration_mode", "📳", Some("phone.vibrate")),
+    ("video_camera", "📹", Some("camera.video")),
+    ("video_game", "🎮", Some("controller")),
+    ("vietnam", "🇻🇳", None),
+    ("violin", "🎻", Some("violin")),
+    ("virgo", "♍", Some("zodiac.virgo")),
+    ("volcano", "🌋", Some("volcano")),
+    ("volleyball", "🏐", Some("volleyball")),
+    ("vomiting_face", "🤮", Some("face.vomit")),
+    ("vs", "🆚", Some("vs")),
+    ("vulcan_salute", "🖖", Some("hand.part")),
+    ("waffle", "🧇", Some("waffle")),
+    (
+        "wales",
+        "🏴\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}",
+        None,
+    ),
+    ("walking", "🚶", Some("pedestrian")),
+    ("walking_man", "🚶\u{200d}♂\u{fe0f}", None),
+    ("walking_woman", "🚶\u{200d}♀\u{fe0f}", None),
+    ("wallis_futuna", "🇼🇫", None),
+    ("waning_crescent_moon", "🌘", Some("moon.wane.three")),
+    ("waning_gibbous_moon", "🌖", Some("moon.wane.one")),
+    ("warning", "⚠\u{fe0f}", Some("warning")),
+    ("wastebasket", "🗑\u{fe0f}", Some("bin")),
+    ("watch", "⌚", Some("watch")),
+    ("water_buffalo", "🐃", Some("buffalo.water")),
+    ("water_polo", "🤽", Some("waterpolo")),
+    ("watermelon", "🍉", Some("watermelon")),
+    ("wave", "👋", Some("hand.wave")),
+    ("wavy_dash", "〰\u{fe0f}", Some("dash.wave.double")),
+    ("waxing_crescent_moon", "🌒", Some("moon.wax.one")),
+    ("waxing_gibbous_moon", "🌔", Some("moon.wax.three")),
+    ("wc", "🚾", Some("wc")),
+    ("weary", "😩", Some("face.weary")),
+    ("wedding", "💒", Some("church.love")),
+    ("weight_lifting", "🏋\u{fe0f}", Some("weightlifting")),
+    ("weight_lifting_man", "🏋\u{fe0f}\u{200d}♂\u{fe0f}", None),
+    ("weight_lifting_woman", "🏋\u{fe0f}\u{200d}♀\u{fe0f}", None),
+    ("western_sahara", "🇪🇭", None),
+    ("whale", "🐳", Some("whale.spout")),
+    ("whale2", "🐋", Some("whale")),
+    ("wheel", "🛞", Some("wheel")),
+    ("wheel_of_dharma", "☸\u{fe0f}", Some("faith.dharma")),
+    ("wheelchair", "♿", Some("wheelchair.box")),
+    ("white_check_mark", "✅", Some("checkmark.box")),
+    ("white_circle", "⚪", Some("circle.white")),
+    ("white_flag", "🏳\u{fe0f}", Some("flag.white")),
+    ("white_flower", "💮", Some("flower.white")),
+    ("white_haired_man", "👨\u{200d}🦳", None),
+    ("white_haired_woman", "👩\u{200d}🦳", None),
+    ("white_heart", "🤍", Some("heart.white")),
+    ("white_large_square", "⬜", Some("square.white")),
+    (
+        "white_medium_small_square",
+        "◽",
+        Some("square.white.small"),
+    ),
+    (
+        "white_medium_square",
+        "◻\u{fe0f}",
+        Some("square.white.medium"),
+    ),
+    ("white_small_square", "▫\u{fe0f}", Some("square.white.tiny")),
+    ("white_square_button", "🔳", Some("button.alt")),
+    ("wilted_flower", "🥀", Some("flower.wilted")),
+    ("wind_chime", "🎐", Some("windchime")),
+    ("wind_face", "🌬\u{fe0f}", Some("wind")),
+    ("window", "🪟", Some("window")),
+    ("wine_glass", "🍷", Some("wine")),
+    ("wing", "🪽", None),
+    ("wink", "😉", Some("face.wink")),
+    ("wireless", "🛜", None),
+    ("wolf", "🐺", Some("wolf")),
+    ("woman", "👩", Some("woman")),
+    ("woman_artist", "👩\u{200d}🎨", None),
+    ("woman_astronaut", "👩\u{200d}🚀", None),
+    ("woman_beard", "🧔\u{200d}♀\u{fe0f}", None),
+    ("woman_cartwheeling", "🤸\u{200d}♀\u{fe0f}", None),
+    ("woman_cook", "👩\u{200d}🍳", None),
+    ("woman_dancing", "💃", Some("dancing.woman")),
+    ("woman_facepalming", "🤦\u{200d}♀\u{fe0f}", None),
+    ("woman_factory_worker", "👩\u{200d}🏭", None),
+    ("woman_farmer", "👩\u{200d}🌾", None),
+    ("woman_feeding_baby", "👩\u{200d}🍼", None),
+    ("woman_firefighter", "👩\u{200d}🚒", None),
+    ("woman_health_worker", "👩\u{200d}⚕\u{fe0f}", None),
+    ("woman_in_manual_wheelchair", "👩\u{200d}🦽", None),
+    ("woman_in_motorized_wheelchair", "👩\u{200d}🦼", None),
+    ("woman_in_tuxedo", "🤵\u{200d}♀\u{fe0f}", None),
+    ("woman_judge", "👩\u{200d}⚖\u{fe0f}", None),
+    ("woman_juggling", "🤹\u{200d}♀\u{fe0f}", None),
+    ("woman_mechanic", "👩\u{200d}🔧", None),
+    ("woman_office_worker", "👩\u{200d}💼", None),
+    ("woman_pilot", "👩\u{200d}✈\u{fe0f}", None),
+    ("woman_playing_handball", "🤾\u{200d}♀\u{fe0f}", None),
+    ("woman_playing_water_polo", "🤽\u{200d}♀\u{fe0f}", None),
+    ("woman_scientist", "👩\u{200d}🔬", None),
+    ("woman_shrugging", "🤷\u{200d}♀\u{fe0f}", None),
+    ("woman_singer", "👩\u{200d}🎤", None),
+    ("woman_student", "👩\u{200d}🎓", None),
+    ("woman_teacher", "👩\u{200d}🏫", None),
+    ("woman_technologist", "👩\u{200d}💻", None),
+    ("woman_with_headscarf", "🧕", Some("person.hijab")),
+    ("woman_with_probing_cane", "👩\u{200d}🦯", None),
+    ("woman_with_turban", "👳\u{200d}♀\u{fe0f}", None),
+    ("woman_with_veil", "👰\u{200d}♀\u{fe0f}", None),
+    ("womans_clothes", "👚", Some("blouse")),
+    ("womans_hat", "👒", Some("hat.ribbon")),
+    ("women_wrestling", "🤼\u{200d}♀\u{fe0f}", None),
+    ("womens", "🚺", Some("woman.box")),
+    ("wood", "🪵", Some("wood")),
+    ("woozy_face", "🥴", Some("face.woozy")),
+    ("world_map", "🗺\u{fe0f}", Some("map.world")),
+    ("worm", "🪱", Some("worm")),
+    ("worried", "😟", Some("face.worry")),
+    ("wrench", "🔧", Some("wrench")),
+    ("wrestling", "🤼", Some("wrestling")),
+    ("writing_hand", "✍\u{fe0f}", Some("hand.write")),
+    ("x", "❌", Some("crossmark")),
+    ("x_ray", "🩻", Some("xray")),
+    ("yarn", "🧶", Some("yarn")),
+    ("yawning_face", "🥱", Some("face.yawn")),
+    ("yellow_circle", "🟡", Some("circle.yellow")),
+    ("yellow_heart", "💛", Some("heart.yellow")),
+    ("yellow_square", "🟨", Some("square.yellow")),
+    ("yemen", "🇾🇪", None),
+    ("yen", "💴", Some("money.yen")),
+    ("yin_yang", "☯\u{fe0f}", Some("faith.yinyang")),
+    ("yo_yo", "🪀", Some("yoyo")),
+    ("yum", "😋", Some("face.lick")),
+    ("zambia", "🇿🇲", None),
+    ("zany_face", "🤪", Some("face.goofy")),
+    ("zap", "⚡", Some("lightning")),
+    ("zebra", "🦓", Some("zebra")),
+    ("zero", "0\u{fe0f}\u{20e3}", None),
+    ("zimbabwe", "🇿🇼", None),
+    ("zipper_mouth_face", "🤐", Some("face.zip")),
+    ("zombie", "🧟", Some("zombie")),
+    ("zombie_man", "🧟\u{200d}♂\u{fe0f}", None),
+    ("zombie_woman", "🧟\u{200d}♀\u{fe0f}", None),
+    ("zzz", "💤", Some("zzz")),
+];
@@ -1,11 +1,286 @@
 //! Convert Markdown to Typst.
+//!
+//! Each converter handles one Markdown construct and passes everything else through,
+//! so they can be combined freely. When using all of them, apply them in this order:
+//!
+//! 1. [`ConvertCallouts`]
+//! 2. [`ConvertHeadings`]
+//! 3. [`ConvertParagraphs`]
+//! 4. [`ConvertSoftBreaks`]
+//! 5. [`ConvertHardBreaks`]
+//! 6. [`ConvertText`]
+//! 7. [`ConvertStrong`]
+//! 8. [`ConvertEmphasis`]
+//! 9. [`ConvertBlockQuotes`]
+//! 10. [`ConvertLists`]
+//! 11. [`ConvertCode`]
+//! 12. [`ConvertLinks`]
+//! 13. [`ConvertTables`]
+//!
+//! [`ConvertCallouts`] recognizes alerts by the text of their first paragraph, so it
+//! must run before paragraphs and text are converted. The optional [`ConvertEmoji`]
+//! also works on Markdown text, so it must run before [`ConvertText`]. [`ConvertText`]
+//! tracks Markdown code blocks to leave their text alone, so it must run before
+//! [`ConvertCode`] turns them into Typst code blocks. Any converter that is skipped
+//! leaves its Markdown events in the stream.
+//!
+//! [`Conversion`] (with the `builder` feature) and [`markdown_to_typst`] apply this
+//! order for you.
 use std::collections::VecDeque;
 
+use crate::convert::{Converter, ConverterIter, Input};
 use crate::converter;
 use crate::markdown;
 use crate::typst;
 use crate::ParserEvent;
 
+#[cfg(feature = "builder")]
+mod builder;
+mod emoji;
+
+#[cfg(feature = "builder")]
+pub use builder::Conversion;
+pub use emoji::{ConvertEmoji, EmojiConverter, EmojiStyle};
+
+/// Convert Markdown to Typst markup with all converters applied.
+///
+/// Tables and heading attributes are enabled when parsing.
+pub fn markdown_to_typst(markdown: &str) -> String {
+    let events = markdown::Parser::new_ext(
+        markdown,
+        markdown::Options::ENABLE_TABLES | markdown::Options::ENABLE_HEADING_ATTRIBUTES,
+    );
+    let events = convert_all(markdown::MarkdownIter(events));
+    typst::to::markup::TypstMarkup::new(typst::AssertTypst(events)).collect()
+}
+
+/// Apply all converters in the documented order.
+pub(crate) fn convert_all<'a>(
+    events: impl Iterator<Item = ParserEvent<'a>>,
+) -> impl Iterator<Item = ParserEvent<'a>> {
+    let events = ConvertCallouts::new(events);
+    let events = ConvertHeadings::new(events);
+    let events = ConvertParagraphs::new(events);
+    let events = ConvertSoftBreaks::new(events);
+    let events = ConvertHardBreaks::new(events);
+    let events = ConvertText::new(events);
+    let events = ConvertStrong::new(events);
+    let events = ConvertEmphasis::new(events);
+    let events = ConvertBlockQuotes::new(events);
+    let events = ConvertLists::new(events);
+    let events = ConvertCode::new(events);
+    let events = ConvertLinks::new(events);
+    ConvertTables::new(events)
+}
+
+/// Convert GitHub alerts (`> [!NOTE]`) and mdbook-admonish blocks (```` ```admonish
+/// warning ````) to Typst callouts.
+///
+/// The body of an admonish block is Markdown inside a code block, so it is parsed again
+/// and its Markdown events are passed on inside the callout for the following
+/// converters. Tables are enabled when parsing the body, heading attributes are not.
+#[derive(Debug, Default)]
+pub struct CalloutConverter {
+    // The callout kind of each open block quote, `None` for plain quotes.
+    quotes: Vec<Option<typst::CalloutKind>>,
+}
+
+/// The callout kind for a GitHub alert marker, e.g. `[!NOTE]`.
+fn alert_kind(marker: &str) -> Option<typst::CalloutKind> {
+    let name = marker.strip_prefix("[!")?.strip_suffix(']')?;
+    match name.to_ascii_lowercase().as_str() {
+        "note" => Some(typst::CalloutKind::Note),
+        "tip" => Some(typst::CalloutKind::Tip),
+        "important" => Some(typst::CalloutKind::Important),
+        "warning" => Some(typst::CalloutKind::Warning),
+        "caution" => Some(typst::CalloutKind::Caution),
+        _ => None,
+    }
+}
+
+/// The callout kind and title for an admonish code block info string, e.g.
+/// `admonish warning title="Careful"`.
+fn admonish(info: &str) -> Option<(typst::CalloutKind, Option<String>)> {
+    let rest = info.strip_prefix("admonish")?;
+    if !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+    let title = rest.split_once("title=\"").and_then(|(_, t)| {
+        t.split_once('"')
+            .map(|(title, _)| title.replace("\\\"", "\""))
+    });
+    // The kind is the first word that is not an option.
+    let kind = match rest.split_whitespace().find(|w| !w.contains('=')) {
+        None | Some("note") => typst::CalloutKind::Note,
+        Some("abstract" | "summary" | "tldr") => typst::CalloutKind::Abstract,
+        Some("info" | "todo") => typst::CalloutKind::Info,
+        Some("tip" | "hint" | "important") => typst::CalloutKind::Tip,
+        Some("success" | "check" | "done") => typst::CalloutKind::Success,
+        Some("question" | "help" | "faq") => typst::CalloutKind::Question,
+        Some("warning" | "caution" | "attention") => typst::CalloutKind::Warning,
+        Some("failure" | "fail" | "missing") => typst::CalloutKind::Failure,
+        Some("danger" | "error") => typst::CalloutKind::Danger,
+        Some("bug") => typst::CalloutKind::Bug,
+        Some("example") => typst::CalloutKind::Example,
+        Some("quote" | "cite") => typst::CalloutKind::Quote,
+        Some(_) => typst::CalloutKind::Note,
+    };
+    Some((kind, title))
+}
+
+/// Copy the strings borrowed from an admonish body so its events outlive it.
+fn owned<'a>(s: markdown::CowStr<'_>) -> markdown::CowStr<'a> {
+    s.into_string().into()
+}
+
+fn owned_tag<'a>(tag: markdown::Tag<'_>) -> markdown::Tag<'a> {
+    use markdown::Tag;
+    match tag {
+        Tag::Paragraph => Tag::Paragraph,
+        // Heading attributes are not parsed, see `CalloutConverter`.
+        Tag::Heading(level, _, _) => Tag::Heading(level, None, vec![]),
+        Tag::BlockQuote => Tag::BlockQuote,
+        Tag::CodeBlock(markdown::CodeBlockKind::Indented) => {
+            Tag::CodeBlock(markdown::CodeBlockKind::Indented)
+        }
+        Tag::CodeBlock(markdown::CodeBlockKind::Fenced(info)) => {
+            Tag::CodeBlock(markdown::CodeBlockKind::Fenced(owned(info)))
+        }
+        Tag::List(start) => Tag::List(start),
+        Tag::Item => Tag::Item,
+        Tag::FootnoteDefinition(label) => Tag::FootnoteDefinition(owned(label)),
+        Tag::Table(alignments) => Tag::Table(alignments),
+        Tag::TableHead => Tag::TableHead,
+        Tag::TableRow => Tag::TableRow,
+        Tag::TableCell => Tag::TableCell,
+        Tag::Emphasis => Tag::Emphasis,
+        Tag::Strong => Tag::Strong,
+        Tag::Strikethrough => Tag::Strikethrough,
+        Tag::Link(ty, url, title) => Tag::Link(ty, owned(url), owned(title)),
+        Tag::Image(ty, url, title) => Tag::Image(ty, owned(url), owned(title)),
+    }
+}
+
+fn owned_event<'a>(event: markdown::Event<'_>) -> markdown::Event<'a> {
+    use markdown::Event;
+    match event {
+        Event::Start(tag) => Event::Start(owned_tag(tag)),
+        Event::End(tag) => Event::End(owned_tag(tag)),
+        Event::Text(t) => Event::Text(owned(t)),
+        Event::Code(t) => Event::Code(owned(t)),
+        Event::Html(t) => Event::Html(owned(t)),
+        Event::FootnoteReference(t) => Event::FootnoteReference(owned(t)),
+        Event::SoftBreak => Event::SoftBreak,
+        Event::HardBreak => Event::HardBreak,
+        Event::Rule => Event::Rule,
+        Event::TaskListMarker(checked) => Event::TaskListMarker(checked),
+    }
+}
+
+impl CalloutConverter {
+    /// If a block quote starts with an alert marker line, consume the marker and return
+    /// the alert kind.
+    fn alert<'a>(
+        input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) -> Option<typst::CalloutKind> {
+        if !matches!(
+            input.peek(),
+            Some(ParserEvent::Markdown(markdown::Event::Start(
+                markdown::Tag::Paragraph
+            )))
+        ) {
+            return None;
+        }
+        // The marker is split into several text events at the brackets.
+        let mut marker = String::new();
+        let mut n = 1;
+        while let Some(ParserEvent::Markdown(markdown::Event::Text(t))) = input.peek_nth(n) {
+            marker.push_str(t);
+            n += 1;
+        }
+        let kind = alert_kind(marker.trim())?;
+        let end = match input.peek_nth(n) {
+            // The break ending the marker's line is dropped with the marker.
+            Some(ParserEvent::Markdown(
+                markdown::Event::SoftBreak | markdown::Event::HardBreak,
+            )) => false,
+            Some(ParserEvent::Markdown(markdown::Event::End(markdown::Tag::Paragraph))) => true,
+            _ => return None,
+        };
+
+        let start = input.next();
+        input.by_ref().take(n).for_each(drop);
+        // Keep the rest of the paragraph, drop it if the marker was all of it.
+        if !end {
+            output.extend(start);
+        }
+        Some(kind)
+    }
+}
+
+impl<'a> Converter<'a> for CalloutConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
+            ParserEvent::Markdown(markdown::Event::Start(markdown::Tag::BlockQuote)) => {
+                let mut rest = VecDeque::new();
+                let kind = Self::alert(input, &mut rest);
+                self.quotes.push(kind);
+                match kind {
+                    Some(kind) => output.push_back(ParserEvent::Typst(typst::Event::Start(
+                        typst::Tag::Callout(kind, None),
+                    ))),
+                    None => output.push_back(event),
+                }
+                output.extend(rest);
+            }
+            ParserEvent::Markdown(markdown::Event::End(markdown::Tag::BlockQuote)) => {
+                match self.quotes.pop() {
+                    Some(Some(kind)) => output.push_back(ParserEvent::Typst(typst::Event::End(
+                        typst::Tag::Callout(kind, None),
+                    ))),
+                    _ => output.push_back(event),
+                }
+            }
+            ParserEvent::Markdown(markdown::Event::Start(markdown::Tag::CodeBlock(
+                markdown::CodeBlockKind::Fenced(ref info),
+            ))) if admonish(info).is_some() => {
+                let (kind, title) = admonish(info).expect("admonish block");
+                let mut body = String::new();
+                for e in input.by_ref() {
+                    match e {
+                        ParserEvent::Markdown(markdown::Event::Text(t)) => body.push_str(&t),
+                        ParserEvent::Markdown(markdown::Event::End(markdown::Tag::CodeBlock(
+                            _,
+                        ))) => break,
+                        _ => {}
+                    }
+                }
+                let events = markdown::Parser::new_ext(&body, markdown::Options::ENABLE_TABLES)
+                    .map(owned_event)
+                    .collect::<Vec<_>>();
+                let tag = typst::Tag::Callout(kind, title.map(Into::into));
+                output.push_back(ParserEvent::Typst(typst::Event::Start(tag.clone())));
+                // Convert callouts nested in the body too.
+                output.extend(
+                    CalloutConverter::default().over(markdown::MarkdownIter(events.into_iter())),
+                );
+                output.push_back(ParserEvent::Typst(typst::Event::End(tag)));
+            }
+            x => output.push_back(x),
+        }
+    }
+}
+
+/// Convert GitHub alerts and mdbook-admonish blocks to Typst callouts. See
+/// [`CalloutConverter`].
+pub type ConvertCallouts<'a, T> = ConverterIter<'a, T, CalloutConverter>;
+
 converter!(
     /// Convert Markdown paragraphs to Typst paragraphs.
     ConvertParagraphs,
@@ -22,72 +297,139 @@ converter!(
     }
 });
 
+/// How quotes, dashes and ellipses in text are typeset.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "mdbook", derive(serde::Deserialize))]
+#[cfg_attr(feature = "mdbook", serde(rename_all = "lowercase"))]
+pub enum SmartPunctuation {
+    /// Keep straight quotes, `--`, `---` and `...` in the text and let Typst turn them
+    /// into smart quotes for the text language, dashes and ellipses.
+    #[default]
+    Typst,
+    /// Replace them with Unicode curly quotes, en and em dashes and ellipses, like
+    /// Markdown smart punctuation. Typst smart quotes should be turned off with
+    /// `#set smartquote(enabled: false)`.
+    Unicode,
+}
+
 /// Convert Markdown text to Typst text.
-pub struct ConvertText<T> {
-    code: VecDeque<()>,
-    iter: T,
+#[derive(Debug, Default)]
+pub struct TextConverter {
+    code: usize,
+    punctuation: SmartPunctuation,
+    // The last character of text, `None` at the start of a block.
+    prev: Option<char>,
 }
 
-impl<'a, T> ConvertText<T>
-where
-    T: Iterator<Item = ParserEvent<'a>>,
-{
-    pub fn new(iter: T) -> Self {
-        ConvertText {
-            code: VecDeque::new(),
-            iter,
+impl TextConverter {
+    /// Convert text, typesetting punctuation outside of code with `punctuation`.
+    pub fn with_punctuation(punctuation: SmartPunctuation) -> Self {
+        Self {
+            punctuation,
+            ..Self::default()
         }
     }
 }
 
-impl<'a, T> Iterator for ConvertText<T>
-where
-    T: Iterator<Item = ParserEvent<'a>>,
-{
-    type Item = ParserEvent<'a>;
+/// Replace straight quotes, `--`, `---` and `...` with Unicode punctuation. Quotes
+/// after `prev`, or at the start of the text if `None`, open when following whitespace
+/// or an opening bracket or quote.
+fn smarten(text: &str, prev: &mut Option<char>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let opens = prev.is_none_or(|p| {
+            p.is_whitespace() || matches!(p, '(' | '[' | '{' | '“' | '‘' | '—' | '–')
+        });
+        let c = match c {
+            '"' if opens => '“',
+            '"' => '”',
+            '\'' if opens => '‘',
+            '\'' => '’',
+            '-' if chars.peek() == Some(&'-') => {
+                chars.next();
+                if chars.peek() == Some(&'-') {
+                    chars.next();
+                    '—'
+                } else {
+                    '–'
+                }
+            }
+            '.' if chars.clone().take(2).eq(['.', '.']) => {
+                chars.next();
+                chars.next();
+                '…'
+            }
+            c => c,
+        };
+        out.push(c);
+        *prev = Some(c);
+    }
+    out
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match (self.code.pop_back(), self.iter.next()) {
+impl<'a> Converter<'a> for TextConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
             // In code, include the unescaped text.
-            (Some(_), Some(ParserEvent::Markdown(markdown::Event::Text(t)))) => {
-                Some(ParserEvent::Typst(typst::Event::Text(t)))
+            ParserEvent::Markdown(markdown::Event::Text(t)) if self.code > 0 => {
+                output.push_back(ParserEvent::Typst(typst::Event::Text(t)));
             }
             // Not in code, escape the text using typist escaping rules.
-            (None, Some(ParserEvent::Markdown(markdown::Event::Text(t)))) => {
+            ParserEvent::Markdown(markdown::Event::Text(t)) => {
                 if t.trim().starts_with("\\[") && t.trim().ends_with("\\]") {
                     // Strip out mdbook's non-standard MathJax.
                     // TODO: Translate to typst math and/or expose this as a typed
                     // markdown event.
-                    self.next()
+                } else if self.punctuation == SmartPunctuation::Unicode {
+                    let text = smarten(&t, &mut self.prev);
+                    output.push_back(ParserEvent::Typst(typst::Event::Text(text.into())));
                 } else {
-                    Some(ParserEvent::Typst(typst::Event::Text(t)))
+                    output.push_back(ParserEvent::Typst(typst::Event::Text(t)));
                 }
             }
             // Track code start.
-            (
-                _,
-                event @ Some(ParserEvent::Markdown(markdown::Event::Start(
-                    markdown::Tag::CodeBlock(_),
-                ))),
-            ) => {
-                self.code.push_back(());
-                event
+            event @ ParserEvent::Markdown(markdown::Event::Start(markdown::Tag::CodeBlock(_))) => {
+                self.code += 1;
+                output.push_back(event);
             }
             // Track code end.
-            (
-                _,
-                event @ Some(ParserEvent::Markdown(markdown::Event::End(markdown::Tag::CodeBlock(
-                    _,
-                )))),
-            ) => {
-                let _ = self.code.pop_back();
-                event
+            event @ ParserEvent::Markdown(markdown::Event::End(markdown::Tag::CodeBlock(_))) => {
+                self.code = self.code.saturating_sub(1);
+                self.prev = None;
+                output.push_back(event);
+            }
+            // Inline markup does not break up quotes.
+            event @ ParserEvent::Markdown(
+                markdown::Event::Start(
+                    markdown::Tag::Emphasis
+                    | markdown::Tag::Strong
+                    | markdown::Tag::Strikethrough
+                    | markdown::Tag::Link(..),
+                )
+                | markdown::Event::End(
+                    markdown::Tag::Emphasis
+                    | markdown::Tag::Strong
+                    | markdown::Tag::Strikethrough
+                    | markdown::Tag::Link(..),
+                ),
+            ) => output.push_back(event),
+            x => {
+                self.prev = None;
+                output.push_back(x);
             }
-            (_, x) => x,
         }
     }
 }
 
+/// Convert Markdown text to Typst text. See [`TextConverter`].
+pub type ConvertText<'a, T> = ConverterIter<'a, T, TextConverter>;
+
 converter!(
     /// Convert Markdown links to Typst links.
     ConvertLinks,
@@ -299,51 +641,103 @@ converter!(
    }
 );
 
-converter!(
-    /// Convert Markdown headings to Typst headings.
-    ConvertHeadings,
-    ParserEvent<'a> => ParserEvent<'a>,
-    |this: &mut Self| {
-        struct TypstLevel(std::num::NonZeroU8);
+/// Convert Markdown headings to Typst headings.
+///
+/// Pandoc-style heading classes turn off numbering (`{.unnumbered}`) or leave the
+/// heading out of the outline and bookmarks (`{.unlisted}`). Heading attributes must be
+/// enabled when parsing.
+#[derive(Debug, Clone)]
+pub struct HeadingConverter {
+    unnumbered: String,
+    unlisted: String,
+}
 
-        impl std::ops::Deref for TypstLevel {
-            type Target = std::num::NonZeroU8;
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
-        impl From<markdown::HeadingLevel> for TypstLevel{
-            fn from(item: markdown::HeadingLevel) -> Self {
-                use markdown::HeadingLevel;
-                match item {
-                    HeadingLevel::H1 => TypstLevel(core::num::NonZeroU8::new(1).expect("non-zero")),
-                    HeadingLevel::H2 => TypstLevel(core::num::NonZeroU8::new(2).expect("non-zero")),
-                    HeadingLevel::H3 => TypstLevel(core::num::NonZeroU8::new(3).expect("non-zero")),
-                    HeadingLevel::H4 => TypstLevel(core::num::NonZeroU8::new(4).expect("non-zero")),
-                    HeadingLevel::H5 => TypstLevel(core::num::NonZeroU8::new(5).expect("non-zero")),
-                    HeadingLevel::H6 => TypstLevel(core::num::NonZeroU8::new(6).expect("non-zero")),
-                }
-            }
+impl Default for HeadingConverter {
+    fn default() -> Self {
+        Self {
+            unnumbered: "unnumbered".to_string(),
+            unlisted: "unlisted".to_string(),
         }
-        match this.iter.next() {
-            Some(ParserEvent::Markdown(markdown::Event::Start(markdown::Tag::Heading(level, _, _)))) => {
-                let level: TypstLevel = level.into();
-                Some(ParserEvent::Typst(typst::Event::Start(typst::Tag::Heading(*level,
-                    typst::TableOfContents::Include,
-                    typst::Bookmarks::Include,
-                ))))
+    }
+}
+
+impl HeadingConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turn off numbering for headings with class `class` instead of `unnumbered`.
+    pub fn unnumbered_class(mut self, class: impl Into<String>) -> Self {
+        self.unnumbered = class.into();
+        self
+    }
+
+    /// Leave headings with class `class` out of the outline and bookmarks instead of
+    /// `unlisted`.
+    pub fn unlisted_class(mut self, class: impl Into<String>) -> Self {
+        self.unlisted = class.into();
+        self
+    }
+
+    /// The Typst heading at `level` for a Markdown heading with `classes`.
+    pub(crate) fn heading<'a>(
+        &self,
+        level: std::num::NonZeroU8,
+        classes: &[&str],
+    ) -> typst::Tag<'a> {
+        let unlisted = classes.contains(&self.unlisted.as_str());
+        let unnumbered = classes.contains(&self.unnumbered.as_str());
+        typst::Tag::Heading(
+            level,
+            match unlisted {
+                true => typst::TableOfContents::Exclude,
+                false => typst::TableOfContents::Include,
             },
-            Some(ParserEvent::Markdown(markdown::Event::End(markdown::Tag::Heading(level, _, _))))  => {
-                let level: TypstLevel = level.into();
-                Some(ParserEvent::Typst(typst::Event::End(typst::Tag::Heading(*level,
-                    typst::TableOfContents::Include,
-                    typst::Bookmarks::Include,
-                ))))
+            match unlisted {
+                true => typst::Bookmarks::Exclude,
+                false => typst::Bookmarks::Include,
             },
-            x => x,
+            match unnumbered {
+                true => typst::Numbering::Exclude,
+                false => typst::Numbering::Include,
+            },
+        )
+    }
+}
+
+impl<'a> Converter<'a> for HeadingConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
+            ParserEvent::Markdown(markdown::Event::Start(markdown::Tag::Heading(
+                level,
+                _,
+                classes,
+            ))) => {
+                let level = std::num::NonZeroU8::new(level as u8).expect("non-zero");
+                let tag = self.heading(level, &classes);
+                output.push_back(ParserEvent::Typst(typst::Event::Start(tag)));
+            }
+            ParserEvent::Markdown(markdown::Event::End(markdown::Tag::Heading(
+                level,
+                _,
+                classes,
+            ))) => {
+                let level = std::num::NonZeroU8::new(level as u8).expect("non-zero");
+                let tag = self.heading(level, &classes);
+                output.push_back(ParserEvent::Typst(typst::Event::End(tag)));
+            }
+            x => output.push_back(x),
         }
-   }
-);
+    }
+}
+
+/// Convert Markdown headings to Typst headings. See [`HeadingConverter`].
+pub type ConvertHeadings<'a, T> = ConverterIter<'a, T, HeadingConverter>;
 
 converter!(
     /// Convert Markdown tables to Typst tables.
@@ -432,7 +826,7 @@ mod tests {
 
 ## This is **rad**!
 ";
-            let i = ConvertHeadings::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertHeadings::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -441,17 +835,20 @@ mod tests {
                         NonZeroU8::new(1).unwrap(),
                         typst::TableOfContents::Include,
                         typst::Bookmarks::Include,
+                        typst::Numbering::Include,
                     ))),
                     Markdown(MdEvent::Text(CowStr::Borrowed("Greetings"))),
                     Typst(TypstEvent::End(TypstTag::Heading(
                         NonZeroU8::new(1).unwrap(),
                         typst::TableOfContents::Include,
                         typst::Bookmarks::Include,
+                        typst::Numbering::Include,
                     ))),
                     Typst(TypstEvent::Start(TypstTag::Heading(
                         NonZeroU8::new(2).unwrap(),
                         typst::TableOfContents::Include,
                         typst::Bookmarks::Include,
+                        typst::Numbering::Include,
                     ))),
                     Markdown(MdEvent::Text(CowStr::Borrowed("This is "))),
                     Markdown(MdEvent::Start(MdTag::Strong)),
@@ -462,12 +859,53 @@ mod tests {
                         NonZeroU8::new(2).unwrap(),
                         typst::TableOfContents::Include,
                         typst::Bookmarks::Include,
+                        typst::Numbering::Include,
                     ))),
                 ]
             );
         }
     }
 
+    mod heading_classes {
+        use super::*;
+
+        fn markup(md: &str, converter: HeadingConverter) -> String {
+            let events =
+                markdown::Parser::new_ext(md, markdown::Options::ENABLE_HEADING_ATTRIBUTES);
+            let events = ConvertText::new(converter.over(MarkdownIter(events)));
+            typst::to::markup::TypstMarkup::new(typst::AssertTypst(events)).collect()
+        }
+
+        #[test]
+        fn pandoc() {
+            self::assert_eq!(
+                markup(
+                    "# Preface {.unnumbered}\n## Notes {.unlisted .unnumbered}\n## Plain\n",
+                    HeadingConverter::new()
+                ),
+                "\
+#heading(level: 1, numbering: none)[Preface]
+#heading(level: 2, numbering: none, outlined: false, bookmarked: false)[Notes]
+== Plain
+"
+            );
+        }
+
+        #[test]
+        fn custom() {
+            self::assert_eq!(
+                markup(
+                    "# One {.unnumbered}\n# Two {.nonum}\n",
+                    HeadingConverter::new().unnumbered_class("nonum")
+                ),
+                "\
+= One
+#heading(level: 1, numbering: none)[Two]
+"
+            );
+        }
+    }
+
     /// Markdown docs:
     /// * https://spec.commonmark.org/0.30/#link-reference-definitions
     /// * https://spec.commonmark.org/0.30/#links
@@ -480,7 +918,7 @@ mod tests {
             let md = "\
 Cool [beans](https://example.com)
 ";
-            let i = ConvertLinks::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertLinks::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -506,7 +944,7 @@ Cool [beans](https://example.com)
             let md = "\
 Cool <https://example.com>
 ";
-            let i = ConvertLinks::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertLinks::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -532,7 +970,7 @@ Cool <https://example.com>
             let md = "\
 Who are <you@example.com>
 ";
-            let i = ConvertLinks::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertLinks::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -566,7 +1004,7 @@ Who are <you@example.com>
 
 I **love** cake!
 ";
-            let i = ConvertStrong::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertStrong::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -604,7 +1042,7 @@ I **love** cake!
 
 I *love* cake!
 ";
-            let i = ConvertEmphasis::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertEmphasis::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -640,7 +1078,7 @@ I *love* cake!
             let md = "\
 foo `bar` baz
 ";
-            let i = ConvertCode::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertCode::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -662,7 +1100,7 @@ whatever
     code 1
     code 2
 ";
-            let i = ConvertCode::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertCode::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -691,7 +1129,7 @@ whatever
 blah
 ```
 ";
-            let i = ConvertCode::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertCode::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -716,7 +1154,7 @@ blah
 blah
 ```
 ";
-            let i = ConvertCode::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertCode::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -749,7 +1187,7 @@ bar
 
 baz
 ";
-            let i = ConvertText::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertText::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -766,11 +1204,58 @@ baz
                 ]
             );
         }
+
+        #[test]
+        fn keeps_code_text() {
+            let md = "    foo\n    \\[x\\]\n";
+            let i = ConvertText::new(MarkdownIter(Parser::new(md)));
+
+            self::assert_eq!(
+                i.collect::<Vec<super::ParserEvent>>(),
+                vec![
+                    Markdown(MdEvent::Start(MdTag::CodeBlock(
+                        markdown::CodeBlockKind::Indented
+                    ))),
+                    Typst(TypstEvent::Text(CowStr::Borrowed("foo\n"))),
+                    Typst(TypstEvent::Text(CowStr::Borrowed("\\[x\\]\n"))),
+                    Markdown(MdEvent::End(MdTag::CodeBlock(
+                        markdown::CodeBlockKind::Indented
+                    ))),
+                ]
+            );
+        }
+
+        #[test]
+        fn typst_punctuation() {
+            let md = "\"Hi\" -- it's...";
+            let i = ConvertText::new(MarkdownIter(Parser::new(md)));
+            let text = typst::TypstFilter(i).collect::<Vec<_>>();
+
+            self::assert_eq!(text, vec![TypstEvent::Text("\"Hi\" -- it's...".into())]);
+        }
+
+        #[test]
+        fn unicode_punctuation() {
+            let md = "\"Hi\" -- it's *'done'*... --- `\"code\"`";
+            let i = TextConverter::with_punctuation(SmartPunctuation::Unicode)
+                .over(MarkdownIter(Parser::new(md)));
+            let text = typst::TypstFilter(i).collect::<Vec<_>>();
+
+            self::assert_eq!(
+                text,
+                vec![
+                    TypstEvent::Text("“Hi” – it’s ".into()),
+                    TypstEvent::Text("‘done’".into()),
+                    TypstEvent::Text("… — ".into()),
+                ]
+            );
+        }
     }
 
     /// Markdown docs:
     /// * https://spec.commonmark.org/0.31.2/#hard-line-breaks
     /// * https://spec.commonmark.org/0.31.2/#soft-line-breaks
+    ///
     /// Typst docs:
     /// * https://typst.app/docs/reference/text/
     mod breaks {
@@ -783,7 +1268,7 @@ baz
 foo
 bar
 ";
-            let i = ConvertSoftBreaks::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertSoftBreaks::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -804,7 +1289,7 @@ bar
 foo  
 bar
 ";
-            let i = ConvertHardBreaks::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertHardBreaks::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -833,7 +1318,7 @@ bar
 
 baz
 ";
-            let i = ConvertParagraphs::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertParagraphs::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -866,7 +1351,7 @@ baz
 * are
 * cool
 ";
-            let i = ConvertLists::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertLists::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -895,7 +1380,7 @@ baz
 1. cats are _too_
 2. birds are ok
 ";
-            let i = ConvertLists::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertLists::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -923,7 +1408,7 @@ baz
 6. foo
 1. bar
 ";
-            let i = ConvertLists::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertLists::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -948,7 +1433,7 @@ baz
 * multiple
   lines
 ";
-            let i = ConvertLists::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertLists::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -974,7 +1459,7 @@ baz
         fn backslashes_in_backticks() {
             let md = r###"before `\` after"###;
 
-            let i = ConvertText::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertText::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -993,7 +1478,7 @@ baz
         fn simple_blockquote() {
             let md = "> test";
 
-            let i = ConvertBlockQuotes::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertBlockQuotes::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -1020,7 +1505,7 @@ baz
         fn complex_blockquote() {
             let md = "> one\n> two\n> three";
 
-            let i = ConvertBlockQuotes::new(MarkdownIter(Parser::new(&md)));
+            let i = ConvertBlockQuotes::new(MarkdownIter(Parser::new(md)));
 
             self::assert_eq!(
                 i.collect::<Vec<super::ParserEvent>>(),
@@ -1047,6 +1532,141 @@ baz
         }
     }
 
+    mod callouts {
+        use super::*;
+
+        #[test]
+        fn alert() {
+            let md = "> [!WARNING]\n> Hot.\n\n> Plain";
+            let i = ConvertCallouts::new(MarkdownIter(Parser::new(md)));
+            let tag = TypstTag::Callout(typst::CalloutKind::Warning, None);
+
+            self::assert_eq!(
+                i.collect::<Vec<super::ParserEvent>>(),
+                vec![
+                    Typst(TypstEvent::Start(tag.clone())),
+                    Markdown(MdEvent::Start(MdTag::Paragraph)),
+                    Markdown(MdEvent::Text(CowStr::Borrowed("Hot."))),
+                    Markdown(MdEvent::End(MdTag::Paragraph)),
+                    Typst(TypstEvent::End(tag)),
+                    Markdown(MdEvent::Start(MdTag::BlockQuote)),
+                    Markdown(MdEvent::Start(MdTag::Paragraph)),
+                    Markdown(MdEvent::Text(CowStr::Borrowed("Plain"))),
+                    Markdown(MdEvent::End(MdTag::Paragraph)),
+                    Markdown(MdEvent::End(MdTag::BlockQuote)),
+                ],
+            );
+        }
+
+        #[test]
+        fn alert_marker_on_own_line() {
+            let tag = TypstTag::Callout(typst::CalloutKind::Note, None);
+            for md in [
+                "> [!NOTE]\n> Hot.",
+                "> [!NOTE]  \n> Hot.",
+                "> [!NOTE]\\\n> Hot.",
+            ] {
+                let i = ConvertCallouts::new(MarkdownIter(Parser::new(md)));
+
+                self::assert_eq!(
+                    i.collect::<Vec<super::ParserEvent>>(),
+                    vec![
+                        Typst(TypstEvent::Start(tag.clone())),
+                        Markdown(MdEvent::Start(MdTag::Paragraph)),
+                        Markdown(MdEvent::Text(CowStr::Borrowed("Hot."))),
+                        Markdown(MdEvent::End(MdTag::Paragraph)),
+                        Typst(TypstEvent::End(tag.clone())),
+                    ],
+                    "{md:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn alert_on_own_paragraph() {
+            let md = "> [!tip]\n>\n> Try it.";
+            let i = ConvertCallouts::new(MarkdownIter(Parser::new(md)));
+            let tag = TypstTag::Callout(typst::CalloutKind::Tip, None);
+
+            self::assert_eq!(
+                i.collect::<Vec<super::ParserEvent>>(),
+                vec![
+                    Typst(TypstEvent::Start(tag.clone())),
+                    Markdown(MdEvent::Start(MdTag::Paragraph)),
+                    Markdown(MdEvent::Text(CowStr::Borrowed("Try it."))),
+                    Markdown(MdEvent::End(MdTag::Paragraph)),
+                    Typst(TypstEvent::End(tag)),
+                ],
+            );
+        }
+
+        #[test]
+        fn admonish_body() {
+            let md = "```admonish tip\nSome **bold** text\n\n> [!NOTE]\n> Nested\n```\n";
+            let events =
+                typst::TypstFilter(convert_all(MarkdownIter(Parser::new(md)))).collect::<Vec<_>>();
+
+            // The body goes through the following converters, nested alerts included.
+            assert!(events.contains(&TypstEvent::Start(TypstTag::Strong)));
+            assert!(events.contains(&TypstEvent::Start(TypstTag::Callout(
+                typst::CalloutKind::Note,
+                None
+            ))));
+            assert!(!events.iter().any(|e| matches!(e, TypstEvent::Raw(_))));
+        }
+
+        #[test]
+        fn admonish() {
+            let md = "```admonish caution title=\"Mind the *gap*\"\nBody\n```\n";
+            let i = ConvertCallouts::new(MarkdownIter(Parser::new(md)));
+            let tag = TypstTag::Callout(
+                typst::CalloutKind::Warning,
+                Some("Mind the *gap*".to_string().into()),
+            );
+
+            self::assert_eq!(
+                i.collect::<Vec<super::ParserEvent>>(),
+                vec![
+                    Typst(TypstEvent::Start(tag.clone())),
+                    Markdown(MdEvent::Start(MdTag::Paragraph)),
+                    Markdown(MdEvent::Text("Body".to_string().into())),
+                    Markdown(MdEvent::End(MdTag::Paragraph)),
+                    Typst(TypstEvent::End(tag)),
+                ],
+            );
+        }
+
+        #[test]
+        fn other_code_blocks() {
+            let md = "```admonishment\nBody\n```\n";
+            let i = ConvertCallouts::new(MarkdownIter(Parser::new(md)));
+
+            self::assert_eq!(
+                i.collect::<Vec<super::ParserEvent>>(),
+                MarkdownIter(Parser::new(md)).collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    mod convenience {
+        use super::*;
+
+        #[test]
+        fn markdown_to_typst() {
+            let md = "\
+# Hello
+
+| A |
+|---|
+| b |
+";
+            self::assert_eq!(
+                super::markdown_to_typst(md),
+                "= Hello\n#table(align: [none])[\n#row[\n#cell[A]\n]\n#row[\n#cell[b]\n]\n]\n"
+            );
+        }
+    }
+
     mod tables {
         use super::*;
 
@@ -1058,7 +1678,7 @@ baz
 | Cell1   | Cell2   |
 ";
             let i = ConvertTables::new(MarkdownIter(Parser::new_ext(
-                &md,
+                md,
                 pulldown_cmark::Options::ENABLE_TABLES,
             )));
 
@@ -1101,7 +1721,7 @@ baz
 | Cell1   | Cell2   |
 ";
             let i = ConvertTables::new(MarkdownIter(Parser::new_ext(
-                &md,
+                md,
                 pulldown_cmark::Options::ENABLE_TABLES,
             )));
 
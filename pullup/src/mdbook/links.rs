@@ -0,0 +1,380 @@
+//! Check links in an mdBook without network access.
+//!
+//! Relative links and images are resolved against the chapters of the book and, for
+//! other files, the book's source directory. `#fragment`s are checked against the
+//! heading ids mdBook generates for each chapter. External URLs are listed but not
+//! checked.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+use crate::markdown;
+use crate::mdbook::{ChapterSource, Event, Tag};
+
+/// A link or image in a chapter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// The name of the chapter containing the link.
+    pub chapter: String,
+    /// The source path of the chapter, relative to the source directory.
+    pub path: Option<PathBuf>,
+    /// The destination as written.
+    pub destination: String,
+    /// Whether this is an image rather than a link.
+    pub image: bool,
+}
+
+/// Why a link is broken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// The file does not exist. The path is relative to the source directory, or the
+    /// path as written if it is outside of the source directory.
+    MissingFile(PathBuf),
+    /// The chapter has no heading with the id.
+    MissingAnchor(PathBuf, String),
+}
+
+/// The links of a book.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    /// Links to other hosts, which are not checked.
+    pub external: Vec<Link>,
+    /// Links that do not resolve.
+    pub broken: Vec<(Link, Problem)>,
+    /// The number of links checked, not counting external links.
+    pub checked: usize,
+}
+
+impl Report {
+    /// Whether no checked link is broken. External links are not checked.
+    pub fn is_ok(&self) -> bool {
+        self.broken.is_empty()
+    }
+}
+
+/// Checks the links of an mdBook event stream.
+#[derive(Debug, Clone, Default)]
+pub struct LinkChecker {
+    src: Option<PathBuf>,
+}
+
+impl LinkChecker {
+    /// Check files other than chapters in the `src` directory under the book's
+    /// [`Root`](Event::Root).
+    ///
+    /// The event stream does not carry `book.src` from `book.toml`, so books with
+    /// another source directory must set it with [`LinkChecker::src`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check files other than chapters in `src` instead.
+    pub fn src(mut self, src: impl Into<PathBuf>) -> Self {
+        self.src = Some(src.into());
+        self
+    }
+
+    /// Collect and check the links in `events`.
+    pub fn check<'a, T>(&self, events: T) -> Report
+    where
+        T: IntoIterator<Item = Event<'a>>,
+    {
+        let mut src = self.src.clone();
+        let mut links = vec![];
+        let mut anchors: HashMap<PathBuf, HashSet<String>> = HashMap::new();
+        // The name, path, and heading id counts of each open chapter.
+        let mut chapters: Vec<(String, Option<PathBuf>, HashMap<String, usize>)> = vec![];
+        let mut heading: Option<(Option<String>, String)> = None;
+
+        for event in events {
+            match event {
+                Event::Root(root) => {
+                    src.get_or_insert_with(|| root.join("src"));
+                }
+                Event::Start(Tag::Chapter(_, name, source, _)) => {
+                    let path = match source {
+                        Some(ChapterSource::Path(path)) => Some(path),
+                        _ => None,
+                    };
+                    if let Some(path) = &path {
+                        anchors.entry(path.clone()).or_default();
+                    }
+                    chapters.push((name.to_string(), path, HashMap::new()));
+                }
+                Event::End(Tag::Chapter(..)) => {
+                    chapters.pop();
+                }
+                Event::MarkdownContentEvent(e) => {
+                    let image = matches!(e, markdown::Event::Start(markdown::Tag::Image(..)));
+                    match e {
+                        markdown::Event::Start(markdown::Tag::Heading(_, id, _)) => {
+                            heading = Some((id.map(String::from), String::new()));
+                        }
+                        markdown::Event::Text(t) | markdown::Event::Code(t) => {
+                            if let Some((_, text)) = &mut heading {
+                                text.push_str(&t);
+                            }
+                        }
+                        markdown::Event::End(markdown::Tag::Heading(..)) => {
+                            let Some((id, text)) = heading.take() else {
+                                continue;
+                            };
+                            if let Some((_, Some(path), counts)) = chapters.last_mut() {
+                                let id = id.unwrap_or_else(|| unique_id(&text, counts));
+                                anchors.entry(path.clone()).or_default().insert(id);
+                            }
+                        }
+                        markdown::Event::Start(
+                            markdown::Tag::Link(ty, dest, _) | markdown::Tag::Image(ty, dest, _),
+                        ) if !matches!(
+                            ty,
+                            markdown::LinkType::Email | markdown::LinkType::Autolink
+                        ) || is_external(&dest) =>
+                        {
+                            if let Some((chapter, path, _)) = chapters.last() {
+                                links.push(Link {
+                                    chapter: chapter.clone(),
+                                    path: path.clone(),
+                                    destination: dest.to_string(),
+                                    image,
+                                });
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut report = Report::default();
+        for link in links {
+            if is_external(&link.destination) {
+                report.external.push(link);
+                continue;
+            }
+            report.checked += 1;
+            if let Some(problem) = resolve(&link, &anchors, src.as_deref()) {
+                report.broken.push((link, problem));
+            }
+        }
+        report
+    }
+}
+
+/// Whether `dest` has a URL scheme, e.g. `https:` or `mailto:`.
+fn is_external(dest: &str) -> bool {
+    dest.split_once(':').is_some_and(|(scheme, _)| {
+        !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+/// The id mdBook generates for a heading with `text`, made unique with `counts`.
+fn unique_id(text: &str, counts: &mut HashMap<String, usize>) -> String {
+    let id = text
+        .trim()
+        .chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '-' {
+                Some(c.to_ascii_lowercase())
+            } else if c.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect::<String>();
+    let count = counts.entry(id.clone()).or_insert(0);
+    let id = match *count {
+        0 => id,
+        n => format!("{id}-{n}"),
+    };
+    *count += 1;
+    id
+}
+
+/// Decode `%XX` escapes in a link destination. Invalid escapes are kept as written.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Join `path` to `base` and remove `.` and `..` components. Returns `None` if a `..`
+/// goes above the directory `base` is relative to.
+fn normalize(base: &Path, path: &str) -> Option<PathBuf> {
+    let mut out = PathBuf::new();
+    let joined = match path.strip_prefix('/') {
+        Some(path) => PathBuf::from(path),
+        None => base.join(path),
+    };
+    for component in joined.components() {
+        match component {
+            Component::ParentDir if !out.pop() => return None,
+            Component::Normal(c) => out.push(c),
+            _ => {}
+        }
+    }
+    Some(out)
+}
+
+/// Check a relative link, returning the problem if it does not resolve.
+fn resolve(
+    link: &Link,
+    anchors: &HashMap<PathBuf, HashSet<String>>,
+    src: Option<&Path>,
+) -> Option<Problem> {
+    let dest = link.destination.split('?').next().unwrap_or_default();
+    let (written, fragment) = dest.split_once('#').unwrap_or((dest, ""));
+    let (file, fragment) = (percent_decode(written), percent_decode(fragment));
+    let (file, fragment) = (file.as_str(), fragment.as_str());
+    let target = match (file, &link.path) {
+        ("", Some(path)) => path.clone(),
+        ("", None) => return None,
+        (file, path) => {
+            let base = path
+                .as_deref()
+                .and_then(Path::parent)
+                .unwrap_or(Path::new(""));
+            let Some(target) = normalize(base, file) else {
+                return Some(Problem::MissingFile(written.into()));
+            };
+            // mdBook renders chapters to HTML and directories to their index.
+            let candidates = if file.ends_with('/') || target.as_os_str().is_empty() {
+                vec![target.join("index.md"), target.join("README.md")]
+            } else if target.extension().is_some_and(|e| e == "html") {
+                let md = target.with_extension("md");
+                if md.file_name().is_some_and(|f| f == "index.md") {
+                    vec![md.clone(), md.with_file_name("README.md")]
+                } else {
+                    vec![md]
+                }
+            } else {
+                vec![target.clone()]
+            };
+            match candidates.into_iter().find(|c| anchors.contains_key(c)) {
+                Some(chapter) => chapter,
+                None => {
+                    let exists = src.is_some_and(|src| src.join(&target).exists());
+                    return (!exists).then_some(Problem::MissingFile(target));
+                }
+            }
+        }
+    };
+    let ids = anchors.get(&target)?;
+    (!fragment.is_empty() && !ids.contains(fragment))
+        .then(|| Problem::MissingAnchor(target, fragment.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mdbook::{ChapterKind, ChapterStatus};
+    use similar_asserts::assert_eq;
+
+    fn chapter<'a>(name: &'a str, path: &str, content: &'a str) -> Vec<Event<'a>> {
+        let tag = Tag::Chapter(
+            ChapterStatus::Active,
+            name.into(),
+            Some(ChapterSource::Path(path.into())),
+            ChapterKind::Prefix,
+        );
+        let mut events = vec![Event::Start(tag.clone())];
+        events.extend(markdown::Parser::new(content).map(Event::MarkdownContentEvent));
+        events.push(Event::End(tag));
+        events
+    }
+
+    fn link(chapter: &str, path: &str, destination: &str, image: bool) -> Link {
+        Link {
+            chapter: chapter.to_string(),
+            path: Some(path.into()),
+            destination: destination.to_string(),
+            image,
+        }
+    }
+
+    #[test]
+    fn check() {
+        let src = std::env::temp_dir().join(format!("pullup-links-{}", std::process::id()));
+        std::fs::create_dir_all(src.join("img")).expect("temp dir");
+        std::fs::write(src.join("img/cat.png"), b"").expect("temp file");
+        std::fs::write(src.join("img/my cat.png"), b"").expect("temp file");
+
+        let mut events = vec![Event::Root("/book".into())];
+        events.extend(chapter(
+            "Intro",
+            "intro.md",
+            "# Hello World\n\n## Hello World\n\n\
+             [ok](guide/usage.md#setup) [ok](#hello-world-1) [ok](guide/usage.html)\n\
+             [bad](#nope) [bad](missing.md) [bad](guide/usage.md#nope)\n\
+             [web](https://example.com) <https://example.com/auto>",
+        ));
+        events.extend(chapter(
+            "Usage",
+            "guide/usage.md",
+            "# Setup\n\n![cat](../img/cat.png) ![dog](../img/dog.png) [up](../intro.md)\n\
+             ![cat](../img/my%20cat.png) [up](../intro%2Emd#hello%2Dworld)\n\
+             [out](../../outside.md) [out](/../outside.md)",
+        ));
+        let report = LinkChecker::new().src(&src).check(events);
+        std::fs::remove_dir_all(&src).ok();
+
+        self::assert_eq!(
+            report,
+            Report {
+                external: vec![
+                    link("Intro", "intro.md", "https://example.com", false),
+                    link("Intro", "intro.md", "https://example.com/auto", false),
+                ],
+                broken: vec![
+                    (
+                        link("Intro", "intro.md", "#nope", false),
+                        Problem::MissingAnchor("intro.md".into(), "nope".to_string())
+                    ),
+                    (
+                        link("Intro", "intro.md", "missing.md", false),
+                        Problem::MissingFile("missing.md".into())
+                    ),
+                    (
+                        link("Intro", "intro.md", "guide/usage.md#nope", false),
+                        Problem::MissingAnchor("guide/usage.md".into(), "nope".to_string())
+                    ),
+                    (
+                        link("Usage", "guide/usage.md", "../img/dog.png", true),
+                        Problem::MissingFile("img/dog.png".into())
+                    ),
+                    (
+                        link("Usage", "guide/usage.md", "../../outside.md", false),
+                        Problem::MissingFile("../../outside.md".into())
+                    ),
+                    (
+                        link("Usage", "guide/usage.md", "/../outside.md", false),
+                        Problem::MissingFile("/../outside.md".into())
+                    ),
+                ],
+                checked: 13,
+            }
+        );
+    }
+}
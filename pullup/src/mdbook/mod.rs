@@ -1,8 +1,14 @@
 //! Support for [mdBook](https://github.com/rust-lang/mdBook).
 
 use crate::ParserEvent;
-pub use pulldown_mdbook::{ChapterSource, ChapterStatus, ContentType, Event, Parser, Tag};
+/// The `toml` crate used for `book.toml` tables.
+pub use pulldown_mdbook::toml;
+pub use pulldown_mdbook::{
+    ChapterKind, ChapterSource, ChapterStatus, ContentType, Event, Parser, SectionNumber, Tag,
+    TextDirection,
+};
 
+pub mod links;
 pub mod to;
 
 /// Assert that an iterator only contains mdBook events. Panics if another type of event
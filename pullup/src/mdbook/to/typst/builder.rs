@@ -1,19 +1,245 @@
 //! Builder to customize mdBook to Typst conversion.
 
 use core::marker::PhantomData;
+use std::collections::HashMap;
 
+use crate::convert::Converter;
 use crate::markdown::to::typst::*;
+use crate::mdbook;
 use crate::mdbook::to::typst::*;
+use crate::mdbook::toml;
 use crate::mdbook::MdbookIter;
 use crate::ParserEvent;
 
+/// A boxed [`ParserEvent`] iterator passed between pipeline stages.
+pub type Events<'a> = Box<dyn Iterator<Item = ParserEvent<'a>> + 'a>;
+
+type Adaptor<'a> = Box<dyn FnOnce(Events<'a>) -> Events<'a> + 'a>;
+
+/// A named stage of the mdBook to Typst [`Conversion`] pipeline.
+///
+/// Stages run in the order of [`Stage::ALL`]. Each stage corresponds to the builder
+/// switch of the same name. In `book.toml` stages are written in kebab case, e.g.
+/// `soft-breaks`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Stage {
+    /// Insert a title page. Disabled by default. To use a custom layout, replace this
+    /// stage with [`ConvertTitlePage`] using [`TitlePageConverter::with_layout`].
+    TitlePage,
+    /// Wrap the book in the [`Template`] of the [`Options`]. Only enabled if the options
+    /// have a template.
+    Template,
+    Title,
+    Authors,
+    Metadata,
+    /// Apply the builder's [`Options`], or the options in the book configuration.
+    Options,
+    /// Apply the builder's [`DraftPolicy`].
+    Drafts,
+    /// Apply the builder's [`SeparatorPolicy`].
+    Separators,
+    /// Insert an outline at the start of the content. Disabled by default, unless the
+    /// [`Options`] set a `toc-depth`. To insert it at `<!-- toc -->` markers instead,
+    /// replace this stage with [`ConvertOutline`] using [`OutlinePosition::Marker`].
+    Outline,
+    /// Convert parts in the builder's [`PartStyle`].
+    Parts,
+    /// Mark chapters with a source path as separate files, to write with [`Files`].
+    /// Disabled by default.
+    Files,
+    Chapters,
+    /// Unwrap Markdown content embedded in mdBook events. The Markdown stages after it
+    /// only run if this stage is enabled.
+    Content,
+    Callouts,
+    Headings,
+    Paragraphs,
+    SoftBreaks,
+    HardBreaks,
+    /// Expand emoji shortcodes. Disabled by default. To use Typst emoji symbols,
+    /// replace this stage with [`ConvertEmoji`] using [`EmojiStyle::Typst`].
+    Emoji,
+    Text,
+    Strong,
+    Emphasis,
+    #[serde(rename = "blockquotes")]
+    BlockQuotes,
+    Lists,
+    Code,
+    Links,
+    Tables,
+}
+
+impl Stage {
+    /// All stages in pipeline order.
+    pub const ALL: [Stage; 27] = [
+        Stage::TitlePage,
+        Stage::Template,
+        Stage::Title,
+        Stage::Authors,
+        Stage::Metadata,
+        Stage::Options,
+        Stage::Drafts,
+        Stage::Separators,
+        Stage::Outline,
+        Stage::Parts,
+        Stage::Files,
+        Stage::Chapters,
+        Stage::Content,
+        Stage::Callouts,
+        Stage::Headings,
+        Stage::Paragraphs,
+        Stage::SoftBreaks,
+        Stage::HardBreaks,
+        Stage::Emoji,
+        Stage::Text,
+        Stage::Strong,
+        Stage::Emphasis,
+        Stage::BlockQuotes,
+        Stage::Lists,
+        Stage::Code,
+        Stage::Links,
+        Stage::Tables,
+    ];
+
+    /// Apply the built-in implementation of this stage. Policy stages use the default
+    /// policy.
+    pub fn apply<'a>(self, events: Events<'a>) -> Events<'a> {
+        match self {
+            Stage::TitlePage => Box::new(ConvertTitlePage::new(events)),
+            Stage::Template => Box::new(ConvertTemplate::new(events)),
+            Stage::Title => Box::new(ConvertTitle::new(events)),
+            Stage::Authors => Box::new(ConvertAuthors::new(events)),
+            Stage::Metadata => Box::new(ConvertMetadata::new(events)),
+            Stage::Options => Box::new(ConvertOptions::new(events)),
+            Stage::Drafts => Box::new(ConvertDrafts::new(events)),
+            Stage::Separators => Box::new(ConvertSeparators::new(events)),
+            Stage::Outline => Box::new(ConvertOutline::new(events)),
+            Stage::Parts => Box::new(ConvertParts::new(events)),
+            Stage::Files => Box::new(ConvertFiles::new(events)),
+            Stage::Chapters => Box::new(ConvertChapter::new(events)),
+            Stage::Content => Box::new(events.map(|e| match e {
+                ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(m)) => {
+                    ParserEvent::Markdown(m)
+                }
+                x => x,
+            })),
+            Stage::Callouts => Box::new(ConvertCallouts::new(events)),
+            Stage::Headings => Box::new(ConvertHeadings::new(events)),
+            Stage::Paragraphs => Box::new(ConvertParagraphs::new(events)),
+            Stage::SoftBreaks => Box::new(ConvertSoftBreaks::new(events)),
+            Stage::HardBreaks => Box::new(ConvertHardBreaks::new(events)),
+            Stage::Emoji => Box::new(ConvertEmoji::new(events)),
+            Stage::Text => Box::new(ConvertText::new(events)),
+            Stage::Strong => Box::new(ConvertStrong::new(events)),
+            Stage::Emphasis => Box::new(ConvertEmphasis::new(events)),
+            Stage::BlockQuotes => Box::new(ConvertBlockQuotes::new(events)),
+            Stage::Lists => Box::new(ConvertLists::new(events)),
+            Stage::Code => Box::new(ConvertCode::new(events)),
+            Stage::Links => Box::new(ConvertLinks::new(events)),
+            Stage::Tables => Box::new(ConvertTables::new(events)),
+        }
+    }
+}
+
+/// Custom steps to add to the [`Conversion`] pipeline.
+///
+/// Each step is a function taking the events so far and returning a new iterator,
+/// typically a converter's `new` function or a closure calling
+/// [`Converter::over`](crate::convert::Converter::over).
+///
+/// Steps inserted before or after a stage run even if that stage is disabled. Steps
+/// inserted at the same position run in the order they were added.
+#[derive(Default)]
+pub struct Hooks<'a> {
+    before: Vec<(Stage, Adaptor<'a>)>,
+    after: Vec<(Stage, Adaptor<'a>)>,
+    replace: HashMap<Stage, Adaptor<'a>>,
+}
+
+impl<'a> Hooks<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `f` right before `stage`.
+    pub fn before<F, J>(mut self, stage: Stage, f: F) -> Self
+    where
+        F: FnOnce(Events<'a>) -> J + 'a,
+        J: Iterator<Item = ParserEvent<'a>> + 'a,
+    {
+        self.before.push((stage, boxed(f)));
+        self
+    }
+
+    /// Run `f` right after `stage`.
+    pub fn after<F, J>(mut self, stage: Stage, f: F) -> Self
+    where
+        F: FnOnce(Events<'a>) -> J + 'a,
+        J: Iterator<Item = ParserEvent<'a>> + 'a,
+    {
+        self.after.push((stage, boxed(f)));
+        self
+    }
+
+    /// Run `f` instead of the built-in implementation of `stage`. The replacement only
+    /// runs if the stage is enabled.
+    pub fn replace<F, J>(mut self, stage: Stage, f: F) -> Self
+    where
+        F: FnOnce(Events<'a>) -> J + 'a,
+        J: Iterator<Item = ParserEvent<'a>> + 'a,
+    {
+        self.replace.insert(stage, boxed(f));
+        self
+    }
+
+    fn run(&mut self, stage: Stage, enabled: bool, mut events: Events<'a>) -> Events<'a> {
+        for f in take_stage(&mut self.before, stage) {
+            events = f(events);
+        }
+        if enabled {
+            events = match self.replace.remove(&stage) {
+                Some(f) => f(events),
+                None => stage.apply(events),
+            };
+        }
+        for f in take_stage(&mut self.after, stage) {
+            events = f(events);
+        }
+        events
+    }
+}
+
+fn boxed<'a, F, J>(f: F) -> Adaptor<'a>
+where
+    F: FnOnce(Events<'a>) -> J + 'a,
+    J: Iterator<Item = ParserEvent<'a>> + 'a,
+{
+    Box::new(move |events| Box::new(f(events)))
+}
+
+/// Remove and return the adaptors for `stage`, keeping their order.
+fn take_stage<'a>(adaptors: &mut Vec<(Stage, Adaptor<'a>)>, stage: Stage) -> Vec<Adaptor<'a>> {
+    let (matching, rest) = core::mem::take(adaptors)
+        .into_iter()
+        .partition::<Vec<_>, _>(|(s, _)| *s == stage);
+    *adaptors = rest;
+    matching.into_iter().map(|(_, f)| f).collect()
+}
+
 #[derive(typed_builder::TypedBuilder)]
 #[builder(build_method(vis="", name=__build))]
 #[builder(field_defaults(default = true))]
 /// Converts Mdbook to Typst.
 ///
 /// Using the builder one can choose which conversions to apply. By default, all
-/// conversions are enabled.
+/// conversions are enabled. Conversions are applied in the order of [`Stage::ALL`],
+/// which follows the order documented in the [markdown
+/// module](crate::markdown::to::typst) for Markdown content.
+///
+/// Custom steps can be inserted before or after any stage, and stages can be replaced,
+/// via [`Hooks`].
 ///
 /// For more control over conversion, use the converters in [the parent
 /// module](crate::mdbook::to::typst) and [markdown module](crate::markdown::to::typst)
@@ -22,14 +248,36 @@ use crate::ParserEvent;
 pub struct Conversion<'a, T> {
     #[builder(!default)]
     events: T,
+    #[builder(default = false)]
+    title_page: bool,
     title: bool,
     authors: bool,
+    metadata: bool,
+    #[builder(default)]
+    drafts: DraftPolicy<'a>,
+    #[builder(default)]
+    separators: SeparatorPolicy,
+    /// Options replacing those in the book configuration as a whole. The configured
+    /// options are not read, so fields left at their defaults here are not taken from
+    /// `book.toml`.
+    #[builder(default, setter(strip_option))]
+    options: Option<Options>,
+    #[builder(default = false)]
+    outline: bool,
+    #[builder(default)]
+    part_style: PartStyle,
+    parts: bool,
+    #[builder(default = false)]
+    files: bool,
     chapters: bool,
     content: bool,
+    callouts: bool,
     headings: bool,
     paragraphs: bool,
     soft_breaks: bool,
     hard_breaks: bool,
+    #[builder(default = false)]
+    emoji: bool,
     text: bool,
     strong: bool,
     emphasis: bool,
@@ -39,22 +287,73 @@ pub struct Conversion<'a, T> {
     links: bool,
     tables: bool,
     #[builder(default)]
+    hooks: Hooks<'a>,
+    #[builder(default)]
     _p: PhantomData<&'a ()>,
 }
 
+impl<T> Conversion<'_, T> {
+    fn enabled(&self, stage: Stage) -> bool {
+        if let Some(options) = &self.options {
+            if options.skip.contains(&stage) {
+                return false;
+            }
+        }
+        match stage {
+            Stage::Drafts | Stage::Separators | Stage::Options => true,
+            Stage::TitlePage => self.title_page,
+            Stage::Template => self.options.as_ref().is_some_and(|o| o.template.is_some()),
+            Stage::Title => self.title,
+            Stage::Authors => self.authors,
+            Stage::Metadata => self.metadata,
+            Stage::Outline => {
+                self.outline || self.options.as_ref().is_some_and(|o| o.toc_depth.is_some())
+            }
+            Stage::Parts => self.parts,
+            Stage::Files => self.files,
+            Stage::Chapters => self.chapters,
+            Stage::Content => self.content,
+            Stage::Callouts => self.content && self.callouts,
+            Stage::Headings => self.content && self.headings,
+            Stage::Paragraphs => self.content && self.paragraphs,
+            Stage::SoftBreaks => self.content && self.soft_breaks,
+            Stage::HardBreaks => self.content && self.hard_breaks,
+            Stage::Emoji => self.content && self.emoji,
+            Stage::Text => self.content && self.text,
+            Stage::Strong => self.content && self.strong,
+            Stage::Emphasis => self.content && self.emphasis,
+            Stage::BlockQuotes => self.content && self.blockquotes,
+            Stage::Lists => self.content && self.lists,
+            Stage::Code => self.content && self.code,
+            Stage::Links => self.content && self.links,
+            Stage::Tables => self.content && self.tables,
+        }
+    }
+}
+
 #[allow(dead_code, non_camel_case_types, missing_docs)]
-#[automatically_derived]
 impl<
         'a,
         T,
+        __title_page: ::typed_builder::Optional<bool>,
         __title: ::typed_builder::Optional<bool>,
         __authors: ::typed_builder::Optional<bool>,
+        __metadata: ::typed_builder::Optional<bool>,
+        __drafts: ::typed_builder::Optional<DraftPolicy<'a>>,
+        __separators: ::typed_builder::Optional<SeparatorPolicy>,
+        __options: ::typed_builder::Optional<Option<Options>>,
+        __outline: ::typed_builder::Optional<bool>,
+        __part_style: ::typed_builder::Optional<PartStyle>,
+        __parts: ::typed_builder::Optional<bool>,
+        __files: ::typed_builder::Optional<bool>,
         __chapters: ::typed_builder::Optional<bool>,
         __content: ::typed_builder::Optional<bool>,
+        __callouts: ::typed_builder::Optional<bool>,
         __headings: ::typed_builder::Optional<bool>,
         __paragraphs: ::typed_builder::Optional<bool>,
         __soft_breaks: ::typed_builder::Optional<bool>,
         __hard_breaks: ::typed_builder::Optional<bool>,
+        __emoji: ::typed_builder::Optional<bool>,
         __text: ::typed_builder::Optional<bool>,
         __strong: ::typed_builder::Optional<bool>,
         __emphasis: ::typed_builder::Optional<bool>,
@@ -63,6 +362,7 @@ impl<
         __code: ::typed_builder::Optional<bool>,
         __links: ::typed_builder::Optional<bool>,
         __tables: ::typed_builder::Optional<bool>,
+        __hooks: ::typed_builder::Optional<Hooks<'a>>,
         ___p: ::typed_builder::Optional<PhantomData<&'a ()>>,
     >
     ConversionBuilder<
@@ -70,14 +370,25 @@ impl<
         T,
         (
             (T,),
+            __title_page,
             __title,
             __authors,
+            __metadata,
+            __drafts,
+            __separators,
+            __options,
+            __outline,
+            __part_style,
+            __parts,
+            __files,
             __chapters,
             __content,
+            __callouts,
             __headings,
             __paragraphs,
             __soft_breaks,
             __hard_breaks,
+            __emoji,
             __text,
             __strong,
             __emphasis,
@@ -86,70 +397,671 @@ impl<
             __code,
             __links,
             __tables,
+            __hooks,
             ___p,
         ),
     >
 where
     T: Iterator<Item = crate::mdbook::Event<'a>> + 'a,
 {
-    pub fn build(self) -> impl Iterator<Item = ParserEvent<'a>> {
-        let this = self.__build();
-        let mut events: Box<dyn Iterator<Item = ParserEvent<'_>>> =
-            Box::new(MdbookIter(this.events));
-        if this.title {
-            events = Box::new(ConvertTitle::new(events));
-        }
-        if this.authors {
-            events = Box::new(ConvertAuthors::new(events));
-        }
-        if this.chapters {
-            events = Box::new(ConvertChapter::new(events));
-        }
-        if this.content {
-            events = Box::new(events.map(|e| match e {
-                ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(m)) => {
-                    ParserEvent::Markdown(m)
-                }
-                x => x,
-            }));
-            if this.headings {
-                events = Box::new(ConvertHeadings::new(events));
-            }
-            if this.paragraphs {
-                events = Box::new(ConvertParagraphs::new(events));
-            }
-            if this.soft_breaks {
-                events = Box::new(ConvertSoftBreaks::new(events));
-            }
-            if this.hard_breaks {
-                events = Box::new(ConvertHardBreaks::new(events));
-            }
-            if this.text {
-                events = Box::new(ConvertText::new(events));
-            }
-            if this.strong {
-                events = Box::new(ConvertStrong::new(events));
-            }
-            if this.emphasis {
-                events = Box::new(ConvertEmphasis::new(events));
+    /// Build the conversion pipeline.
+    ///
+    /// Unless options are set on the builder, they are read from the renderer table in
+    /// the book configuration at the start of the events, which fails if the table is
+    /// not valid [`Options`].
+    pub fn build(self) -> Result<impl Iterator<Item = ParserEvent<'a>>, toml::de::Error> {
+        let mut this = self.__build();
+        let head = match this.options {
+            Some(_) => vec![],
+            None => {
+                let (head, options) = read_options(&mut this.events)?;
+                this.options = options;
+                head
             }
-            if this.blockquotes {
-                events = Box::new(ConvertBlockQuotes::new(events));
-            }
-            if this.lists {
-                events = Box::new(ConvertLists::new(events));
-            }
-            if this.code {
-                events = Box::new(ConvertCode::new(events));
+        };
+        let enabled = Stage::ALL.map(|stage| (stage, this.enabled(stage)));
+
+        let drafts = this.drafts.clone();
+        this.hooks
+            .replace
+            .entry(Stage::Drafts)
+            .or_insert_with(|| boxed(|e| DraftConverter::new(drafts).over(e)));
+        let separators = this.separators;
+        this.hooks
+            .replace
+            .entry(Stage::Separators)
+            .or_insert_with(|| boxed(move |e| SeparatorConverter::new(separators).over(e)));
+        let part_style = this.part_style;
+        this.hooks
+            .replace
+            .entry(Stage::Parts)
+            .or_insert_with(|| boxed(move |e| PartConverter::new(part_style).over(e)));
+        if let Some(options) = this.options.clone() {
+            let pagebreaks = options.chapter_page_breaks;
+            if let Some(template) = options.template.clone() {
+                this.hooks
+                    .replace
+                    .entry(Stage::Template)
+                    .or_insert_with(|| boxed(|e| TemplateConverter::new(template).over(e)));
             }
-            if this.links {
-                events = Box::new(ConvertLinks::new(events));
+            if let Some(depth) = options.toc_depth {
+                this.hooks.replace.entry(Stage::Outline).or_insert_with(|| {
+                    boxed(move |e| {
+                        OutlineConverter::new(OutlinePosition::Start)
+                            .depth(depth)
+                            .over(e)
+                    })
+                });
             }
-            if this.tables {
-                events = Box::new(ConvertTables::new(events));
+            if let Some(punctuation) = options.smart_punctuation {
+                this.hooks.replace.entry(Stage::Text).or_insert_with(|| {
+                    boxed(move |e| TextConverter::with_punctuation(punctuation).over(e))
+                });
             }
+            this.hooks
+                .replace
+                .entry(Stage::Options)
+                .or_insert_with(|| boxed(|e| OptionsConverter::new(options).over(e)));
+            this.hooks
+                .replace
+                .entry(Stage::Chapters)
+                .or_insert_with(|| {
+                    boxed(move |e| ChapterConverter::with_pagebreaks(pagebreaks).over(e))
+                });
         }
 
-        events
+        let mut events: Events<'a> = Box::new(MdbookIter(head.into_iter().chain(this.events)));
+        for (stage, enabled) in enabled {
+            events = this.hooks.run(stage, enabled, events);
+        }
+
+        Ok(events)
+    }
+}
+
+/// The events read by [`read_options`] and the options found in them.
+type Head<'a> = (Vec<mdbook::Event<'a>>, Option<Options>);
+
+/// Read the book configuration at the start of `events` and the [`Options`] in its
+/// renderer table, if any. Returns the events read.
+fn read_options<'a, T>(events: &mut T) -> Result<Head<'a>, toml::de::Error>
+where
+    T: Iterator<Item = mdbook::Event<'a>>,
+{
+    let mut head = vec![];
+    let mut options = None;
+    let Some(first) = events.next() else {
+        return Ok((head, options));
+    };
+    let configuration = first == mdbook::Event::Start(mdbook::Tag::BookConfiguration);
+    head.push(first);
+    if !configuration {
+        return Ok((head, options));
+    }
+    for event in events.by_ref() {
+        let end = event == mdbook::Event::End(mdbook::Tag::BookConfiguration);
+        if let mdbook::Event::RendererConfig(table) = &event {
+            options = Some(Options::from_table(table)?);
+        }
+        head.push(event);
+        if end {
+            break;
+        }
+    }
+    Ok((head, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markdown;
+    use crate::typst;
+    use similar_asserts::assert_eq;
+
+    use mdbook::Event as MdbookEvent;
+    use ParserEvent::*;
+
+    fn content<'a>() -> Vec<MdbookEvent<'a>> {
+        vec![
+            MdbookEvent::MarkdownContentEvent(markdown::Event::Start(markdown::Tag::Paragraph)),
+            MdbookEvent::MarkdownContentEvent(markdown::Event::Text("hi".into())),
+            MdbookEvent::MarkdownContentEvent(markdown::Event::End(markdown::Tag::Paragraph)),
+        ]
+    }
+
+    fn policy_events() -> Vec<MdbookEvent<'static>> {
+        let draft = mdbook::Tag::Chapter(
+            mdbook::ChapterStatus::Draft,
+            "Later".into(),
+            None,
+            mdbook::ChapterKind::Suffix,
+        );
+        vec![
+            MdbookEvent::Start(mdbook::Tag::BookContent),
+            MdbookEvent::Start(draft.clone()),
+            MdbookEvent::End(draft),
+            MdbookEvent::Separator,
+            MdbookEvent::End(mdbook::Tag::BookContent),
+        ]
+    }
+
+    /// Typst markup for `events`, which must all be Typst events apart from the book's
+    /// configuration and content boundaries.
+    fn markup<'a>(events: impl Iterator<Item = ParserEvent<'a>>) -> String {
+        typst::to::markup::TypstMarkup::new(typst::AssertTypst(events.filter(|e| {
+            !matches!(
+                e,
+                Mdbook(
+                    MdbookEvent::Start(mdbook::Tag::BookConfiguration | mdbook::Tag::BookContent)
+                        | MdbookEvent::End(
+                            mdbook::Tag::BookConfiguration | mdbook::Tag::BookContent
+                        )
+                )
+            )
+        })))
+        .collect()
+    }
+
+    #[test]
+    fn default_pipeline() {
+        let events = Conversion::builder()
+            .events(content().into_iter())
+            .build()
+            .expect("valid options")
+            .collect::<Vec<_>>();
+        self::assert_eq!(
+            events,
+            vec![
+                Typst(typst::Event::Start(typst::Tag::Paragraph)),
+                Typst(typst::Event::Text("hi".into())),
+                Typst(typst::Event::End(typst::Tag::Paragraph)),
+            ]
+        );
+    }
+
+    #[test]
+    fn before_and_after() {
+        // Before text conversion the text is still Markdown, after it is Typst.
+        let hooks = Hooks::new()
+            .before(Stage::Text, |events| {
+                events.map(|e| match e {
+                    Markdown(markdown::Event::Text(t)) => {
+                        Markdown(markdown::Event::Text(t.to_uppercase().into()))
+                    }
+                    x => x,
+                })
+            })
+            .after(Stage::Text, |events| {
+                events.flat_map(|e| match e {
+                    Typst(typst::Event::Text(t)) => vec![
+                        Typst(typst::Event::Text(t)),
+                        Typst(typst::Event::Text("!".into())),
+                    ],
+                    x => vec![x],
+                })
+            });
+        let events = Conversion::builder()
+            .events(content().into_iter())
+            .hooks(hooks)
+            .build()
+            .expect("valid options")
+            .collect::<Vec<_>>();
+        self::assert_eq!(
+            events,
+            vec![
+                Typst(typst::Event::Start(typst::Tag::Paragraph)),
+                Typst(typst::Event::Text("HI".into())),
+                Typst(typst::Event::Text("!".into())),
+                Typst(typst::Event::End(typst::Tag::Paragraph)),
+            ]
+        );
+    }
+
+    #[test]
+    fn replace() {
+        let hooks = Hooks::new().replace(Stage::Paragraphs, |events| {
+            events.filter(|e| {
+                !matches!(
+                    e,
+                    Markdown(markdown::Event::Start(_) | markdown::Event::End(_))
+                )
+            })
+        });
+        let events = Conversion::builder()
+            .events(content().into_iter())
+            .hooks(hooks)
+            .build()
+            .expect("valid options")
+            .collect::<Vec<_>>();
+        self::assert_eq!(events, vec![Typst(typst::Event::Text("hi".into()))]);
+    }
+
+    #[test]
+    fn replacement_skipped_when_disabled() {
+        let hooks = Hooks::new().replace(Stage::Text, |events| events.filter(|_| false));
+        let events = Conversion::builder()
+            .events(content().into_iter())
+            .text(false)
+            .hooks(hooks)
+            .build()
+            .expect("valid options")
+            .collect::<Vec<_>>();
+        self::assert_eq!(
+            events,
+            vec![
+                Typst(typst::Event::Start(typst::Tag::Paragraph)),
+                Markdown(markdown::Event::Text("hi".into())),
+                Typst(typst::Event::End(typst::Tag::Paragraph)),
+            ]
+        );
+    }
+
+    #[test]
+    fn content_headings() {
+        let numbered = |name: &'static str, number: Vec<u32>| {
+            mdbook::Tag::Chapter(
+                mdbook::ChapterStatus::Active,
+                name.into(),
+                None,
+                mdbook::ChapterKind::Numbered(mdbook::SectionNumber(number)),
+            )
+        };
+        let heading = markdown::Tag::Heading(markdown::HeadingLevel::H1, None, vec![]);
+        let events = vec![
+            MdbookEvent::Start(numbered("One", vec![1])),
+            MdbookEvent::MarkdownContentEvent(markdown::Event::Start(heading.clone())),
+            MdbookEvent::MarkdownContentEvent(markdown::Event::Text("Intro".into())),
+            MdbookEvent::MarkdownContentEvent(markdown::Event::End(heading)),
+            MdbookEvent::Start(numbered("Two", vec![1, 1])),
+            MdbookEvent::End(numbered("Two", vec![1, 1])),
+            MdbookEvent::End(numbered("One", vec![1])),
+        ];
+        let markup: String = typst::to::markup::TypstMarkup::new(typst::AssertTypst(
+            Conversion::builder()
+                .events(events.into_iter())
+                .build()
+                .expect("valid options"),
+        ))
+        .collect();
+        // The content heading is not numbered and does not step the counter, so the
+        // sub-chapter is still numbered 1.1.
+        self::assert_eq!(
+            markup,
+            "\
+#set heading(numbering: \"1.1.\")
+#counter(heading).update((0,))
+= One
+#heading(level: 2, numbering: none)[Intro]
+#counter(heading).update((1, 0))
+== Two
+#pagebreak(weak: true)
+#pagebreak(weak: true)
+"
+        );
+    }
+
+    #[test]
+    fn parts() {
+        let part = mdbook::Tag::Part(Some("Basics".into()), Some(1));
+        let chapter = mdbook::Tag::Chapter(
+            mdbook::ChapterStatus::Active,
+            "One".into(),
+            None,
+            mdbook::ChapterKind::Numbered(mdbook::SectionNumber(vec![1])),
+        );
+        let events = vec![
+            MdbookEvent::Start(mdbook::Tag::Part(None, None)),
+            MdbookEvent::End(mdbook::Tag::Part(None, None)),
+            MdbookEvent::Start(part.clone()),
+            MdbookEvent::Start(chapter.clone()),
+            MdbookEvent::End(chapter),
+            MdbookEvent::End(part),
+        ];
+        let markup: String = typst::to::markup::TypstMarkup::new(typst::AssertTypst(
+            Conversion::builder()
+                .events(events.into_iter())
+                .build()
+                .expect("valid options"),
+        ))
+        .collect();
+        self::assert_eq!(
+            markup,
+            "\
+#set heading(numbering: none)
+= Basics
+#set heading(numbering: (..n) => numbering(\"1.1.\", ..n.pos().slice(1)))
+#counter(heading).update((0, 0))
+== One
+#pagebreak(weak: true)
+"
+        );
+    }
+
+    #[test]
+    fn part_pages() {
+        let part = mdbook::Tag::Part(Some("Basics".into()), Some(1));
+        let chapter = mdbook::Tag::Chapter(
+            mdbook::ChapterStatus::Active,
+            "One".into(),
+            None,
+            mdbook::ChapterKind::Numbered(mdbook::SectionNumber(vec![1])),
+        );
+        let events = vec![
+            MdbookEvent::Start(part.clone()),
+            MdbookEvent::Start(chapter.clone()),
+            MdbookEvent::End(chapter),
+            MdbookEvent::End(part),
+        ];
+        let markup: String = typst::to::markup::TypstMarkup::new(typst::AssertTypst(
+            Conversion::builder()
+                .events(events.into_iter())
+                .part_style(PartStyle::Page)
+                .build()
+                .expect("valid options"),
+        ))
+        .collect();
+        self::assert_eq!(
+            markup,
+            "\
+#pagebreak(weak: true)
+#align(center + horizon, heading(level: 1, numbering: none, \"Basics\"))
+#pagebreak(weak: true)
+#set heading(numbering: \"1.1.\")
+#counter(heading).update((0,))
+= One
+#pagebreak(weak: true)
+"
+        );
+    }
+
+    #[test]
+    fn default_policies() {
+        let unchanged = Conversion::builder()
+            .events(policy_events().into_iter())
+            .hooks(Hooks::new().replace(Stage::Drafts, |e| e))
+            .build()
+            .expect("valid options")
+            .collect::<Vec<_>>();
+        let events = Conversion::builder()
+            .events(policy_events().into_iter())
+            .build()
+            .expect("valid options")
+            .collect::<Vec<_>>();
+        self::assert_eq!(events, unchanged);
+        self::assert_eq!(
+            markup(events.into_iter()),
+            "\
+#set heading(numbering: none)
+= Later
+#pagebreak(weak: true)
+"
+        );
+    }
+
+    #[test]
+    fn drop_drafts() {
+        self::assert_eq!(
+            markup(
+                Conversion::builder()
+                    .events(policy_events().into_iter())
+                    .drafts(DraftPolicy::Drop)
+                    .build()
+                    .expect("valid options")
+            ),
+            ""
+        );
+    }
+
+    #[test]
+    fn placeholder_drafts() {
+        self::assert_eq!(
+            markup(
+                Conversion::builder()
+                    .events(policy_events().into_iter())
+                    .drafts(DraftPolicy::Placeholder("Coming soon.".into()))
+                    .build()
+                    .expect("valid options")
+            ),
+            "\
+#set heading(numbering: none)
+= Later
+#par()[Coming soon.]
+#pagebreak(weak: true)
+"
+        );
+    }
+
+    #[test]
+    fn appendix_drafts() {
+        self::assert_eq!(
+            markup(
+                Conversion::builder()
+                    .events(policy_events().into_iter())
+                    .drafts(DraftPolicy::Appendix("Drafts".into()))
+                    .build()
+                    .expect("valid options")
+            ),
+            "\
+#set heading(numbering: none)
+= Drafts
+- Later
+#pagebreak(weak: true)
+"
+        );
+    }
+
+    #[test]
+    fn ignore_separators() {
+        let events = Conversion::builder()
+            .events(policy_events().into_iter())
+            .separators(SeparatorPolicy::Ignore)
+            .build()
+            .expect("valid options")
+            .collect::<Vec<_>>();
+        assert!(!events.contains(&Mdbook(MdbookEvent::Separator)));
+    }
+
+    #[test]
+    fn divider_separators() {
+        self::assert_eq!(
+            markup(
+                Conversion::builder()
+                    .events(policy_events().into_iter())
+                    .drafts(DraftPolicy::Drop)
+                    .separators(SeparatorPolicy::Divider)
+                    .build()
+                    .expect("valid options")
+            ),
+            "#line(length: 100%)\n"
+        );
+    }
+
+    #[test]
+    fn options() {
+        let table: mdbook::toml::value::Table = mdbook::toml::from_str(
+            r#"
+            skip = ["emphasis"]
+            paper = "a5"
+            chapter-page-breaks = false
+            "#,
+        )
+        .expect("valid table");
+        let chapter = mdbook::Tag::Chapter(
+            mdbook::ChapterStatus::Active,
+            "One".into(),
+            None,
+            mdbook::ChapterKind::Prefix,
+        );
+        let events = [
+            vec![
+                MdbookEvent::Start(mdbook::Tag::BookConfiguration),
+                MdbookEvent::RendererConfig(&table),
+                MdbookEvent::End(mdbook::Tag::BookConfiguration),
+                MdbookEvent::Start(mdbook::Tag::BookContent),
+                MdbookEvent::Start(chapter.clone()),
+            ],
+            content(),
+            vec![
+                MdbookEvent::End(chapter),
+                MdbookEvent::End(mdbook::Tag::BookContent),
+            ],
+        ]
+        .concat();
+
+        // Options are read from the book configuration.
+        self::assert_eq!(
+            markup(
+                Conversion::builder()
+                    .events(events.clone().into_iter())
+                    .build()
+                    .expect("valid options")
+            ),
+            "\
+#set page(paper: \"a5\")
+#set heading(numbering: none)
+= One
+#par()[hi]
+"
+        );
+
+        // The outline depth is read from the options.
+        let outline = Conversion::builder()
+            .events(events.clone().into_iter())
+            .options(Options {
+                toc_depth: Some(2),
+                ..Options::default()
+            })
+            .outline(true)
+            .build()
+            .expect("valid options")
+            .filter(|e| matches!(e, Typst(typst::Event::FunctionCall(_, f, _)) if f.as_ref() == "outline"))
+            .collect::<Vec<_>>();
+        self::assert_eq!(
+            outline,
+            vec![Typst(typst::Event::FunctionCall(
+                None,
+                "outline".into(),
+                vec!["depth: 2".into()]
+            ))]
+        );
+
+        // The title page follows the configuration.
+        let title_page = Conversion::builder()
+            .events(events.clone().into_iter())
+            .title_page(true)
+            .build()
+            .expect("valid options")
+            .filter(|e| matches!(e, Typst(typst::Event::FunctionCall(_, f, _)) if f.as_ref() == "title-page"))
+            .count();
+        self::assert_eq!(title_page, 1);
+
+        // A template replaces the document settings.
+        let markup_with_template = markup(
+            Conversion::builder()
+                .events(events.clone().into_iter())
+                .options(Options {
+                    template: Some(Template::default()),
+                    ..Options::default()
+                })
+                .build()
+                .expect("valid options"),
+        );
+        assert!(markup_with_template
+            .starts_with("#import \"template.typ\": *\n#show: template.with()\n"));
+
+        // Options given to the builder replace the configured ones, rather than being
+        // merged with them: neither the paper nor the page breaks are taken from the
+        // configuration.
+        self::assert_eq!(
+            markup(
+                Conversion::builder()
+                    .events(events.into_iter())
+                    .options(Options {
+                        fonts: vec!["A".into()],
+                        ..Options::default()
+                    })
+                    .build()
+                    .expect("valid options")
+            ),
+            "\
+#set text(font: (\"A\",))
+#set heading(numbering: none)
+= One
+#par()[hi]
+#pagebreak(weak: true)
+"
+        );
+    }
+
+    #[test]
+    fn invalid_options() {
+        let table: mdbook::toml::value::Table =
+            mdbook::toml::from_str("paper = \"a5\"\ntoc-depth = \"deep\"").expect("valid table");
+        let events = vec![
+            MdbookEvent::Start(mdbook::Tag::BookConfiguration),
+            MdbookEvent::RendererConfig(&table),
+            MdbookEvent::End(mdbook::Tag::BookConfiguration),
+        ];
+        let err = Conversion::builder()
+            .events(events.into_iter())
+            .build()
+            .err()
+            .expect("invalid options");
+        assert!(err.to_string().contains("toc-depth"), "{err}");
+    }
+
+    #[test]
+    fn toc_depth_enables_outline() {
+        let events = vec![
+            MdbookEvent::Start(mdbook::Tag::BookContent),
+            MdbookEvent::End(mdbook::Tag::BookContent),
+        ];
+        let outline = Conversion::builder()
+            .events(events.into_iter())
+            .options(Options {
+                toc_depth: Some(3),
+                ..Options::default()
+            })
+            .build()
+            .expect("valid options")
+            .filter(|e| matches!(e, Typst(typst::Event::FunctionCall(_, f, _)) if f.as_ref() == "outline"))
+            .collect::<Vec<_>>();
+        self::assert_eq!(
+            outline,
+            vec![Typst(typst::Event::FunctionCall(
+                None,
+                "outline".into(),
+                vec!["depth: 3".into()]
+            ))]
+        );
+    }
+
+    #[test]
+    fn files() {
+        let chapter = mdbook::Tag::Chapter(
+            mdbook::ChapterStatus::Active,
+            "One".into(),
+            Some(mdbook::ChapterSource::Path("one.md".into())),
+            mdbook::ChapterKind::Prefix,
+        );
+        let events = vec![
+            MdbookEvent::Start(chapter.clone()),
+            MdbookEvent::End(chapter),
+        ];
+        let files = Files::from_events(typst::AssertTypst(
+            Conversion::builder()
+                .events(events.into_iter())
+                .files(true)
+                .build()
+                .expect("valid options"),
+        ));
+        self::assert_eq!(files.main, "#include \"chapters/one.typ\"\n");
+        self::assert_eq!(
+            files.files,
+            vec![(
+                "chapters/one.typ".into(),
+                "#set heading(numbering: none)\n= One\n#pagebreak(weak: true)\n".to_string()
+            )]
+        );
     }
 }
@@ -0,0 +1,327 @@
+//! Write a book as one Typst file per chapter.
+
+use std::collections::VecDeque;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use crate::convert::{Converter, ConverterIter, Input};
+use crate::mdbook;
+use crate::typst;
+use crate::typst::to::markup::TypstMarkup;
+use crate::ParserEvent;
+
+/// The name of the main file written by [`Files`].
+pub const MAIN: &str = "main.typ";
+
+/// The directory chapter files are placed in, so they cannot clash with [`MAIN`] or
+/// other files next to it, such as a template.
+pub const CHAPTERS: &str = "chapters";
+
+/// Mark the content of each mdBook chapter with a source path as a Typst
+/// [`File`](typst::Tag::File), at the chapter's path with a `.typ` extension in the
+/// [`CHAPTERS`] directory.
+///
+/// Chapters without a path, such as drafts, stay in the enclosing file, as do chapters
+/// whose path is absolute or has `..` components, which could escape [`CHAPTERS`]. Must
+/// run before
+/// [`ChapterConverter`](super::ChapterConverter) so the chapter heading is inside the
+/// file.
+#[derive(Debug, Default)]
+pub struct FilesConverter;
+
+/// Whether `path` is relative and has no `..` components, so joining it to a directory
+/// stays inside that directory.
+fn is_contained(path: &Path) -> bool {
+    path.components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// The Typst path for a chapter `source`, with `/` separators, if the source is
+/// contained in the book's source directory.
+fn typst_path(source: &Path) -> Option<String> {
+    if !is_contained(source) {
+        return None;
+    }
+    let path = Path::new(CHAPTERS)
+        .join(source.with_extension("typ"))
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    Some(path)
+}
+
+impl<'a> Converter<'a> for FilesConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
+            ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::Chapter(
+                _,
+                _,
+                Some(mdbook::ChapterSource::Path(ref source)),
+                _,
+            ))) => {
+                if let Some(path) = typst_path(source) {
+                    let tag = typst::Tag::File(path.into());
+                    output.push_back(ParserEvent::Typst(typst::Event::Start(tag)));
+                }
+                output.push_back(event);
+            }
+            ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::Chapter(
+                _,
+                _,
+                Some(mdbook::ChapterSource::Path(ref source)),
+                _,
+            ))) => {
+                let path = typst_path(source);
+                output.push_back(event);
+                if let Some(path) = path {
+                    let tag = typst::Tag::File(path.into());
+                    output.push_back(ParserEvent::Typst(typst::Event::End(tag)));
+                }
+            }
+            x => output.push_back(x),
+        }
+    }
+}
+
+/// Mark mdBook chapters as Typst files. See [`FilesConverter`].
+pub type ConvertFiles<'a, T> = ConverterIter<'a, T, FilesConverter>;
+
+/// Typst markup split into a main document and one file per
+/// [`File`](typst::Tag::File).
+///
+/// The main document holds everything outside of files, such as the document settings,
+/// with an `#include` line in place of each file in the order the files start. Nested
+/// files are included from the main document too, so content of a file after a nested
+/// file ends is placed before the nested file.
+///
+/// Typst scopes set rules to the file they appear in, so each file starts with the set
+/// rules of the files before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Files {
+    /// The markup of the main document.
+    pub main: String,
+    /// The path and markup of each file, in order.
+    pub files: Vec<(PathBuf, String)>,
+}
+
+impl Files {
+    /// Split Typst events into files and generate markup for each.
+    pub fn from_events<'a, T>(events: T) -> Self
+    where
+        T: Iterator<Item = typst::Event<'a>>,
+    {
+        let mut main = vec![];
+        let mut files: Vec<(PathBuf, Vec<typst::Event<'a>>)> = vec![];
+        let mut open: Vec<usize> = vec![];
+        let mut sets = vec![];
+
+        for event in events {
+            match event {
+                typst::Event::Start(typst::Tag::File(path)) => {
+                    main.push(typst::Event::Include(path.clone()));
+                    open.push(files.len());
+                    files.push((PathBuf::from(path.as_ref()), sets.clone()));
+                }
+                typst::Event::End(typst::Tag::File(_)) => {
+                    open.pop();
+                }
+                event => match open.last() {
+                    Some(&i) => {
+                        if matches!(event, typst::Event::Set(..)) {
+                            sets.push(event.clone());
+                        }
+                        files[i].1.push(event);
+                    }
+                    None => main.push(event),
+                },
+            }
+        }
+
+        Self {
+            main: TypstMarkup::new(main.into_iter()).collect(),
+            files: files
+                .into_iter()
+                .map(|(path, events)| (path, TypstMarkup::new(events.into_iter()).collect()))
+                .collect(),
+        }
+    }
+
+    /// Write [`MAIN`] and every file to `dir`, creating directories as needed.
+    ///
+    /// Fails without writing anything if a file is at the path of [`MAIN`], or its path
+    /// is absolute or has `..` components and so could be outside of `dir`.
+    pub fn write_to(&self, dir: &Path) -> io::Result<()> {
+        for (path, _) in &self.files {
+            if !is_contained(path) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "file {} would be outside of {}",
+                        path.display(),
+                        dir.display()
+                    ),
+                ));
+            }
+            if path.components().eq(Path::new(MAIN).components()) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("a file would overwrite {MAIN}"),
+                ));
+            }
+        }
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(dir.join(MAIN), &self.main)?;
+        for (path, markup) in &self.files {
+            let path = dir.join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, markup)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mdbook::MdbookIter;
+    use crate::mdbook::{ChapterKind, ChapterSource, ChapterStatus, Event, Tag};
+    use similar_asserts::assert_eq;
+
+    fn chapter<'a>(name: &'a str, path: Option<&str>) -> Tag<'a> {
+        Tag::Chapter(
+            ChapterStatus::Active,
+            name.into(),
+            path.map(|p| ChapterSource::Path(PathBuf::from(p))),
+            ChapterKind::Prefix,
+        )
+    }
+
+    #[test]
+    fn marks_chapters() {
+        let input = vec![
+            Event::Start(chapter("Intro", Some("guide/intro.md"))),
+            Event::Start(chapter("Draft", None)),
+            Event::End(chapter("Draft", None)),
+            Event::End(chapter("Intro", Some("guide/intro.md"))),
+        ];
+        let output = ConvertFiles::new(MdbookIter(input.clone().into_iter())).collect::<Vec<_>>();
+        let file = typst::Tag::File("chapters/guide/intro.typ".into());
+        let expected = vec![
+            ParserEvent::Typst(typst::Event::Start(file.clone())),
+            ParserEvent::Mdbook(input[0].clone()),
+            ParserEvent::Mdbook(input[1].clone()),
+            ParserEvent::Mdbook(input[2].clone()),
+            ParserEvent::Mdbook(input[3].clone()),
+            ParserEvent::Typst(typst::Event::End(file)),
+        ];
+        self::assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn main_chapter() {
+        let input = vec![
+            Event::Start(chapter("Main", Some("main.md"))),
+            Event::End(chapter("Main", Some("main.md"))),
+        ];
+        let files = Files::from_events(typst::TypstFilter(ConvertFiles::new(MdbookIter(
+            input.into_iter(),
+        ))));
+        self::assert_eq!(files.main, "#include \"chapters/main.typ\"\n");
+        self::assert_eq!(
+            files.files,
+            vec![("chapters/main.typ".into(), String::new())]
+        );
+
+        let clash = Files {
+            main: String::new(),
+            files: vec![(MAIN.into(), String::new())],
+        };
+        let dir = std::env::temp_dir().join(format!("pullup-files-{}", std::process::id()));
+        let err = clash.write_to(&dir).expect_err("main file is reserved");
+        self::assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn escaping_chapters() {
+        let outside = if cfg!(windows) {
+            "C:\\book\\intro.md"
+        } else {
+            "/book/intro.md"
+        };
+        let input = vec![
+            Event::Start(chapter("Main", Some("../main.md"))),
+            Event::End(chapter("Main", Some("../main.md"))),
+            Event::Start(chapter("Intro", Some(outside))),
+            Event::End(chapter("Intro", Some(outside))),
+        ];
+        let output = ConvertFiles::new(MdbookIter(input.clone().into_iter())).collect::<Vec<_>>();
+        let expected = input
+            .into_iter()
+            .map(ParserEvent::Mdbook)
+            .collect::<Vec<_>>();
+        self::assert_eq!(output, expected);
+
+        let dir = std::env::temp_dir().join(format!("pullup-escape-{}", std::process::id()));
+        for path in ["chapters/../main.typ", "../intro.typ", outside] {
+            let escape = Files {
+                main: String::new(),
+                files: vec![(path.into(), String::new())],
+            };
+            let err = escape.write_to(&dir).expect_err("file is outside of dir");
+            self::assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+            assert!(!dir.exists());
+        }
+    }
+
+    #[test]
+    fn splits_files() {
+        let file = |p: &'static str| typst::Tag::File(p.into());
+        let input = vec![
+            typst::Event::DocumentSet("title".into(), "\"Book\"".into()),
+            typst::Event::Start(file("intro.typ")),
+            typst::Event::Set("heading".into(), "numbering".into(), "\"1.1.\"".into()),
+            typst::Event::Text("Intro".into()),
+            typst::Event::Start(file("intro/nested.typ")),
+            typst::Event::Text("Nested".into()),
+            typst::Event::End(file("intro/nested.typ")),
+            typst::Event::End(file("intro.typ")),
+            typst::Event::Start(file("outro.typ")),
+            typst::Event::Text("Outro".into()),
+            typst::Event::End(file("outro.typ")),
+        ];
+        let files = Files::from_events(input.into_iter());
+        self::assert_eq!(
+            files,
+            Files {
+                main: "#set document(title: \"Book\")\n\
+                       #include \"intro.typ\"\n\
+                       #include \"intro/nested.typ\"\n\
+                       #include \"outro.typ\"\n"
+                    .to_string(),
+                files: vec![
+                    (
+                        "intro.typ".into(),
+                        "#set heading(numbering: \"1.1.\")\nIntro".to_string()
+                    ),
+                    (
+                        "intro/nested.typ".into(),
+                        "#set heading(numbering: \"1.1.\")\nNested".to_string()
+                    ),
+                    (
+                        "outro.typ".into(),
+                        "#set heading(numbering: \"1.1.\")\nOutro".to_string()
+                    ),
+                ],
+            }
+        );
+    }
+}
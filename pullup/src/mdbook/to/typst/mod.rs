@@ -1,59 +1,48 @@
 //! Convert mdBook to Typst.
 
+use crate::convert::{Converter, ConverterIter, Input};
 use crate::converter;
+use crate::headings::{HeadingShiftConverter, Overflow};
 use crate::markdown;
 use crate::markdown::CowStr;
 use crate::mdbook;
 use crate::typst;
 use crate::ParserEvent;
 
-use core::cmp::min;
 use core::num::NonZeroU8;
 use std::collections::VecDeque;
 
 #[cfg(feature = "builder")]
 mod builder;
+mod files;
+mod options;
 
 #[cfg(feature = "builder")]
-pub use builder::Conversion;
+pub use builder::{Conversion, Events, Hooks, Stage};
+pub use files::{ConvertFiles, Files, FilesConverter, CHAPTERS, MAIN};
+pub use options::{ConvertOptions, Options, OptionsConverter, Template};
 
 /// Convert mdBook authors to Typst authors.
-#[derive(Debug)]
-pub struct ConvertAuthors<'a, T> {
+#[derive(Debug, Default)]
+pub struct AuthorsConverter<'a> {
     authors: Vec<CowStr<'a>>,
-    iter: T,
 }
 
-impl<'a, T> ConvertAuthors<'a, T>
-where
-    T: Iterator<Item = ParserEvent<'a>>,
-{
-    #[allow(dead_code)]
-    fn new(iter: T) -> Self {
-        Self {
-            authors: vec![],
-            iter,
-        }
-    }
-}
-
-impl<'a, T> Iterator for ConvertAuthors<'a, T>
-where
-    T: Iterator<Item = ParserEvent<'a>>,
-{
-    type Item = ParserEvent<'a>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.iter.next() {
-            Some(ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::AuthorList))) => {
+impl<'a> Converter<'a> for AuthorsConverter<'a> {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
+            ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::AuthorList)) => {
                 self.authors = vec![];
-                self.next()
             }
-            Some(ParserEvent::Mdbook(mdbook::Event::Author(a))) => {
+            ParserEvent::Mdbook(mdbook::Event::Author(a)) => {
                 self.authors.push(a);
-                self.next()
             }
-            Some(ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::AuthorList))) => {
+            ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::AuthorList)) => {
                 if !self.authors.is_empty() {
                     let markup_array = format!(
                         "({})",
@@ -64,18 +53,321 @@ where
                             .collect::<Vec<_>>()
                             .join(",")
                     );
-                    return Some(ParserEvent::Typst(typst::Event::DocumentSet(
+                    output.push_back(ParserEvent::Typst(typst::Event::DocumentSet(
                         "author".into(),
                         markup_array.into(),
                     )));
                 }
-                self.next()
             }
-            x => x,
+            x => output.push_back(x),
+        }
+    }
+}
+
+/// Convert mdBook authors to Typst authors. See [`AuthorsConverter`].
+pub type ConvertAuthors<'a, T> = ConverterIter<'a, T, AuthorsConverter<'a>>;
+
+/// Quote `s` as a Typst string literal.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Format `values` as a Typst array.
+fn array(values: &[String]) -> String {
+    match values {
+        [value] => format!("({value},)"),
+        _ => format!("({})", values.join(", ")),
+    }
+}
+
+/// Format a date like `2024-05-01` as a Typst datetime. Only the date is used from
+/// datetimes like `2024-05-01T10:00:00Z`.
+fn datetime(date: &str) -> Option<String> {
+    let parts = date
+        .splitn(3, '-')
+        .map(|p| {
+            p.chars()
+                .take_while(char::is_ascii_digit)
+                .collect::<String>()
+                .parse::<u32>()
+        })
+        .collect::<Vec<_>>();
+    match parts.as_slice() {
+        [Ok(year), Ok(month), Ok(day)] => Some(format!(
+            "datetime(year: {year}, month: {month}, day: {day})"
+        )),
+        _ => None,
+    }
+}
+
+/// Split a language tag like `pt-BR` into the Typst `lang` and `region`, as Typst takes
+/// them separately.
+fn language(tag: &str) -> (String, Option<String>) {
+    let mut parts = tag.splitn(2, ['-', '_']);
+    let lang = parts.next().unwrap_or_default().to_lowercase();
+    (lang, parts.next().map(str::to_uppercase))
+}
+
+/// Convert mdBook book metadata to Typst document and text settings.
+///
+/// The description, keywords and date become `document` settings. The language and
+/// text direction become `text` settings so Typst hyphenates and lays out text for
+/// the book's language. Multilingual markers have no Typst equivalent and are dropped.
+#[derive(Debug, Default)]
+pub struct MetadataConverter;
+
+impl<'a> Converter<'a> for MetadataConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        let text_set = |k: &'static str, v: String| {
+            ParserEvent::Typst(typst::Event::Set("text".into(), k.into(), v.into()))
+        };
+        match event {
+            ParserEvent::Mdbook(mdbook::Event::Description(d)) => {
+                output.push_back(ParserEvent::Typst(typst::Event::DocumentSet(
+                    "description".into(),
+                    quote(&d).into(),
+                )));
+            }
+            ParserEvent::Mdbook(mdbook::Event::Keywords(keywords)) => {
+                let keywords = keywords.iter().map(|k| quote(k)).collect::<Vec<_>>();
+                output.push_back(ParserEvent::Typst(typst::Event::DocumentSet(
+                    "keywords".into(),
+                    array(&keywords).into(),
+                )));
+            }
+            ParserEvent::Mdbook(mdbook::Event::Date(date)) => {
+                if let Some(date) = datetime(&date) {
+                    output.push_back(ParserEvent::Typst(typst::Event::DocumentSet(
+                        "date".into(),
+                        date.into(),
+                    )));
+                } else {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("ignoring unparseable date: {}", date);
+                }
+            }
+            ParserEvent::Mdbook(mdbook::Event::Language(language)) => {
+                let (lang, region) = self::language(&language);
+                output.push_back(text_set("lang", quote(&lang)));
+                if let Some(region) = region {
+                    output.push_back(text_set("region", quote(&region)));
+                }
+            }
+            ParserEvent::Mdbook(mdbook::Event::TextDirection(dir)) => {
+                let dir = match dir {
+                    mdbook::TextDirection::LeftToRight => "ltr",
+                    mdbook::TextDirection::RightToLeft => "rtl",
+                };
+                output.push_back(text_set("dir", dir.to_string()));
+            }
+            ParserEvent::Mdbook(mdbook::Event::Multilingual) => {}
+            x => output.push_back(x),
+        }
+    }
+}
+
+/// Convert mdBook book metadata to Typst. See [`MetadataConverter`].
+pub type ConvertMetadata<'a, T> = ConverterIter<'a, T, MetadataConverter>;
+
+/// The default title page layout, used unless [`TitlePageConverter::with_layout`] is
+/// given a function.
+const TITLE_PAGE: (&str, &str) = (
+    "title-page(title: none, subtitle: none, authors: (), date: none)",
+    r#"page(header: none, footer: none, numbering: none, align(center + horizon, {
+  if title != none { text(2.5em, weight: "bold", title) }
+  if subtitle != none { parbreak(); text(1.4em, subtitle) }
+  if authors.len() > 0 { v(2em); text(1.2em, authors.join(", ")) }
+  if date != none { v(1em); date.display() }
+}))"#,
+);
+
+/// Insert a title page after the book configuration.
+///
+/// The title, description, authors and date in the configuration are passed as the
+/// `title`, `subtitle`, `authors` and `date` arguments of the layout function.
+/// Configuration events are passed through unchanged, so this must run before the
+/// converters consuming them, such as [`ConvertTitle`].
+#[derive(Debug, Default)]
+pub struct TitlePageConverter<'a> {
+    layout: Option<CowStr<'a>>,
+    title: Option<CowStr<'a>>,
+    description: Option<CowStr<'a>>,
+    authors: Vec<CowStr<'a>>,
+    date: Option<CowStr<'a>>,
+}
+
+impl<'a> TitlePageConverter<'a> {
+    /// Lay out the title page with the Typst function `layout` instead of the default
+    /// layout. The function must be defined or imported, e.g. by a template.
+    pub fn with_layout(layout: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            layout: Some(layout.into()),
+            ..Self::default()
+        }
+    }
+
+    fn title_page(&mut self, output: &mut VecDeque<ParserEvent<'a>>) {
+        let mut args: Vec<CowStr<'a>> = vec![];
+        if let Some(title) = self.title.take() {
+            args.push(format!("title: {}", quote(&title)).into());
+        }
+        if let Some(description) = self.description.take() {
+            args.push(format!("subtitle: {}", quote(&description)).into());
+        }
+        if !self.authors.is_empty() {
+            let authors = self
+                .authors
+                .drain(..)
+                .map(|a| quote(&a))
+                .collect::<Vec<_>>();
+            args.push(format!("authors: {}", array(&authors)).into());
+        }
+        if let Some(date) = self.date.take().as_deref().and_then(datetime) {
+            args.push(format!("date: {date}").into());
+        }
+        let layout = match &self.layout {
+            Some(layout) => layout.clone(),
+            None => {
+                output.push_back(ParserEvent::Typst(typst::Event::Let(
+                    TITLE_PAGE.0.into(),
+                    TITLE_PAGE.1.into(),
+                )));
+                "title-page".into()
+            }
+        };
+        output.push_back(ParserEvent::Typst(typst::Event::FunctionCall(
+            None, layout, args,
+        )));
+    }
+}
+
+impl<'a> Converter<'a> for TitlePageConverter<'a> {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match &event {
+            ParserEvent::Mdbook(mdbook::Event::Title(t)) => self.title = Some(t.clone()),
+            ParserEvent::Mdbook(mdbook::Event::Description(d)) => {
+                self.description = Some(d.clone())
+            }
+            ParserEvent::Mdbook(mdbook::Event::Author(a)) => self.authors.push(a.clone()),
+            ParserEvent::Mdbook(mdbook::Event::Date(d)) => self.date = Some(d.clone()),
+            ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::BookConfiguration)) => {
+                output.push_back(event);
+                self.title_page(output);
+                return;
+            }
+            _ => {}
+        }
+        output.push_back(event);
+    }
+}
+
+/// Insert a title page. See [`TitlePageConverter`].
+pub type ConvertTitlePage<'a, T> = ConverterIter<'a, T, TitlePageConverter<'a>>;
+
+/// Wrap the book in a user-supplied Typst [`Template`].
+///
+/// Emits `#import "source": *` and `#show: name.with(...)` after the book
+/// configuration. The book's title, authors, description, date, keywords and language
+/// are passed to the template as the `title`, `authors`, `description`, `date`,
+/// `keywords`, `lang` and `region` arguments, when set, instead of being converted to
+/// `document` and `text` settings. Templates can take arguments they do not use with
+/// `..rest`.
+///
+/// The metadata events are consumed, so this must run before the converters that would
+/// otherwise convert them, such as [`ConvertTitle`].
+#[derive(Debug, Default)]
+pub struct TemplateConverter<'a> {
+    template: Template,
+    args: Vec<CowStr<'a>>,
+    authors: Vec<CowStr<'a>>,
+}
+
+impl TemplateConverter<'_> {
+    pub fn new(template: Template) -> Self {
+        Self {
+            template,
+            args: vec![],
+            authors: vec![],
         }
     }
 }
 
+impl<'a> Converter<'a> for TemplateConverter<'a> {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
+            ParserEvent::Mdbook(mdbook::Event::Title(t)) => {
+                self.args.push(format!("title: {}", quote(&t)).into());
+            }
+            ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::AuthorList))
+            | ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::AuthorList)) => {}
+            ParserEvent::Mdbook(mdbook::Event::Author(a)) => self.authors.push(a),
+            ParserEvent::Mdbook(mdbook::Event::Description(d)) => {
+                self.args.push(format!("description: {}", quote(&d)).into());
+            }
+            ParserEvent::Mdbook(mdbook::Event::Date(d)) => {
+                if let Some(date) = datetime(&d) {
+                    self.args.push(format!("date: {date}").into());
+                }
+            }
+            ParserEvent::Mdbook(mdbook::Event::Keywords(keywords)) => {
+                let keywords = keywords.iter().map(|k| quote(k)).collect::<Vec<_>>();
+                self.args
+                    .push(format!("keywords: {}", array(&keywords)).into());
+            }
+            ParserEvent::Mdbook(mdbook::Event::Language(l)) => {
+                let (lang, region) = language(&l);
+                self.args.push(format!("lang: {}", quote(&lang)).into());
+                if let Some(region) = region {
+                    self.args.push(format!("region: {}", quote(&region)).into());
+                }
+            }
+            ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::BookConfiguration)) => {
+                output.push_back(event);
+                if !self.authors.is_empty() {
+                    let authors = self
+                        .authors
+                        .drain(..)
+                        .map(|a| quote(&a))
+                        .collect::<Vec<_>>();
+                    self.args
+                        .push(format!("authors: {}", array(&authors)).into());
+                }
+                output.push_back(ParserEvent::Typst(typst::Event::Import(
+                    self.template.source.clone().into(),
+                    vec!["*".into()],
+                )));
+                let show = typst::Tag::Show(
+                    typst::ShowType::Function,
+                    "".into(),
+                    None,
+                    Some(format!("{}.with({})", self.template.name, self.args.join(", ")).into()),
+                );
+                output.push_back(ParserEvent::Typst(typst::Event::Start(show.clone())));
+                output.push_back(ParserEvent::Typst(typst::Event::End(show)));
+            }
+            x => output.push_back(x),
+        }
+    }
+}
+
+/// Wrap the book in a Typst template. See [`TemplateConverter`].
+pub type ConvertTemplate<'a, T> = ConverterIter<'a, T, TemplateConverter<'a>>;
+
 // TODO: tests
 converter!(
     /// Convert mdBook title to Typst set document title event.
@@ -93,167 +385,541 @@ converter!(
     }
 });
 
-#[derive(Debug)]
-pub struct ConvertChapter<'a, T> {
-    chapters: VecDeque<()>,
-    buf: VecDeque<ParserEvent<'a>>,
-    iter: T,
-}
-
-impl<'a, T> ConvertChapter<'a, T>
-where
-    T: Iterator<Item = ParserEvent<'a>>,
-{
-    #[allow(dead_code)]
-    fn new(iter: T) -> Self {
-        Self {
-            chapters: VecDeque::new(),
-            buf: VecDeque::new(),
-            iter,
+/// How [`PartConverter`] renders titled parts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PartStyle {
+    /// Render the part title as a top-level heading and shift the headings of its
+    /// chapters down one level.
+    ///
+    /// Titled parts are left for [`ConvertChapter`] to render, which does not end them
+    /// with a pagebreak of their own as their last chapter already does.
+    #[default]
+    Heading,
+    /// Render the part title centered on a page of its own. Chapter headings are not
+    /// shifted.
+    Page,
+}
+
+/// Convert mdBook parts to Typst. Untitled parts are dropped.
+#[derive(Debug, Default)]
+pub struct PartConverter {
+    style: PartStyle,
+}
+
+impl PartConverter {
+    pub fn new(style: PartStyle) -> Self {
+        Self { style }
+    }
+}
+
+impl<'a> Converter<'a> for PartConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match (self.style, event) {
+            (_, ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::Part(None, _))))
+            | (_, ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::Part(None, _)))) => {}
+            (
+                PartStyle::Page,
+                ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::Part(Some(title), _))),
+            ) => {
+                let pagebreak = ParserEvent::Typst(typst::Event::FunctionCall(
+                    None,
+                    "pagebreak".into(),
+                    vec!["weak: true".into()],
+                ));
+                output.push_back(pagebreak.clone());
+                output.push_back(ParserEvent::Typst(typst::Event::FunctionCall(
+                    None,
+                    "align".into(),
+                    vec![
+                        "center + horizon".into(),
+                        format!("heading(level: 1, numbering: none, {})", quote(&title)).into(),
+                    ],
+                )));
+                output.push_back(pagebreak);
+            }
+            (PartStyle::Page, ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::Part(_, _)))) => {
+            }
+            (_, x) => output.push_back(x),
         }
     }
 }
 
-impl<'a, T> Iterator for ConvertChapter<'a, T>
-where
-    T: Iterator<Item = ParserEvent<'a>>,
-{
-    type Item = ParserEvent<'a>;
+/// Convert mdBook parts to Typst. See [`PartConverter`].
+pub type ConvertParts<'a, T> = ConverterIter<'a, T, PartConverter>;
+
+/// What to do with draft chapters, i.e. chapters without a source file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum DraftPolicy<'a> {
+    /// Keep draft chapters as empty chapters.
+    #[default]
+    Keep,
+    /// Leave draft chapters out. Their sub-chapters are kept.
+    Drop,
+    /// Keep draft chapters, with the given text as their content.
+    Placeholder(CowStr<'a>),
+    /// Leave draft chapters out and list their names in a suffix chapter with the given
+    /// title at the end of the book.
+    Appendix(CowStr<'a>),
+}
+
+/// Apply a [`DraftPolicy`] to mdBook draft chapters. This must run before
+/// [`ConvertChapter`].
+#[derive(Debug, Default)]
+pub struct DraftConverter<'a> {
+    policy: DraftPolicy<'a>,
+    // Whether each open chapter was dropped.
+    open: Vec<bool>,
+    drafts: Vec<CowStr<'a>>,
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        // If we have a previously buffered event, return it.
-        if let Some(buffered) = self.buf.pop_front() {
-            #[cfg(feature = "tracing")]
-            tracing::trace!("returning buffered: {:?}", buffered);
+impl<'a> DraftConverter<'a> {
+    pub fn new(policy: DraftPolicy<'a>) -> Self {
+        Self {
+            policy,
+            open: vec![],
+            drafts: vec![],
+        }
+    }
 
-            return Some(buffered);
+    /// Emit the appendix listing the dropped drafts, if any.
+    fn appendix(&mut self, output: &mut VecDeque<ParserEvent<'a>>) {
+        let DraftPolicy::Appendix(title) = &self.policy else {
+            return;
+        };
+        if self.drafts.is_empty() {
+            return;
         }
-        // Otherwise pull from the inner iterator.
-        match self.iter.next() {
-            // Start of chapter.
-            Some(ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::Chapter(
-                _,
+        let tag = mdbook::Tag::Chapter(
+            mdbook::ChapterStatus::Active,
+            title.clone(),
+            None,
+            mdbook::ChapterKind::Suffix,
+        );
+        let content = |e| ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(e));
+        output.push_back(ParserEvent::Mdbook(mdbook::Event::Start(tag.clone())));
+        output.push_back(content(markdown::Event::Start(markdown::Tag::List(None))));
+        for name in self.drafts.drain(..) {
+            output.push_back(content(markdown::Event::Start(markdown::Tag::Item)));
+            output.push_back(content(markdown::Event::Text(name)));
+            output.push_back(content(markdown::Event::End(markdown::Tag::Item)));
+        }
+        output.push_back(content(markdown::Event::End(markdown::Tag::List(None))));
+        output.push_back(ParserEvent::Mdbook(mdbook::Event::End(tag)));
+    }
+}
+
+impl<'a> Converter<'a> for DraftConverter<'a> {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
+            ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::Chapter(
+                status,
                 name,
-                _,
-                _,
-            )))) => {
-                #[cfg(feature = "tracing")]
-                tracing::trace!("chapter start: {}", name);
+                source,
+                kind,
+            ))) => {
+                let draft = status == mdbook::ChapterStatus::Draft;
+                match &self.policy {
+                    DraftPolicy::Placeholder(text) if draft => {
+                        let text = text.clone();
+                        let content =
+                            |e| ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(e));
+                        output.push_back(ParserEvent::Mdbook(mdbook::Event::Start(
+                            mdbook::Tag::Chapter(status, name, source, kind),
+                        )));
+                        output.push_back(content(markdown::Event::Start(markdown::Tag::Paragraph)));
+                        output.push_back(content(markdown::Event::Text(text)));
+                        output.push_back(content(markdown::Event::End(markdown::Tag::Paragraph)));
+                        self.open.push(false);
+                    }
+                    DraftPolicy::Drop | DraftPolicy::Appendix(_) if draft => {
+                        #[cfg(feature = "tracing")]
+                        tracing::trace!("dropping draft chapter: {}", name);
 
-                // Get how many chapters deep we are.
-                let depth = self.chapters.len();
+                        if matches!(self.policy, DraftPolicy::Appendix(_)) {
+                            self.drafts.push(name);
+                        }
+                        self.open.push(true);
+                    }
+                    _ => {
+                        output.push_back(ParserEvent::Mdbook(mdbook::Event::Start(
+                            mdbook::Tag::Chapter(status, name, source, kind),
+                        )));
+                        self.open.push(false);
+                    }
+                }
+            }
+            ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::Chapter(..))) => {
+                if !self.open.pop().unwrap_or(false) {
+                    output.push_back(event);
+                }
+            }
+            ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::BookContent)) => {
+                self.appendix(output);
+                output.push_back(event);
+            }
+            x => output.push_back(x),
+        }
+    }
 
-                // Create a Typst heading start event for the chapter.
-                let tag = typst::Tag::Heading(
-                    NonZeroU8::new((1 + depth).try_into().expect("nonzero")).expect("nonzero"),
-                    typst::TableOfContents::Include,
-                    typst::Bookmarks::Include,
-                );
+    fn flush(&mut self, output: &mut VecDeque<ParserEvent<'a>>) {
+        self.appendix(output);
+    }
+}
+
+/// Apply a [`DraftPolicy`] to mdBook draft chapters. See [`DraftConverter`].
+pub type ConvertDrafts<'a, T> = ConverterIter<'a, T, DraftConverter<'a>>;
+
+/// What to do with mdBook separators.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SeparatorPolicy {
+    /// Leave separators out.
+    #[default]
+    Ignore,
+    /// Render separators as a full-width line.
+    Divider,
+}
+
+/// Apply a [`SeparatorPolicy`] to mdBook separators.
+#[derive(Debug, Default)]
+pub struct SeparatorConverter {
+    policy: SeparatorPolicy,
+}
+
+impl SeparatorConverter {
+    pub fn new(policy: SeparatorPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl<'a> Converter<'a> for SeparatorConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match (self.policy, event) {
+            (SeparatorPolicy::Ignore, ParserEvent::Mdbook(mdbook::Event::Separator)) => {}
+            (SeparatorPolicy::Divider, ParserEvent::Mdbook(mdbook::Event::Separator)) => {
+                output.push_back(ParserEvent::Typst(typst::Event::Line(
+                    None,
+                    None,
+                    Some("100%".into()),
+                    None,
+                    None,
+                )));
+            }
+            (_, x) => output.push_back(x),
+        }
+    }
+}
+
+/// Apply a [`SeparatorPolicy`] to mdBook separators. See [`SeparatorConverter`].
+pub type ConvertSeparators<'a, T> = ConverterIter<'a, T, SeparatorConverter>;
+
+/// Where [`OutlineConverter`] inserts the outline.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutlinePosition {
+    /// At the start of the book content, after any title page.
+    #[default]
+    Start,
+    /// In place of each `<!-- toc -->` HTML comment in the content.
+    Marker,
+}
+
+/// Insert a Typst outline, i.e. a table of contents.
+///
+/// See <https://typst.app/docs/reference/model/outline/>.
+#[derive(Debug, Default)]
+pub struct OutlineConverter<'a> {
+    position: OutlinePosition,
+    title: Option<CowStr<'a>>,
+    depth: Option<u8>,
+    indent: Option<CowStr<'a>>,
+    inserted: bool,
+}
+
+impl<'a> OutlineConverter<'a> {
+    pub fn new(position: OutlinePosition) -> Self {
+        Self {
+            position,
+            ..Self::default()
+        }
+    }
 
-                let start_event = ParserEvent::Typst(typst::Event::Start(tag.clone()));
-                let end_event = ParserEvent::Typst(typst::Event::End(tag));
+    /// Set the outline title. Typst uses a localized "Contents" by default.
+    pub fn title(mut self, title: impl Into<CowStr<'a>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
 
-                // Queue up the chapter name text event and heading end event.
-                self.buf
-                    .push_back(ParserEvent::Typst(typst::Event::Text(name.clone())));
-                self.buf.push_back(end_event);
+    /// Only include headings up to `depth`.
+    pub fn depth(mut self, depth: u8) -> Self {
+        self.depth = Some(depth);
+        self
+    }
 
-                // Record that we are one chapter deeper.
-                self.chapters.push_back(());
+    /// Set the indent of nested entries, as Typst code such as `auto` or `1em`.
+    pub fn indent(mut self, indent: impl Into<CowStr<'a>>) -> Self {
+        self.indent = Some(indent.into());
+        self
+    }
 
+    fn outline(&self) -> ParserEvent<'a> {
+        let mut args = vec![];
+        if let Some(title) = &self.title {
+            args.push(format!("title: {}", quote(title)).into());
+        }
+        if let Some(depth) = self.depth {
+            args.push(format!("depth: {depth}").into());
+        }
+        if let Some(indent) = &self.indent {
+            args.push(format!("indent: {indent}").into());
+        }
+        ParserEvent::Typst(typst::Event::FunctionCall(None, "outline".into(), args))
+    }
+}
+
+/// Whether `html` is an outline marker.
+fn is_marker(html: &str) -> bool {
+    html.trim() == "<!-- toc -->"
+}
+
+impl<'a> Converter<'a> for OutlineConverter<'a> {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match (self.position, event) {
+            (
+                OutlinePosition::Start,
+                e @ ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::BookContent)),
+            ) if !self.inserted => {
+                output.push_back(e);
+                output.push_back(self.outline());
+                self.inserted = true;
+            }
+            (
+                OutlinePosition::Marker,
+                ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(markdown::Event::Html(
+                    html,
+                ))),
+            )
+            | (OutlinePosition::Marker, ParserEvent::Markdown(markdown::Event::Html(html)))
+                if is_marker(&html) =>
+            {
+                output.push_back(self.outline());
+            }
+            (_, x) => output.push_back(x),
+        }
+    }
+}
+
+/// Insert a Typst outline. See [`OutlineConverter`].
+pub type ConvertOutline<'a, T> = ConverterIter<'a, T, OutlineConverter<'a>>;
+
+/// Convert mdBook chapters to Typst headings followed by a pagebreak. Markdown headings
+/// inside a chapter are shifted down by the chapter depth with a
+/// [`HeadingShiftConverter`]. Headings shifted past level 6 become deeper Typst
+/// headings unless another [`Overflow`] is set.
+///
+/// Numbered chapters turn on heading numbering and set the heading counter so Typst
+/// uses mdBook's section number, even when the chapter heading is nested deeper than
+/// the number, e.g. inside a titled part. Prefix and suffix chapters and parts turn it
+/// off.
+/// Headings inside chapters are never numbered, like in mdBook.
+///
+/// Titled parts, as left by [`PartConverter`] with [`PartStyle::Heading`], get an
+/// unnumbered heading like a chapter and shift the chapters inside them down one level.
+#[derive(Debug)]
+pub struct ChapterConverter {
+    depth: usize,
+    // The heading levels left out of the number, or `None` if numbering is off. `None`
+    // until numbering has been set.
+    numbering: Option<Option<usize>>,
+    pagebreaks: bool,
+    headings: HeadingShiftConverter,
+}
+
+impl Default for ChapterConverter {
+    fn default() -> Self {
+        Self::with_pagebreaks(true)
+    }
+}
+
+impl ChapterConverter {
+    /// Convert chapters, ending each with a pagebreak only if `pagebreaks` is set.
+    pub fn with_pagebreaks(pagebreaks: bool) -> Self {
+        Self {
+            depth: 0,
+            numbering: None,
+            pagebreaks,
+            headings: HeadingShiftConverter::new(0).overflow(Overflow::Typst),
+        }
+    }
+
+    /// Handle headings shifted past level 6 with `overflow`.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.headings = self.headings.overflow(overflow);
+        self
+    }
+
+    /// Emit the heading for a chapter of `kind` and record that we are one chapter
+    /// deeper.
+    fn enter<'a>(
+        &mut self,
+        name: CowStr<'a>,
+        kind: &mdbook::ChapterKind,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        self.number(kind, output);
+
+        // Create a Typst heading for the chapter.
+        let tag = typst::Tag::Heading(
+            NonZeroU8::new((1 + self.depth).try_into().expect("nonzero")).expect("nonzero"),
+            typst::TableOfContents::Include,
+            typst::Bookmarks::Include,
+            typst::Numbering::Include,
+        );
+        output.push_back(ParserEvent::Typst(typst::Event::Start(tag.clone())));
+        output.push_back(ParserEvent::Typst(typst::Event::Text(name)));
+        output.push_back(ParserEvent::Typst(typst::Event::End(tag)));
+
+        self.depth += 1;
+        self.headings.set_offset(self.depth as isize);
+    }
+
+    /// Record that we are one chapter shallower.
+    fn leave(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        self.headings.set_offset(self.depth as isize);
+    }
+
+    /// Emit the heading numbering and counter for a chapter of `kind`.
+    fn number<'a>(&mut self, kind: &mdbook::ChapterKind, output: &mut VecDeque<ParserEvent<'a>>) {
+        let offset = match kind {
+            mdbook::ChapterKind::Numbered(number) => {
+                Some((self.depth + 1).saturating_sub(number.len()))
+            }
+            _ => None,
+        };
+        if self.numbering != Some(offset) {
+            let numbering = match offset {
+                None => "none".to_string(),
+                Some(0) => "\"1.1.\"".to_string(),
+                Some(o) => format!("(..n) => numbering(\"1.1.\", ..n.pos().slice({o}))"),
+            };
+            output.push_back(ParserEvent::Typst(typst::Event::Set(
+                "heading".into(),
+                "numbering".into(),
+                numbering.into(),
+            )));
+            self.numbering = Some(offset);
+        }
+        if let (mdbook::ChapterKind::Numbered(number), Some(offset)) = (kind, offset) {
+            // The heading steps the counter, so set it to just before the number.
+            if let Some((last, parents)) = number.split_last() {
+                let mut values = vec!["0".to_string(); offset];
+                values.extend(parents.iter().map(u32::to_string));
+                values.push(last.saturating_sub(1).to_string());
+                output.push_back(ParserEvent::Typst(typst::Event::FunctionCall(
+                    Some("counter(heading)".into()),
+                    "update".into(),
+                    vec![array(&values).into()],
+                )));
+            }
+        }
+    }
+}
+
+impl<'a> Converter<'a> for ChapterConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        match event {
+            // Start of chapter.
+            ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::Chapter(_, name, _, kind))) => {
                 #[cfg(feature = "tracing")]
-                tracing::trace!("returning: {:?}", start_event);
+                tracing::trace!("chapter start: {}", name);
 
-                // Return the heading start event.
-                Some(start_event)
+                self.enter(name, &kind, output);
+            }
+            // Start of a titled part, left by `PartConverter` with `PartStyle::Heading`.
+            ParserEvent::Mdbook(mdbook::Event::Start(mdbook::Tag::Part(Some(title), _))) => {
+                self.enter(title, &mdbook::ChapterKind::Prefix, output);
+            }
+            // End of a titled part. Its last chapter already ended with a pagebreak.
+            ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::Part(Some(_), _))) => {
+                self.leave();
             }
             // End of a chapter.
-            Some(ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::Chapter(_, _name, _, _)))) => {
+            ParserEvent::Mdbook(mdbook::Event::End(mdbook::Tag::Chapter(_, _name, _, _))) => {
                 #[cfg(feature = "tracing")]
                 tracing::trace!("chapter end: {}", _name);
 
-                // Record that we are one chapter shallower.
-                let _ = self.chapters.pop_front();
+                self.leave();
                 // Chapters are converted to page break.
-                Some(ParserEvent::Typst(typst::Event::FunctionCall(
-                    None,
-                    "pagebreak".into(),
-                    vec!["weak: true".into()],
-                )))
+                if self.pagebreaks {
+                    output.push_back(ParserEvent::Typst(typst::Event::FunctionCall(
+                        None,
+                        "pagebreak".into(),
+                        vec!["weak: true".into()],
+                    )));
+                }
             }
-            // Heading start in a chapter.
-            Some(ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(
-                markdown::Event::Start(markdown::Tag::Heading(level, x, y)),
+            // Heading in a chapter.
+            ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(markdown::Event::Start(
+                tag @ markdown::Tag::Heading(..),
             ))) => {
-                #[cfg(feature = "tracing")]
-                tracing::trace!("heading start: {}", level);
-
-                use markdown::HeadingLevel;
-                // Get how many chapters deep we are.
-                let depth = self.chapters.len();
-                let new_level = match level {
-                    HeadingLevel::H1 => {
-                        HeadingLevel::try_from(min(1 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H2 => {
-                        HeadingLevel::try_from(min(2 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H3 => {
-                        HeadingLevel::try_from(min(3 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H4 => {
-                        HeadingLevel::try_from(min(4 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H5 => {
-                        HeadingLevel::try_from(min(5 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H6 => HeadingLevel::H6,
-                };
-                self.buf
-                    .push_back(ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(
-                        markdown::Event::Start(markdown::Tag::Heading(new_level, x, y)),
-                    )));
-                self.next()
+                let x = markdown::Event::Start(unnumbered(tag));
+                let x = ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(x));
+                self.headings.convert(x, input, output)
             }
-            // Heading end in a chapter.
-            Some(ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(
-                markdown::Event::End(markdown::Tag::Heading(level, x, y)),
+            ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(markdown::Event::End(
+                tag @ markdown::Tag::Heading(..),
             ))) => {
-                #[cfg(feature = "tracing")]
-                tracing::trace!("heading end: {}", level);
-                use markdown::HeadingLevel;
-                // Get how many chapters deep we are.
-                let depth = self.chapters.len();
-                let new_level = match level {
-                    HeadingLevel::H1 => {
-                        HeadingLevel::try_from(min(1 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H2 => {
-                        HeadingLevel::try_from(min(2 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H3 => {
-                        HeadingLevel::try_from(min(3 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H4 => {
-                        HeadingLevel::try_from(min(4 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H5 => {
-                        HeadingLevel::try_from(min(5 + depth, 6)).expect("valid heading level")
-                    }
-                    HeadingLevel::H6 => HeadingLevel::H6,
-                };
-                self.buf
-                    .push_back(ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(
-                        markdown::Event::End(markdown::Tag::Heading(new_level, x, y)),
-                    )));
-                self.next()
+                let x = markdown::Event::End(unnumbered(tag));
+                let x = ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(x));
+                self.headings.convert(x, input, output)
             }
+            x => output.push_back(x),
+        }
+    }
+}
 
-            x => x,
+/// Mark a content heading with the default unnumbered class of [`HeadingConverter`].
+/// mdBook only numbers chapters, so this keeps content headings out of the chapter
+/// numbering and the heading counter.
+fn unnumbered(tag: markdown::Tag<'_>) -> markdown::Tag<'_> {
+    match tag {
+        markdown::Tag::Heading(level, id, mut classes) => {
+            if !classes.contains(&"unnumbered") {
+                classes.push("unnumbered");
+            }
+            markdown::Tag::Heading(level, id, classes)
         }
+        tag => tag,
     }
 }
 
+/// Convert mdBook chapters to Typst headings. See [`ChapterConverter`].
+pub type ConvertChapter<'a, T> = ConverterIter<'a, T, ChapterConverter>;
+
 // TODO: tests
 converter!(
     /// Convert mdBook chapters to Typst pagebreaks. This does not affect any content in
@@ -273,3 +939,361 @@ converter!(
             x => x,
     }
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mdbook::MdbookIter;
+    use similar_asserts::assert_eq;
+
+    use markdown::HeadingLevel;
+    use mdbook::Event as MdbookEvent;
+    use mdbook::Tag as MdbookTag;
+    use typst::Event as TypstEvent;
+    use typst::Tag as TypstTag;
+    use ParserEvent::*;
+
+    fn chapter<'a>(name: &'a str) -> MdbookTag<'a> {
+        MdbookTag::Chapter(
+            mdbook::ChapterStatus::Active,
+            name.into(),
+            None,
+            mdbook::ChapterKind::Prefix,
+        )
+    }
+
+    fn heading<'a>(level: u8) -> TypstTag<'a> {
+        TypstTag::Heading(
+            NonZeroU8::new(level).expect("nonzero"),
+            typst::TableOfContents::Include,
+            typst::Bookmarks::Include,
+            typst::Numbering::Include,
+        )
+    }
+
+    fn unnumbered<'a>(level: u8) -> TypstTag<'a> {
+        TypstTag::Heading(
+            NonZeroU8::new(level).expect("nonzero"),
+            typst::TableOfContents::Include,
+            typst::Bookmarks::Include,
+            typst::Numbering::Exclude,
+        )
+    }
+
+    #[test]
+    fn nested_chapters() {
+        let md_heading = markdown::Tag::Heading(HeadingLevel::H1, None, vec![]);
+        let events = vec![
+            MdbookEvent::Start(chapter("One")),
+            MdbookEvent::Start(chapter("Two")),
+            MdbookEvent::MarkdownContentEvent(markdown::Event::Start(md_heading.clone())),
+            MdbookEvent::MarkdownContentEvent(markdown::Event::End(md_heading)),
+            MdbookEvent::End(chapter("Two")),
+            MdbookEvent::End(chapter("One")),
+        ];
+        let shifted = markdown::Tag::Heading(HeadingLevel::H3, None, vec!["unnumbered"]);
+        let pagebreak = Typst(TypstEvent::FunctionCall(
+            None,
+            "pagebreak".into(),
+            vec!["weak: true".into()],
+        ));
+
+        self::assert_eq!(
+            ConvertChapter::new(MdbookIter(events.into_iter())).collect::<Vec<_>>(),
+            vec![
+                Typst(TypstEvent::Set(
+                    "heading".into(),
+                    "numbering".into(),
+                    "none".into()
+                )),
+                Typst(TypstEvent::Start(heading(1))),
+                Typst(TypstEvent::Text("One".into())),
+                Typst(TypstEvent::End(heading(1))),
+                Typst(TypstEvent::Start(heading(2))),
+                Typst(TypstEvent::Text("Two".into())),
+                Typst(TypstEvent::End(heading(2))),
+                Mdbook(MdbookEvent::MarkdownContentEvent(markdown::Event::Start(
+                    shifted.clone()
+                ))),
+                Mdbook(MdbookEvent::MarkdownContentEvent(markdown::Event::End(
+                    shifted
+                ))),
+                pagebreak.clone(),
+                pagebreak,
+            ]
+        );
+    }
+
+    #[test]
+    fn deep_headings() {
+        let h6 = markdown::Tag::Heading(HeadingLevel::H6, None, vec![]);
+        let events = || {
+            MdbookIter(
+                vec![
+                    MdbookEvent::Start(chapter("One")),
+                    MdbookEvent::MarkdownContentEvent(markdown::Event::Start(h6.clone())),
+                    MdbookEvent::MarkdownContentEvent(markdown::Event::End(h6.clone())),
+                    MdbookEvent::End(chapter("One")),
+                ]
+                .into_iter(),
+            )
+        };
+
+        self::assert_eq!(
+            ConvertChapter::new(events())
+                .skip(4)
+                .take(2)
+                .collect::<Vec<_>>(),
+            vec![
+                Typst(TypstEvent::Start(unnumbered(7))),
+                Typst(TypstEvent::End(unnumbered(7))),
+            ]
+        );
+        let h6 = markdown::Tag::Heading(HeadingLevel::H6, None, vec!["unnumbered"]);
+        self::assert_eq!(
+            ChapterConverter::default()
+                .overflow(Overflow::Clamp)
+                .over(events())
+                .skip(4)
+                .take(2)
+                .collect::<Vec<_>>(),
+            vec![
+                Mdbook(MdbookEvent::MarkdownContentEvent(markdown::Event::Start(
+                    h6.clone()
+                ))),
+                Mdbook(MdbookEvent::MarkdownContentEvent(markdown::Event::End(
+                    h6.clone()
+                ))),
+            ]
+        );
+    }
+
+    #[test]
+    fn numbered_chapters() {
+        let numbered = |name: &'static str, number: Vec<u32>| {
+            MdbookTag::Chapter(
+                mdbook::ChapterStatus::Active,
+                name.into(),
+                None,
+                mdbook::ChapterKind::Numbered(mdbook::SectionNumber(number)),
+            )
+        };
+        let suffix = MdbookTag::Chapter(
+            mdbook::ChapterStatus::Active,
+            "Appendix".into(),
+            None,
+            mdbook::ChapterKind::Suffix,
+        );
+        let events = vec![
+            MdbookEvent::Start(numbered("One", vec![1])),
+            MdbookEvent::Start(numbered("Two", vec![1, 2])),
+            MdbookEvent::End(numbered("Two", vec![1, 2])),
+            MdbookEvent::End(numbered("One", vec![1])),
+            MdbookEvent::Start(suffix.clone()),
+            MdbookEvent::End(suffix),
+        ];
+        let markup: String = typst::to::markup::TypstMarkup::new(typst::AssertTypst(
+            ConvertChapter::new(MdbookIter(events.into_iter())),
+        ))
+        .collect();
+
+        self::assert_eq!(
+            markup,
+            "\
+#set heading(numbering: \"1.1.\")
+#counter(heading).update((0,))
+= One
+#counter(heading).update((1, 1))
+== Two
+#pagebreak(weak: true)
+#pagebreak(weak: true)
+#set heading(numbering: none)
+= Appendix
+#pagebreak(weak: true)
+"
+        );
+    }
+
+    #[test]
+    fn part_pages() {
+        let part = MdbookTag::Part(Some("The \"Basics\"".into()), Some(1));
+        let events = vec![
+            MdbookEvent::Start(MdbookTag::Part(None, None)),
+            MdbookEvent::End(MdbookTag::Part(None, None)),
+            MdbookEvent::Start(part.clone()),
+            MdbookEvent::Start(chapter("One")),
+            MdbookEvent::End(chapter("One")),
+            MdbookEvent::End(part),
+        ];
+        let markup: String = typst::to::markup::TypstMarkup::new(typst::AssertTypst(
+            ConvertChapter::new(ConvertParts::with(
+                MdbookIter(events.into_iter()),
+                PartConverter::new(PartStyle::Page),
+            )),
+        ))
+        .collect();
+
+        self::assert_eq!(
+            markup,
+            "\
+#pagebreak(weak: true)
+#align(center + horizon, heading(level: 1, numbering: none, \"The \\\"Basics\\\"\"))
+#pagebreak(weak: true)
+#set heading(numbering: none)
+= One
+#pagebreak(weak: true)
+"
+        );
+    }
+
+    #[test]
+    fn metadata() {
+        let events = vec![
+            MdbookEvent::Description("A \"good\" book".into()),
+            MdbookEvent::Language("pt-br".into()),
+            MdbookEvent::TextDirection(mdbook::TextDirection::LeftToRight),
+            MdbookEvent::Multilingual,
+            MdbookEvent::Keywords(vec!["rust".into()]),
+            MdbookEvent::Date("2024-05-01T10:00:00Z".into()),
+            MdbookEvent::Date("soon".into()),
+        ];
+        let markup: String = typst::to::markup::TypstMarkup::new(typst::AssertTypst(
+            ConvertMetadata::new(MdbookIter(events.into_iter())),
+        ))
+        .collect();
+
+        self::assert_eq!(
+            markup,
+            "\
+#set document(description: \"A \\\"good\\\" book\")
+#set text(lang: \"pt\")
+#set text(region: \"BR\")
+#set text(dir: ltr)
+#set document(keywords: (\"rust\",))
+#set document(date: datetime(year: 2024, month: 5, day: 1))
+"
+        );
+    }
+
+    #[test]
+    fn outline() {
+        let events = vec![
+            MdbookEvent::Start(MdbookTag::BookContent),
+            MdbookEvent::MarkdownContentEvent(markdown::Event::Html("<!-- toc -->\n".into())),
+            MdbookEvent::End(MdbookTag::BookContent),
+        ];
+        let outline = |args: Vec<&'static str>| {
+            Typst(TypstEvent::FunctionCall(
+                None,
+                "outline".into(),
+                args.into_iter().map(CowStr::from).collect(),
+            ))
+        };
+
+        self::assert_eq!(
+            ConvertOutline::new(MdbookIter(events.clone().into_iter())).collect::<Vec<_>>(),
+            vec![
+                Mdbook(MdbookEvent::Start(MdbookTag::BookContent)),
+                outline(vec![]),
+                Mdbook(events[1].clone()),
+                Mdbook(MdbookEvent::End(MdbookTag::BookContent)),
+            ]
+        );
+        self::assert_eq!(
+            OutlineConverter::new(OutlinePosition::Marker)
+                .title("Contents")
+                .depth(2)
+                .indent("auto")
+                .over(MdbookIter(events.into_iter()))
+                .collect::<Vec<_>>(),
+            vec![
+                Mdbook(MdbookEvent::Start(MdbookTag::BookContent)),
+                outline(vec!["title: \"Contents\"", "depth: 2", "indent: auto"]),
+                Mdbook(MdbookEvent::End(MdbookTag::BookContent)),
+            ]
+        );
+    }
+
+    #[test]
+    fn title_page() {
+        let events = vec![
+            MdbookEvent::Start(MdbookTag::BookConfiguration),
+            MdbookEvent::Title("Book".into()),
+            MdbookEvent::Start(MdbookTag::AuthorList),
+            MdbookEvent::Author("Alice".into()),
+            MdbookEvent::End(MdbookTag::AuthorList),
+            MdbookEvent::Date("2024-05-01".into()),
+            MdbookEvent::End(MdbookTag::BookConfiguration),
+        ];
+        let title_page = |events: Vec<MdbookEvent<'static>>, converter| {
+            ConvertTitlePage::with(MdbookIter(events.into_iter()), converter)
+                .filter(|e| matches!(e, Typst(_)))
+                .collect::<Vec<_>>()
+        };
+        let args = vec![
+            "title: \"Book\"".into(),
+            "authors: (\"Alice\",)".into(),
+            "date: datetime(year: 2024, month: 5, day: 1)".into(),
+        ];
+
+        self::assert_eq!(
+            title_page(events.clone(), TitlePageConverter::default()),
+            vec![
+                Typst(TypstEvent::Let(TITLE_PAGE.0.into(), TITLE_PAGE.1.into())),
+                Typst(TypstEvent::FunctionCall(
+                    None,
+                    "title-page".into(),
+                    args.clone()
+                )),
+            ]
+        );
+        self::assert_eq!(
+            title_page(events, TitlePageConverter::with_layout("cover")),
+            vec![Typst(TypstEvent::FunctionCall(None, "cover".into(), args))]
+        );
+    }
+
+    #[test]
+    fn template() {
+        let events = vec![
+            MdbookEvent::Start(MdbookTag::BookConfiguration),
+            MdbookEvent::Title("Book".into()),
+            MdbookEvent::Start(MdbookTag::AuthorList),
+            MdbookEvent::Author("Alice".into()),
+            MdbookEvent::Author("Bob".into()),
+            MdbookEvent::End(MdbookTag::AuthorList),
+            MdbookEvent::Language("pt-BR".into()),
+            MdbookEvent::End(MdbookTag::BookConfiguration),
+        ];
+        let markup: String = typst::to::markup::TypstMarkup::new(typst::AssertTypst(
+            ConvertTemplate::new(MdbookIter(events.into_iter())).filter(|e| matches!(e, Typst(_))),
+        ))
+        .collect();
+
+        self::assert_eq!(
+            markup,
+            "\
+#import \"template.typ\": *
+#show: template.with(title: \"Book\", lang: \"pt\", region: \"BR\", authors: (\"Alice\", \"Bob\"))
+"
+        );
+    }
+
+    #[test]
+    fn authors() {
+        let events = vec![
+            MdbookEvent::Start(MdbookTag::AuthorList),
+            MdbookEvent::Author("Alice".into()),
+            MdbookEvent::Author("Bob".into()),
+            MdbookEvent::End(MdbookTag::AuthorList),
+        ];
+
+        self::assert_eq!(
+            ConvertAuthors::new(MdbookIter(events.into_iter())).collect::<Vec<_>>(),
+            vec![Typst(TypstEvent::DocumentSet(
+                "author".into(),
+                "(\"Alice\",\"Bob\")".into()
+            ))]
+        );
+    }
+}
@@ -0,0 +1,278 @@
+//! Conversion options read from `book.toml`.
+
+use std::collections::VecDeque;
+
+use serde::Deserialize;
+
+use super::{array, quote};
+use crate::convert::{Converter, ConverterIter, Input};
+use crate::markdown::to::typst::SmartPunctuation;
+use crate::mdbook;
+use crate::mdbook::toml;
+use crate::typst;
+use crate::ParserEvent;
+
+#[cfg(feature = "builder")]
+use super::Stage;
+
+/// Options for converting mdBook to Typst, read from the renderer's `[output.typst]`
+/// table in `book.toml`:
+///
+/// ```toml
+/// [output.typst]
+/// skip = ["emphasis", "tables"]
+/// paper = "a5"
+/// fonts = ["Libertinus Serif", "New Computer Modern"]
+/// toc-depth = 2
+/// chapter-page-breaks = false
+/// smart-punctuation = "unicode"
+/// template = { source = "template.typ", name = "book" }
+/// ```
+///
+/// Unknown keys are ignored. Stages are only skipped by the
+/// [`Conversion`](super::Conversion) builder, so `skip` is an error without the
+/// `builder` feature.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Options {
+    /// [`Conversion`](super::Conversion) stages to leave out.
+    #[cfg(feature = "builder")]
+    pub skip: Vec<Stage>,
+    /// The paper size, e.g. `a4` or `us-letter`.
+    pub paper: Option<String>,
+    /// Fonts for text, in order of preference.
+    pub fonts: Vec<String>,
+    /// The deepest heading level to include in the table of contents.
+    pub toc_depth: Option<u8>,
+    /// Whether each chapter ends with a page break.
+    pub chapter_page_breaks: bool,
+    /// How quotes and dashes are typeset. Typst's smart quotes are left alone if not
+    /// set.
+    pub smart_punctuation: Option<SmartPunctuation>,
+    /// A template to wrap the book in.
+    pub template: Option<Template>,
+}
+
+/// A Typst template function applied to the whole book with an everything show rule,
+/// `#show: name.with(...)`. See [`TemplateConverter`](super::TemplateConverter).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct Template {
+    /// The module defining the template, e.g. `template.typ` or a package spec.
+    pub source: String,
+    /// The name of the template function.
+    pub name: String,
+}
+
+impl Default for Template {
+    fn default() -> Self {
+        Self {
+            source: "template.typ".to_string(),
+            name: "template".to_string(),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "builder")]
+            skip: vec![],
+            paper: None,
+            fonts: vec![],
+            toc_depth: None,
+            chapter_page_breaks: true,
+            smart_punctuation: None,
+            template: None,
+        }
+    }
+}
+
+impl Options {
+    /// Deserialize options from a renderer table.
+    pub fn from_table(table: &toml::value::Table) -> Result<Self, toml::de::Error> {
+        #[cfg(not(feature = "builder"))]
+        if table.contains_key("skip") {
+            return Err(serde::de::Error::custom(
+                "`skip` requires the `builder` feature",
+            ));
+        }
+        toml::Value::Table(table.clone()).try_into()
+    }
+
+    /// The Typst set rules for these options.
+    fn set_rules<'a>(&self) -> impl Iterator<Item = ParserEvent<'a>> {
+        let paper = self
+            .paper
+            .as_ref()
+            .map(|paper| typst::Event::Set("page".into(), "paper".into(), quote(paper).into()));
+        let fonts = (!self.fonts.is_empty()).then(|| {
+            let fonts = self.fonts.iter().map(|f| quote(f)).collect::<Vec<_>>();
+            typst::Event::Set("text".into(), "font".into(), array(&fonts).into())
+        });
+        let smartquote = self.smart_punctuation.map(|p| {
+            let enabled = match p {
+                SmartPunctuation::Typst => "true",
+                SmartPunctuation::Unicode => "false",
+            };
+            typst::Event::Set("smartquote".into(), "enabled".into(), enabled.into())
+        });
+        paper
+            .into_iter()
+            .chain(fonts)
+            .chain(smartquote)
+            .map(ParserEvent::Typst)
+    }
+}
+
+/// Apply the [`Options`] that map to Typst set rules, the paper size, fonts and smart
+/// quotes.
+///
+/// Options given to [`OptionsConverter::new`] are applied before the first event.
+/// [`RendererConfig`](mdbook::Event::RendererConfig) events are dropped, read their
+/// table with [`Options::from_table`] to handle invalid options.
+#[derive(Debug, Default)]
+pub struct OptionsConverter {
+    options: Option<Options>,
+}
+
+impl OptionsConverter {
+    pub fn new(options: Options) -> Self {
+        Self {
+            options: Some(options),
+        }
+    }
+
+    fn apply<'a>(&mut self, output: &mut VecDeque<ParserEvent<'a>>) {
+        if let Some(options) = self.options.take() {
+            output.extend(options.set_rules());
+        }
+    }
+}
+
+impl<'a> Converter<'a> for OptionsConverter {
+    fn convert(
+        &mut self,
+        event: ParserEvent<'a>,
+        _input: &mut Input<'_, 'a>,
+        output: &mut VecDeque<ParserEvent<'a>>,
+    ) {
+        self.apply(output);
+        match event {
+            ParserEvent::Mdbook(mdbook::Event::RendererConfig(_)) => {}
+            x => output.push_back(x),
+        }
+    }
+
+    fn flush(&mut self, output: &mut VecDeque<ParserEvent<'a>>) {
+        self.apply(output);
+    }
+}
+
+/// Apply [`Options`] to Typst. See [`OptionsConverter`].
+pub type ConvertOptions<'a, T> = ConverterIter<'a, T, OptionsConverter>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mdbook::MdbookIter;
+    use similar_asserts::assert_eq;
+
+    fn table(s: &str) -> toml::value::Table {
+        toml::from_str(s).expect("valid table")
+    }
+
+    #[test]
+    fn deserialize() {
+        let options = Options::from_table(&table(
+            r#"
+            paper = "a5"
+            fonts = ["Libertinus Serif"]
+            toc-depth = 2
+            chapter-page-breaks = false
+            unknown = 1
+            "#,
+        ))
+        .expect("valid options");
+        self::assert_eq!(
+            (
+                options.paper,
+                options.fonts,
+                options.toc_depth,
+                options.chapter_page_breaks
+            ),
+            (
+                Some("a5".to_string()),
+                vec!["Libertinus Serif".to_string()],
+                Some(2),
+                false
+            )
+        );
+        self::assert_eq!(
+            Options::from_table(&table("")).expect("empty"),
+            Options::default()
+        );
+        assert!(Options::from_table(&table("toc-depth = \"deep\"")).is_err());
+    }
+
+    #[test]
+    fn template() {
+        let options =
+            Options::from_table(&table(r#"template = { name = "book" }"#)).expect("valid options");
+        self::assert_eq!(
+            options.template,
+            Some(Template {
+                source: "template.typ".into(),
+                name: "book".into(),
+            })
+        );
+    }
+
+    #[cfg(feature = "builder")]
+    #[test]
+    fn skip() {
+        let options = Options::from_table(&table(r#"skip = ["soft-breaks", "blockquotes"]"#))
+            .expect("valid options");
+        self::assert_eq!(options.skip, vec![Stage::SoftBreaks, Stage::BlockQuotes]);
+    }
+
+    #[cfg(not(feature = "builder"))]
+    #[test]
+    fn skip_without_builder() {
+        assert!(Options::from_table(&table(r#"skip = ["tables"]"#)).is_err());
+    }
+
+    #[test]
+    fn set_rules_from_table() {
+        let table =
+            table("paper = \"a5\"\nfonts = [\"A\", \"B\"]\nsmart-punctuation = \"unicode\"");
+        let options = Options::from_table(&table).expect("valid options");
+        let events = vec![
+            mdbook::Event::RendererConfig(&table),
+            mdbook::Event::Separator,
+        ];
+        self::assert_eq!(
+            OptionsConverter::new(options)
+                .over(MdbookIter(events.into_iter()))
+                .collect::<Vec<_>>(),
+            vec![
+                ParserEvent::Typst(typst::Event::Set(
+                    "page".into(),
+                    "paper".into(),
+                    "\"a5\"".into()
+                )),
+                ParserEvent::Typst(typst::Event::Set(
+                    "text".into(),
+                    "font".into(),
+                    "(\"A\", \"B\")".into()
+                )),
+                ParserEvent::Typst(typst::Event::Set(
+                    "smartquote".into(),
+                    "enabled".into(),
+                    "false".into()
+                )),
+                ParserEvent::Mdbook(mdbook::Event::Separator),
+            ]
+        );
+    }
+}
@@ -0,0 +1,438 @@
+//! Render [`ParserEvent`] streams as plain text.
+//!
+//! Useful for search indexes, previews and summaries. Markup is dropped and only the
+//! text remains:
+//!
+//! * Headings, paragraphs and code blocks are separated by blank lines.
+//! * List items start with `-` or their number.
+//! * Table cells are separated by ` | `, one row per line.
+//! * Links are written as `text (url)`.
+//! * Code is kept as is.
+//! * Configuration such as Typst set rules or mdBook metadata is dropped.
+
+use std::borrow::Cow;
+
+use crate::ParserEvent;
+
+#[cfg(feature = "markdown")]
+use crate::markdown;
+#[cfg(feature = "mdbook")]
+use crate::mdbook;
+#[cfg(feature = "typst")]
+use crate::typst;
+
+/// Render events as plain text with [`PlainText`]'s defaults.
+pub fn to_plaintext<'a, T>(events: T) -> String
+where
+    T: IntoIterator<Item = ParserEvent<'a>>,
+{
+    PlainText::new().render(events)
+}
+
+/// Plain text rendering options.
+#[derive(Debug, Clone, Default)]
+pub struct PlainText {
+    width: Option<usize>,
+}
+
+impl PlainText {
+    /// Render without wrapping lines.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap paragraphs, headings and list items at `width` characters. Code and table
+    /// rows are not wrapped, nor are words longer than the width.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Render `events` as plain text.
+    pub fn render<'a, T>(&self, events: T) -> String
+    where
+        T: IntoIterator<Item = ParserEvent<'a>>,
+    {
+        let mut writer = Writer {
+            width: self.width,
+            ..Writer::default()
+        };
+        for event in events {
+            piece(&event, &mut |piece| writer.write(piece));
+        }
+        writer.finish()
+    }
+}
+
+/// A format-independent view of an event.
+#[derive(Debug)]
+enum Piece<'e> {
+    Text(Cow<'e, str>),
+    /// The start or end of a paragraph-like block, such as a heading.
+    Block,
+    Code(bool),
+    /// A list, with the number of the first item if numbered.
+    List(Option<u64>),
+    ListEnd,
+    ListItem(bool),
+    Row(bool),
+    Cell(bool),
+    Link(Cow<'e, str>),
+    LinkEnd,
+    HardBreak,
+}
+
+/// The plain text pieces for `event`.
+fn piece<'e>(event: &'e ParserEvent<'_>, f: &mut dyn FnMut(Piece<'e>)) {
+    match event {
+        #[cfg(feature = "markdown")]
+        ParserEvent::Markdown(e) => markdown_piece(e, f),
+        #[cfg(feature = "mdbook")]
+        ParserEvent::Mdbook(e) => mdbook_piece(e, f),
+        #[cfg(feature = "typst")]
+        ParserEvent::Typst(e) => typst_piece(e, f),
+        #[allow(unreachable_patterns)]
+        _ => {}
+    }
+}
+
+#[cfg(feature = "markdown")]
+fn markdown_piece<'e>(event: &'e markdown::Event<'_>, f: &mut dyn FnMut(Piece<'e>)) {
+    use markdown::{Event, Tag};
+
+    let start = matches!(event, Event::Start(_));
+    match event {
+        Event::Start(tag) | Event::End(tag) => match tag {
+            Tag::Paragraph | Tag::Heading(..) | Tag::BlockQuote | Tag::FootnoteDefinition(_) => {
+                f(Piece::Block)
+            }
+            Tag::CodeBlock(_) => f(Piece::Code(start)),
+            Tag::List(number) if start => f(Piece::List(*number)),
+            Tag::List(_) => f(Piece::ListEnd),
+            Tag::Item => f(Piece::ListItem(start)),
+            Tag::Table(_) => f(Piece::Block),
+            Tag::TableHead | Tag::TableRow => f(Piece::Row(start)),
+            Tag::TableCell => f(Piece::Cell(start)),
+            Tag::Link(markdown::LinkType::Autolink | markdown::LinkType::Email, _, _) => {}
+            Tag::Link(_, url, _) if start => f(Piece::Link(Cow::Borrowed(url))),
+            Tag::Link(..) => f(Piece::LinkEnd),
+            Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Image(..) => {}
+        },
+        Event::Text(t) | Event::Code(t) => f(Piece::Text(Cow::Borrowed(t))),
+        Event::FootnoteReference(name) => f(Piece::Text(Cow::Owned(format!("[{name}]")))),
+        Event::TaskListMarker(done) => f(Piece::Text(Cow::Borrowed(if *done {
+            "[x] "
+        } else {
+            "[ ] "
+        }))),
+        Event::SoftBreak => f(Piece::Text(Cow::Borrowed(" "))),
+        Event::HardBreak => f(Piece::HardBreak),
+        Event::Rule => {
+            f(Piece::Block);
+            f(Piece::Text(Cow::Borrowed("---")));
+            f(Piece::Block);
+        }
+        Event::Html(_) => {}
+    }
+}
+
+#[cfg(feature = "mdbook")]
+fn mdbook_piece<'e>(event: &'e mdbook::Event<'_>, f: &mut dyn FnMut(Piece<'e>)) {
+    use mdbook::{Event, Tag};
+
+    match event {
+        Event::Start(Tag::Chapter(_, name, _, _)) | Event::Start(Tag::Part(Some(name), _)) => {
+            f(Piece::Block);
+            f(Piece::Text(Cow::Borrowed(name)));
+            f(Piece::Block);
+        }
+        Event::MarkdownContentEvent(e) => markdown_piece(e, f),
+        _ => {}
+    }
+}
+
+#[cfg(feature = "typst")]
+fn typst_piece<'e>(event: &'e typst::Event<'_>, f: &mut dyn FnMut(Piece<'e>)) {
+    use typst::{Event, Tag};
+
+    let start = matches!(event, Event::Start(_));
+    match event {
+        Event::Start(tag) | Event::End(tag) => match tag {
+            Tag::Paragraph | Tag::Heading(..) | Tag::Quote(typst::QuoteType::Block, _, _) => {
+                f(Piece::Block)
+            }
+            Tag::Callout(kind, title) => {
+                f(Piece::Block);
+                let title = title.as_deref().unwrap_or(kind.name());
+                if start && !title.is_empty() {
+                    f(Piece::Text(Cow::Owned(format!("{title}:"))));
+                    f(Piece::HardBreak);
+                }
+            }
+            Tag::CodeBlock(..) => f(Piece::Code(start)),
+            Tag::BulletList(..) if start => f(Piece::List(None)),
+            Tag::NumberedList(number, _, _) if start => f(Piece::List(Some(*number))),
+            Tag::BulletList(..) | Tag::NumberedList(..) => f(Piece::ListEnd),
+            Tag::Item => f(Piece::ListItem(start)),
+            Tag::Table(_)
+            | Tag::Block(_)
+            | Tag::Rect(_)
+            | Tag::Grid(_)
+            | Tag::Stack(..)
+            | Tag::Child
+            | Tag::Columns(..)
+            | Tag::Align(_)
+            | Tag::Pad(..)
+            | Tag::Place(..) => f(Piece::Block),
+            Tag::TableHead | Tag::TableRow => f(Piece::Row(start)),
+            Tag::TableCell => f(Piece::Cell(start)),
+            Tag::Link(typst::LinkType::Content, url) if start => f(Piece::Link(Cow::Borrowed(url))),
+            Tag::Link(typst::LinkType::Content, _) => f(Piece::LinkEnd),
+            Tag::Quote(typst::QuoteType::Inline, _, _)
+            | Tag::Emphasis
+            | Tag::Strong
+            | Tag::Strikethrough
+            | Tag::Link(..)
+            | Tag::Show(..)
+            | Tag::File(_)
+            | Tag::Box(_) => {}
+        },
+        Event::Text(t) | Event::Code(t) => f(Piece::Text(Cow::Borrowed(t))),
+        Event::Linebreak => f(Piece::HardBreak),
+        Event::Parbreak | Event::PageBreak => f(Piece::Block),
+        Event::Line(..)
+        | Event::Import(..)
+        | Event::Include(_)
+        | Event::Let(..)
+        | Event::FunctionCall(..)
+        | Event::DocumentFunctionCall(_)
+        | Event::Set(..)
+        | Event::DocumentSet(..)
+        | Event::Raw(_) => {}
+    }
+}
+
+/// Writes [`Piece`]s as text.
+#[derive(Debug, Default)]
+struct Writer {
+    width: Option<usize>,
+    out: String,
+    // Text of the current line or paragraph, before wrapping.
+    line: String,
+    // The marker of the list item the current line starts, if any.
+    marker: Option<String>,
+    // The next number of each open list, `None` for bullet lists.
+    lists: Vec<Option<u64>>,
+    links: Vec<String>,
+    code: bool,
+    // Cells in the current table row.
+    cells: Vec<String>,
+}
+
+impl Writer {
+    fn write(&mut self, piece: Piece<'_>) {
+        match piece {
+            Piece::Text(t) if self.code => self.out.push_str(&t),
+            Piece::Text(t) => self.line.push_str(&t),
+            Piece::HardBreak => self.flush_line(),
+            Piece::Block if self.lists.is_empty() => self.end_block(),
+            Piece::Block => self.flush_line(),
+            Piece::Code(true) => {
+                self.end_block();
+                self.code = true;
+            }
+            Piece::Code(false) => {
+                self.code = false;
+                if !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out.push('\n');
+            }
+            Piece::List(number) => {
+                self.flush_line();
+                self.lists.push(number);
+            }
+            Piece::ListEnd => {
+                self.flush_line();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.end_block();
+                }
+            }
+            Piece::ListItem(true) => {
+                self.flush_line();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}. ", *n - 1)
+                    }
+                    _ => "- ".to_string(),
+                };
+                self.marker = Some(marker);
+            }
+            Piece::ListItem(false) => self.flush_line(),
+            Piece::Row(true) => self.cells.clear(),
+            Piece::Row(false) => {
+                let row = std::mem::take(&mut self.cells).join(" | ");
+                self.out.push_str(row.trim_end());
+                self.out.push('\n');
+            }
+            Piece::Cell(true) => {}
+            Piece::Cell(false) => {
+                let cell = std::mem::take(&mut self.line);
+                self.cells.push(cell.trim().to_string());
+            }
+            Piece::Link(url) => self.links.push(url.into_owned()),
+            Piece::LinkEnd => {
+                if let Some(url) = self.links.pop() {
+                    if !url.is_empty() && !self.line.ends_with(&url) {
+                        self.line.push_str(&format!(" ({url})"));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Write the current line, wrapped and indented for the current list.
+    fn flush_line(&mut self) {
+        let text = std::mem::take(&mut self.line);
+        let text = text.split_whitespace().collect::<Vec<_>>();
+        let marker = self.marker.take();
+        if text.is_empty() && marker.is_none() {
+            return;
+        }
+        let indent = " ".repeat(2 * self.lists.len().saturating_sub(1));
+        let first = format!("{indent}{}", marker.as_deref().unwrap_or(""));
+        let rest = if self.lists.is_empty() {
+            String::new()
+        } else {
+            " ".repeat(first.chars().count())
+        };
+
+        let mut line = first;
+        let mut empty = true;
+        for word in text {
+            let fits = self
+                .width
+                .is_none_or(|w| line.chars().count() + 1 + word.chars().count() <= w);
+            if !empty && !fits {
+                self.out.push_str(&line);
+                self.out.push('\n');
+                line = rest.clone();
+                empty = true;
+            }
+            if !empty {
+                line.push(' ');
+            }
+            line.push_str(word);
+            empty = false;
+        }
+        self.out.push_str(line.trim_end());
+        self.out.push('\n');
+    }
+
+    /// Write the current line and separate it from what follows with a blank line.
+    fn end_block(&mut self) {
+        self.flush_line();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.flush_line();
+        let len = self.out.trim_end().len();
+        self.out.truncate(len);
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out
+    }
+}
+
+#[cfg(all(test, feature = "markdown"))]
+mod tests {
+    use super::*;
+    use crate::markdown::{MarkdownIter, Options, Parser};
+    use similar_asserts::assert_eq;
+
+    fn plaintext(md: &str) -> String {
+        to_plaintext(MarkdownIter(Parser::new_ext(md, Options::ENABLE_TABLES)))
+    }
+
+    #[test]
+    fn blocks() {
+        let md = "\
+# Title
+
+Some *emphasis* and `code`,
+soft broken.
+
+```rust
+fn main() {}
+```
+
+| A | B |
+|---|---|
+| 1 | 2 |
+";
+        self::assert_eq!(
+            plaintext(md),
+            "\
+Title
+
+Some emphasis and code, soft broken.
+
+fn main() {}
+
+A | B
+1 | 2
+"
+        );
+    }
+
+    #[test]
+    fn lists_and_links() {
+        let md = "\
+1. [Docs](https://example.com)
+2. <https://example.com/auto>
+   - nested
+
+After.
+";
+        self::assert_eq!(
+            plaintext(md),
+            "\
+1. Docs (https://example.com)
+2. https://example.com/auto
+  - nested
+
+After.
+"
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        let md = "- one two three four five six\n";
+        let text = PlainText::new()
+            .width(12)
+            .render(MarkdownIter(Parser::new(md)));
+        self::assert_eq!(text, "- one two\n  three four\n  five six\n");
+    }
+
+    #[cfg(feature = "typst")]
+    #[test]
+    fn typst_settings_dropped() {
+        use crate::typst::{Event, Tag, TypstIter};
+
+        let events = vec![
+            Event::Set("text".into(), "lang".into(), "\"en\"".into()),
+            Event::Let("x".into(), "1".into()),
+            Event::Start(Tag::Paragraph),
+            Event::Text("Hello".into()),
+            Event::End(Tag::Paragraph),
+        ];
+        self::assert_eq!(to_plaintext(TypstIter(events.into_iter())), "Hello\n");
+    }
+}
@@ -0,0 +1,341 @@
+//! Document statistics such as word counts and reading time.
+//!
+//! With the `serde` feature, [`Stats`] can be serialized, e.g. to JSON for a search
+//! index or a dashboard.
+
+use std::collections::BTreeMap;
+
+use crate::ParserEvent;
+
+#[cfg(feature = "markdown")]
+use crate::markdown;
+#[cfg(feature = "mdbook")]
+use crate::mdbook;
+#[cfg(feature = "typst")]
+use crate::typst;
+
+/// Statistics for a document.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Stats {
+    /// Words outside of code blocks.
+    pub words: usize,
+    /// The estimated reading time, rounded up.
+    pub reading_minutes: usize,
+    /// The deepest heading level, or chapter nesting depth.
+    pub outline_depth: usize,
+    pub links: usize,
+    pub images: usize,
+    /// Code blocks by language. Blocks without a language are counted under `""`.
+    pub code_blocks: BTreeMap<String, usize>,
+    /// Words in each mdBook chapter, not counting nested chapters.
+    pub chapters: Vec<Section>,
+    /// Words after each heading, up to the next heading or chapter.
+    pub headings: Vec<Section>,
+}
+
+/// Statistics for a chapter or heading.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Section {
+    pub title: String,
+    /// The heading level, or the chapter nesting depth starting at 1.
+    pub level: usize,
+    pub words: usize,
+}
+
+/// Collect [`Stats`] with [`Analyzer`]'s defaults.
+pub fn stats<'a, T>(events: T) -> Stats
+where
+    T: IntoIterator<Item = ParserEvent<'a>>,
+{
+    Analyzer::new().analyze(events)
+}
+
+/// Collects [`Stats`] from any [`ParserEvent`] stream.
+#[derive(Debug, Clone)]
+pub struct Analyzer {
+    words_per_minute: usize,
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Self {
+            words_per_minute: 200,
+        }
+    }
+}
+
+impl Analyzer {
+    /// Estimate reading time at 200 words per minute.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Estimate reading time at `words_per_minute` instead.
+    pub fn words_per_minute(mut self, words_per_minute: usize) -> Self {
+        self.words_per_minute = words_per_minute.max(1);
+        self
+    }
+
+    /// Collect statistics for `events`.
+    pub fn analyze<'a, T>(&self, events: T) -> Stats
+    where
+        T: IntoIterator<Item = ParserEvent<'a>>,
+    {
+        let mut collector = Collector::default();
+        for event in events {
+            collector.event(&event);
+        }
+        let mut stats = collector.stats;
+        stats.reading_minutes = stats.words.div_ceil(self.words_per_minute);
+        stats
+    }
+}
+
+#[derive(Debug, Default)]
+struct Collector {
+    stats: Stats,
+    // Indexes into `stats.chapters` of the open chapters.
+    chapters: Vec<usize>,
+    // Index into `stats.headings` of the current heading section.
+    heading: Option<usize>,
+    // Whether heading text is being read.
+    in_heading: bool,
+    code: usize,
+    // Whether the last text ended inside a word.
+    in_word: bool,
+}
+
+impl Collector {
+    fn event(&mut self, event: &ParserEvent<'_>) {
+        match event {
+            #[cfg(feature = "markdown")]
+            ParserEvent::Markdown(e) => self.markdown(e),
+            #[cfg(feature = "mdbook")]
+            ParserEvent::Mdbook(e) => self.mdbook(e),
+            #[cfg(feature = "typst")]
+            ParserEvent::Typst(e) => self.typst(e),
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+    }
+
+    #[cfg(feature = "markdown")]
+    fn markdown(&mut self, event: &markdown::Event<'_>) {
+        use markdown::{Event, Tag};
+
+        match event {
+            Event::Text(t) | Event::Code(t) => self.text(t),
+            Event::Start(Tag::Heading(level, _, _)) => self.heading(*level as usize),
+            Event::End(Tag::Heading(..)) => self.heading_end(),
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let lang = match kind {
+                    markdown::CodeBlockKind::Fenced(info) => {
+                        info.split([' ', ',']).next().unwrap_or_default()
+                    }
+                    markdown::CodeBlockKind::Indented => "",
+                };
+                self.code_block(lang);
+            }
+            Event::End(Tag::CodeBlock(_)) => self.code_block_end(),
+            Event::Start(Tag::Link(..)) => self.stats.links += 1,
+            Event::Start(Tag::Image(..)) => self.stats.images += 1,
+            Event::Start(Tag::Emphasis | Tag::Strong | Tag::Strikethrough)
+            | Event::End(Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Link(..)) => {}
+            _ => self.in_word = false,
+        }
+    }
+
+    #[cfg(feature = "mdbook")]
+    fn mdbook(&mut self, event: &mdbook::Event<'_>) {
+        use mdbook::{Event, Tag};
+
+        match event {
+            Event::Start(Tag::Chapter(_, name, _, _)) => {
+                self.chapters.push(self.stats.chapters.len());
+                self.stats.chapters.push(Section {
+                    title: name.to_string(),
+                    level: self.chapters.len(),
+                    words: 0,
+                });
+                self.stats.outline_depth = self.stats.outline_depth.max(self.chapters.len());
+                self.heading = None;
+                self.in_word = false;
+            }
+            Event::End(Tag::Chapter(..)) => {
+                self.chapters.pop();
+                self.heading = None;
+                self.in_word = false;
+            }
+            Event::MarkdownContentEvent(e) => self.markdown(e),
+            _ => {}
+        }
+    }
+
+    #[cfg(feature = "typst")]
+    fn typst(&mut self, event: &typst::Event<'_>) {
+        use typst::{Event, Tag};
+
+        match event {
+            Event::Text(t) | Event::Code(t) => self.text(t),
+            Event::Start(Tag::Heading(level, ..)) => self.heading(level.get().into()),
+            Event::End(Tag::Heading(..)) => self.heading_end(),
+            Event::Start(Tag::CodeBlock(lang, _)) => {
+                self.code_block(lang.as_deref().unwrap_or_default())
+            }
+            Event::End(Tag::CodeBlock(..)) => self.code_block_end(),
+            Event::Start(Tag::Link(..)) => self.stats.links += 1,
+            Event::Start(Tag::Emphasis | Tag::Strong | Tag::Strikethrough)
+            | Event::End(Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Link(..)) => {}
+            _ => self.in_word = false,
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.code > 0 {
+            return;
+        }
+        let mut words = 0;
+        for c in text.chars() {
+            let in_word = !c.is_whitespace();
+            if in_word && !self.in_word {
+                words += 1;
+            }
+            self.in_word = in_word;
+        }
+        self.stats.words += words;
+        if let Some(&i) = self.chapters.last() {
+            self.stats.chapters[i].words += words;
+        }
+        match (self.in_heading, self.heading) {
+            (true, Some(i)) => self.stats.headings[i].title.push_str(text),
+            (false, Some(i)) => self.stats.headings[i].words += words,
+            _ => {}
+        }
+    }
+
+    fn heading(&mut self, level: usize) {
+        self.heading = Some(self.stats.headings.len());
+        self.stats.headings.push(Section {
+            title: String::new(),
+            level,
+            words: 0,
+        });
+        self.stats.outline_depth = self.stats.outline_depth.max(level);
+        self.in_heading = true;
+        self.in_word = false;
+    }
+
+    fn heading_end(&mut self) {
+        self.in_heading = false;
+        self.in_word = false;
+    }
+
+    fn code_block(&mut self, lang: &str) {
+        *self.stats.code_blocks.entry(lang.to_string()).or_default() += 1;
+        self.code += 1;
+    }
+
+    fn code_block_end(&mut self) {
+        self.code = self.code.saturating_sub(1);
+        self.in_word = false;
+    }
+}
+
+#[cfg(all(test, feature = "mdbook"))]
+mod tests {
+    use super::*;
+    use crate::markdown::{MarkdownIter, Parser};
+    use crate::mdbook::{ChapterKind, ChapterStatus, Event, MdbookIter, Tag};
+    use similar_asserts::assert_eq;
+
+    #[test]
+    fn markdown() {
+        let md = "\
+# Intro
+
+Some *empha*sis and [a link](https://example.com).
+
+![image](cat.png)
+
+## Code
+
+```rust,ignore
+let words = \"not counted\";
+```
+
+```
+plain
+```
+";
+        let stats = Analyzer::new()
+            .words_per_minute(2)
+            .analyze(MarkdownIter(Parser::new(md)));
+        self::assert_eq!(
+            stats,
+            Stats {
+                words: 8,
+                reading_minutes: 4,
+                outline_depth: 2,
+                links: 1,
+                images: 1,
+                code_blocks: [("".to_string(), 1), ("rust".to_string(), 1)].into(),
+                chapters: vec![],
+                headings: vec![
+                    Section {
+                        title: "Intro".to_string(),
+                        level: 1,
+                        words: 6
+                    },
+                    Section {
+                        title: "Code".to_string(),
+                        level: 2,
+                        words: 0
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn chapters() {
+        let chapter = |name: &'static str| {
+            Tag::Chapter(
+                ChapterStatus::Active,
+                name.into(),
+                None,
+                ChapterKind::Prefix,
+            )
+        };
+        let text = |t: &'static str| Event::MarkdownContentEvent(markdown::Event::Text(t.into()));
+        let events = vec![
+            Event::Start(chapter("One")),
+            text("one two"),
+            Event::Start(chapter("Nested")),
+            text("three"),
+            Event::End(chapter("Nested")),
+            Event::End(chapter("One")),
+        ];
+        let stats = stats(MdbookIter(events.into_iter()));
+        self::assert_eq!(
+            (stats.words, stats.outline_depth, stats.chapters),
+            (
+                3,
+                2,
+                vec![
+                    Section {
+                        title: "One".to_string(),
+                        level: 1,
+                        words: 2
+                    },
+                    Section {
+                        title: "Nested".to_string(),
+                        level: 2,
+                        words: 1
+                    },
+                ]
+            )
+        );
+    }
+}
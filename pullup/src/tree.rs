@@ -0,0 +1,331 @@
+//! A tree view of [`ParserEvent`] streams.
+//!
+//! Converters operate on flat iterators, which makes structural rewrites such as
+//! wrapping or moving whole elements awkward. A [`Tree`] groups each `Start` event,
+//! its contents, and its `End` event into one [`Node`]. Flattening an unmodified tree
+//! yields exactly the events it was built from.
+
+use crate::validate::{shape, Shape, Violation, ViolationKind};
+use crate::ParserEvent;
+
+/// A node in a [`Tree`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node<'a> {
+    /// A tagged element and everything between its `Start` and `End` events.
+    Element {
+        start: ParserEvent<'a>,
+        children: Vec<Node<'a>>,
+        end: ParserEvent<'a>,
+    },
+    /// An event that is neither a `Start` nor an `End`.
+    Leaf(ParserEvent<'a>),
+}
+
+impl<'a> Node<'a> {
+    /// The event that opens this node. For leaves this is the event itself.
+    pub fn event(&self) -> &ParserEvent<'a> {
+        match self {
+            Node::Element { start, .. } => start,
+            Node::Leaf(e) => e,
+        }
+    }
+
+    /// The children of an element. Leaves have no children.
+    pub fn children(&self) -> &[Node<'a>] {
+        match self {
+            Node::Element { children, .. } => children,
+            Node::Leaf(_) => &[],
+        }
+    }
+
+    /// The children of an element, mutably. Returns `None` for leaves.
+    pub fn children_mut(&mut self) -> Option<&mut Vec<Node<'a>>> {
+        match self {
+            Node::Element { children, .. } => Some(children),
+            Node::Leaf(_) => None,
+        }
+    }
+
+    /// Flatten the node back into events.
+    pub fn into_events(self) -> IntoEvents<'a> {
+        IntoEvents {
+            stack: vec![(vec![self].into_iter(), None)],
+        }
+    }
+}
+
+/// A forest of [`Node`]s built from a balanced event stream.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tree<'a> {
+    pub children: Vec<Node<'a>>,
+}
+
+impl<'a> Tree<'a> {
+    /// Build a tree from an event iterator.
+    ///
+    /// Returns the first [`Violation`] if the `Start` and `End` events are not
+    /// balanced.
+    #[allow(clippy::result_large_err)]
+    pub fn from_events<T>(iter: T) -> Result<Self, Violation<'a>>
+    where
+        T: IntoIterator<Item = ParserEvent<'a>>,
+    {
+        // Each open element is its start index, start event, and children so far.
+        let mut open: Vec<(usize, ParserEvent<'a>, Vec<Node<'a>>)> = vec![];
+        let mut children = vec![];
+
+        for (index, event) in iter.into_iter().enumerate() {
+            match shape(&event) {
+                Shape::Start(_) => {
+                    open.push((index, event, vec![]));
+                }
+                Shape::End(tag) => {
+                    let Some((start_index, start, nodes)) = open.pop() else {
+                        return Err(Violation {
+                            index,
+                            event,
+                            kind: ViolationKind::UnmatchedEnd,
+                        });
+                    };
+                    if !matches!(shape(&start), Shape::Start(s) if s == tag) {
+                        return Err(Violation {
+                            index,
+                            event,
+                            kind: ViolationKind::MismatchedEnd { start: start_index },
+                        });
+                    }
+                    let node = Node::Element {
+                        start,
+                        children: nodes,
+                        end: event,
+                    };
+                    match open.last_mut() {
+                        Some((_, _, parent)) => parent.push(node),
+                        None => children.push(node),
+                    }
+                }
+                Shape::Other => {
+                    let node = Node::Leaf(event);
+                    match open.last_mut() {
+                        Some((_, _, parent)) => parent.push(node),
+                        None => children.push(node),
+                    }
+                }
+            }
+        }
+
+        match open.into_iter().next() {
+            Some((index, event, _)) => Err(Violation {
+                index,
+                event,
+                kind: ViolationKind::Unclosed,
+            }),
+            None => Ok(Self { children }),
+        }
+    }
+
+    /// Iterate over all nodes depth-first, parents before their children.
+    pub fn nodes(&self) -> Nodes<'_, 'a> {
+        Nodes {
+            stack: vec![self.children.iter()],
+        }
+    }
+
+    /// Find the first node, depth-first, matching `predicate`.
+    pub fn find<P>(&self, mut predicate: P) -> Option<&Node<'a>>
+    where
+        P: FnMut(&Node<'a>) -> bool,
+    {
+        self.nodes().find(|n| predicate(n))
+    }
+
+    /// Call `f` on every node depth-first, parents before their children.
+    ///
+    /// Children are visited after `f` returns for their parent, so `f` may replace or
+    /// restructure them.
+    pub fn walk_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Node<'a>),
+    {
+        fn walk<'a, F>(nodes: &mut [Node<'a>], f: &mut F)
+        where
+            F: FnMut(&mut Node<'a>),
+        {
+            for node in nodes {
+                f(node);
+                if let Some(children) = node.children_mut() {
+                    walk(children, f);
+                }
+            }
+        }
+        walk(&mut self.children, &mut f);
+    }
+
+    /// Flatten the tree back into events.
+    pub fn into_events(self) -> IntoEvents<'a> {
+        IntoEvents {
+            stack: vec![(self.children.into_iter(), None)],
+        }
+    }
+}
+
+impl<'a> IntoIterator for Tree<'a> {
+    type Item = ParserEvent<'a>;
+    type IntoIter = IntoEvents<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_events()
+    }
+}
+
+/// Depth-first iterator over the nodes of a [`Tree`].
+#[derive(Debug)]
+pub struct Nodes<'t, 'a> {
+    stack: Vec<core::slice::Iter<'t, Node<'a>>>,
+}
+
+impl<'t, 'a> Iterator for Nodes<'t, 'a> {
+    type Item = &'t Node<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let iter = self.stack.last_mut()?;
+            match iter.next() {
+                Some(node) => {
+                    self.stack.push(node.children().iter());
+                    return Some(node);
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over the events of a flattened [`Tree`] or [`Node`].
+#[derive(Debug)]
+pub struct IntoEvents<'a> {
+    // Each level is the remaining siblings and the end event of their parent.
+    stack: Vec<(std::vec::IntoIter<Node<'a>>, Option<ParserEvent<'a>>)>,
+}
+
+impl<'a> Iterator for IntoEvents<'a> {
+    type Item = ParserEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (iter, _) = self.stack.last_mut()?;
+            match iter.next() {
+                Some(Node::Leaf(event)) => return Some(event),
+                Some(Node::Element {
+                    start,
+                    children,
+                    end,
+                }) => {
+                    self.stack.push((children.into_iter(), Some(end)));
+                    return Some(start);
+                }
+                None => {
+                    if let Some((_, Some(end))) = self.stack.pop() {
+                        return Some(end);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "markdown"))]
+mod tests {
+    use super::*;
+    use crate::markdown;
+    use crate::markdown::{MarkdownIter, Parser};
+    use similar_asserts::assert_eq;
+
+    use markdown::Event as MdEvent;
+    use markdown::Tag as MdTag;
+
+    const MD: &str = "\
+# Hello
+
+Some *emphasis* and `code`.
+
+* one
+* two
+";
+
+    #[test]
+    fn round_trip() {
+        let events = MarkdownIter(Parser::new(MD)).collect::<Vec<_>>();
+        let tree = Tree::from_events(events.clone()).expect("balanced");
+        self::assert_eq!(tree.into_events().collect::<Vec<_>>(), events);
+    }
+
+    #[test]
+    fn structure() {
+        let tree = Tree::from_events(MarkdownIter(Parser::new(MD))).expect("balanced");
+        self::assert_eq!(tree.children.len(), 3);
+
+        let list = tree
+            .find(|n| {
+                matches!(
+                    n.event(),
+                    ParserEvent::Markdown(MdEvent::Start(MdTag::List(_)))
+                )
+            })
+            .expect("list");
+        self::assert_eq!(list.children().len(), 2);
+        self::assert_eq!(
+            list.children()[1].children(),
+            &[Node::Leaf(ParserEvent::Markdown(MdEvent::Text(
+                "two".into()
+            )))]
+        );
+    }
+
+    #[test]
+    fn mutate() {
+        let mut tree = Tree::from_events(MarkdownIter(Parser::new("*a*"))).expect("balanced");
+        // Replace the emphasis with its text.
+        tree.walk_mut(|node| {
+            if let Node::Element {
+                start: ParserEvent::Markdown(MdEvent::Start(MdTag::Emphasis)),
+                children,
+                ..
+            } = node
+            {
+                let text = children.remove(0);
+                *node = text;
+            }
+        });
+        self::assert_eq!(
+            tree.into_events().collect::<Vec<_>>(),
+            vec![
+                ParserEvent::Markdown(MdEvent::Start(MdTag::Paragraph)),
+                ParserEvent::Markdown(MdEvent::Text("a".into())),
+                ParserEvent::Markdown(MdEvent::End(MdTag::Paragraph)),
+            ]
+        );
+    }
+
+    #[test]
+    fn unbalanced() {
+        let events = vec![
+            ParserEvent::Markdown(MdEvent::Start(MdTag::Paragraph)),
+            ParserEvent::Markdown(MdEvent::Text("a".into())),
+        ];
+        let err = Tree::from_events(events).expect_err("unbalanced");
+        self::assert_eq!((err.index, err.kind), (0, ViolationKind::Unclosed));
+
+        let events = vec![
+            ParserEvent::Markdown(MdEvent::Start(MdTag::Paragraph)),
+            ParserEvent::Markdown(MdEvent::End(MdTag::Emphasis)),
+        ];
+        let err = Tree::from_events(events).expect_err("mismatched");
+        self::assert_eq!(
+            (err.index, err.kind),
+            (1, ViolationKind::MismatchedEnd { start: 0 })
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! Move document-level Typst events ahead of content.
+
+use std::collections::VecDeque;
+
+use crate::typst;
+use crate::typst::CowStr;
+use crate::ParserEvent;
+
+/// Two [`DocumentSet`](typst::Event::DocumentSet) events set the same parameter to
+/// different values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentSetConflict<'a> {
+    /// The `document` parameter name.
+    pub key: CowStr<'a>,
+    /// The value that was kept. The last value set wins, like Typst set rules.
+    pub kept: CowStr<'a>,
+    /// The value that was discarded.
+    pub discarded: CowStr<'a>,
+}
+
+/// Hoist document-level Typst events to the top of the stream.
+///
+/// Typst requires `document` set rules and calls to appear before any content.
+/// Converters do not always emit them in that position, so this adaptor buffers the
+/// whole stream and reorders it as:
+///
+/// 1. [`Import`](typst::Event::Import)s
+/// 2. [`Let`](typst::Event::Let) bindings before any content
+/// 3. [`DocumentFunctionCall`](typst::Event::DocumentFunctionCall)s and
+///    [`DocumentSet`](typst::Event::DocumentSet)s
+/// 4. Everything else
+///
+/// The relative order within each group is preserved. Bindings after the first content
+/// event stay in place, as moving them would change what earlier content, later
+/// rebindings, and enclosing blocks see. Duplicate `DocumentSet` keys are merged into
+/// one event at the position of the first occurrence. If the duplicates
+/// have different values, the last value is kept and the conflict is recorded, see
+/// [`HoistDocument::conflicts`].
+#[derive(Debug)]
+pub struct HoistDocument<'a, T> {
+    iter: Option<T>,
+    buf: VecDeque<ParserEvent<'a>>,
+    conflicts: Vec<DocumentSetConflict<'a>>,
+}
+
+impl<'a, T> HoistDocument<'a, T>
+where
+    T: Iterator<Item = ParserEvent<'a>>,
+{
+    pub fn new(iter: T) -> Self {
+        Self {
+            iter: Some(iter),
+            buf: VecDeque::new(),
+            conflicts: vec![],
+        }
+    }
+
+    /// Conflicting `DocumentSet` values found so far.
+    ///
+    /// The whole inner iterator is consumed when the first event is requested, so this
+    /// is complete after the first call to `next()`.
+    pub fn conflicts(&self) -> &[DocumentSetConflict<'a>] {
+        &self.conflicts
+    }
+
+    fn hoist(&mut self, iter: T) {
+        let mut imports = vec![];
+        let mut lets = vec![];
+        let mut document = vec![];
+        let mut content = vec![];
+
+        for event in iter {
+            match event {
+                ParserEvent::Typst(typst::Event::Import(..)) => imports.push(event),
+                ParserEvent::Typst(typst::Event::Let(..)) if content.is_empty() => lets.push(event),
+                ParserEvent::Typst(typst::Event::DocumentFunctionCall(..)) => document.push(event),
+                ParserEvent::Typst(typst::Event::DocumentSet(key, value)) => {
+                    let existing = document.iter_mut().find_map(|e| match e {
+                        ParserEvent::Typst(typst::Event::DocumentSet(k, v)) if *k == key => Some(v),
+                        _ => None,
+                    });
+                    match existing {
+                        Some(existing) => {
+                            if *existing != value {
+                                #[cfg(feature = "tracing")]
+                                tracing::warn!("conflicting document set for {}", key);
+
+                                let discarded = core::mem::replace(existing, value.clone());
+                                self.conflicts.push(DocumentSetConflict {
+                                    key,
+                                    kept: value,
+                                    discarded,
+                                });
+                            }
+                        }
+                        None => {
+                            document.push(ParserEvent::Typst(typst::Event::DocumentSet(key, value)))
+                        }
+                    }
+                }
+                x => content.push(x),
+            }
+        }
+
+        self.buf.extend(imports);
+        self.buf.extend(lets);
+        self.buf.extend(document);
+        self.buf.extend(content);
+    }
+}
+
+impl<'a, T> Iterator for HoistDocument<'a, T>
+where
+    T: Iterator<Item = ParserEvent<'a>>,
+{
+    type Item = ParserEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = self.iter.take() {
+            self.hoist(iter);
+        }
+        self.buf.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typst::TypstIter;
+    use similar_asserts::assert_eq;
+
+    use typst::Event as TypstEvent;
+    use typst::Tag as TypstTag;
+    use ParserEvent::*;
+
+    #[test]
+    fn hoists_document_events() {
+        let events = vec![
+            TypstEvent::Let("x".into(), "1".into()),
+            TypstEvent::Start(TypstTag::Paragraph),
+            TypstEvent::Text("hello".into()),
+            TypstEvent::End(TypstTag::Paragraph),
+            TypstEvent::DocumentSet("title".into(), "\"Book\"".into()),
+            TypstEvent::DocumentFunctionCall(vec!["author: \"me\"".into()]),
+            TypstEvent::Import("utils.typ".into(), vec!["*".into()]),
+        ];
+        let i = HoistDocument::new(TypstIter(events.into_iter()));
+
+        self::assert_eq!(
+            i.collect::<Vec<super::ParserEvent>>(),
+            vec![
+                Typst(TypstEvent::Import("utils.typ".into(), vec!["*".into()])),
+                Typst(TypstEvent::Let("x".into(), "1".into())),
+                Typst(TypstEvent::DocumentSet("title".into(), "\"Book\"".into())),
+                Typst(TypstEvent::DocumentFunctionCall(vec![
+                    "author: \"me\"".into()
+                ])),
+                Typst(TypstEvent::Start(TypstTag::Paragraph)),
+                Typst(TypstEvent::Text("hello".into())),
+                Typst(TypstEvent::End(TypstTag::Paragraph)),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_lets_after_content_in_place() {
+        let events = vec![
+            TypstEvent::Let("x".into(), "1".into()),
+            TypstEvent::DocumentSet("title".into(), "\"Book\"".into()),
+            TypstEvent::Text("a".into()),
+            TypstEvent::Let("x".into(), "2".into()),
+            TypstEvent::Let("y".into(), "x".into()),
+            TypstEvent::Start(TypstTag::Paragraph),
+            TypstEvent::Let("z".into(), "3".into()),
+            TypstEvent::End(TypstTag::Paragraph),
+        ];
+        let i = HoistDocument::new(TypstIter(events.into_iter()));
+
+        self::assert_eq!(
+            i.collect::<Vec<super::ParserEvent>>(),
+            vec![
+                Typst(TypstEvent::Let("x".into(), "1".into())),
+                Typst(TypstEvent::DocumentSet("title".into(), "\"Book\"".into())),
+                Typst(TypstEvent::Text("a".into())),
+                Typst(TypstEvent::Let("x".into(), "2".into())),
+                Typst(TypstEvent::Let("y".into(), "x".into())),
+                Typst(TypstEvent::Start(TypstTag::Paragraph)),
+                Typst(TypstEvent::Let("z".into(), "3".into())),
+                Typst(TypstEvent::End(TypstTag::Paragraph)),
+            ]
+        );
+    }
+
+    #[test]
+    fn merges_duplicate_keys() {
+        let events = vec![
+            TypstEvent::DocumentSet("title".into(), "\"Book\"".into()),
+            TypstEvent::Text("hello".into()),
+            TypstEvent::DocumentSet("title".into(), "\"Book\"".into()),
+        ];
+        let mut i = HoistDocument::new(TypstIter(events.into_iter()));
+
+        self::assert_eq!(
+            i.by_ref().collect::<Vec<super::ParserEvent>>(),
+            vec![
+                Typst(TypstEvent::DocumentSet("title".into(), "\"Book\"".into())),
+                Typst(TypstEvent::Text("hello".into())),
+            ]
+        );
+        assert!(i.conflicts().is_empty());
+    }
+
+    #[test]
+    fn reports_conflicts() {
+        let events = vec![
+            TypstEvent::DocumentSet("title".into(), "\"First\"".into()),
+            TypstEvent::DocumentSet("author".into(), "\"me\"".into()),
+            TypstEvent::DocumentSet("title".into(), "\"Second\"".into()),
+        ];
+        let mut i = HoistDocument::new(TypstIter(events.into_iter()));
+
+        self::assert_eq!(
+            i.by_ref().collect::<Vec<super::ParserEvent>>(),
+            vec![
+                Typst(TypstEvent::DocumentSet("title".into(), "\"Second\"".into())),
+                Typst(TypstEvent::DocumentSet("author".into(), "\"me\"".into())),
+            ]
+        );
+        self::assert_eq!(
+            i.conflicts(),
+            &[DocumentSetConflict {
+                key: "title".into(),
+                kept: "\"Second\"".into(),
+                discarded: "\"First\"".into(),
+            }]
+        );
+    }
+}
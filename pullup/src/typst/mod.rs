@@ -1,12 +1,14 @@
 //! Support for [Typist](https://typst.app/docs).
 
 pub use pulldown_typst::{
-    Bookmarks, CodeBlockDisplay, Event, LinkType, NumberingPattern, QuoteQuotes, QuoteType,
-    ShowType, TableCellAlignment, TableOfContents, Tag,
+    Alignment, Bookmarks, CalloutKind, CodeBlockDisplay, CowStr, Direction, Event, Frame, Grid,
+    HorizontalAlignment, Length, LinkType, Numbering, NumberingPattern, QuoteQuotes, QuoteType,
+    ShowType, Sizing, TableCellAlignment, TableOfContents, Tag, VerticalAlignment,
 };
 
 use crate::ParserEvent;
 
+pub mod hoist;
 pub mod to;
 
 /// Assert that an iterator only contains Typst events. Panics if another type of event
@@ -0,0 +1,535 @@
+//! Iterator adaptor that checks a [`ParserEvent`] stream for structural errors.
+//!
+//! Custom converters can easily produce streams that later fail to render, for example
+//! by converting a `Start` event but not its `End`. [`Validate`] passes events through
+//! unchanged and records every [`Violation`] it sees along the way.
+
+use core::fmt;
+
+#[cfg(feature = "markdown")]
+use crate::markdown;
+#[cfg(feature = "mdbook")]
+use crate::mdbook;
+#[cfg(feature = "typst")]
+use crate::typst;
+use crate::ParserEvent;
+
+/// The markup format of a [`ParserEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[cfg(feature = "markdown")]
+    Markdown,
+    #[cfg(feature = "mdbook")]
+    Mdbook,
+    #[cfg(feature = "typst")]
+    Typst,
+}
+
+impl Format {
+    fn of(event: &ParserEvent<'_>) -> Option<Self> {
+        match event {
+            #[cfg(feature = "markdown")]
+            ParserEvent::Markdown(_) => Some(Format::Markdown),
+            #[cfg(feature = "mdbook")]
+            ParserEvent::Mdbook(_) => Some(Format::Mdbook),
+            #[cfg(feature = "typst")]
+            ParserEvent::Typst(_) => Some(Format::Typst),
+            #[cfg(not(any(feature = "markdown", feature = "mdbook", feature = "typst")))]
+            ParserEvent::NoFeaturesEnabled(_) => None,
+        }
+    }
+}
+
+/// A problem found in an event stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation<'a> {
+    /// The zero-based index of the offending event in the stream.
+    pub index: usize,
+    /// The offending event.
+    pub event: ParserEvent<'a>,
+    /// What is wrong with the event.
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for Violation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "event {}: ", self.index)?;
+        match self.kind {
+            ViolationKind::UnmatchedEnd => write!(f, "end without a start"),
+            ViolationKind::MismatchedEnd { start } => {
+                write!(f, "end does not match the start at event {start}")
+            }
+            ViolationKind::Unclosed => write!(f, "start is never ended"),
+            ViolationKind::ItemOutsideList => write!(f, "list item outside of a list"),
+            ViolationKind::TableCellOutsideRow => {
+                write!(f, "table cell outside of a table row or head")
+            }
+            ViolationKind::UnexpectedFormat => write!(f, "event format is not allowed"),
+            ViolationKind::DocumentAfterContent { content } => {
+                write!(f, "document event after content at event {content}")
+            }
+        }?;
+        write!(f, " ({:?})", self.event)
+    }
+}
+
+/// The kind of a [`Violation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// An `End` event without any open `Start`.
+    UnmatchedEnd,
+    /// An `End` event that does not match the innermost open `Start`. The field is the
+    /// index of that `Start`.
+    MismatchedEnd { start: usize },
+    /// A `Start` event that is never ended.
+    Unclosed,
+    /// A list item that is not directly inside a list.
+    ItemOutsideList,
+    /// A table cell that is not directly inside a table row or table head.
+    TableCellOutsideRow,
+    /// An event in a format not allowed by [`Validate::only`].
+    UnexpectedFormat,
+    /// A Typst document event after content. The field is the index of the first
+    /// content event.
+    DocumentAfterContent { content: usize },
+}
+
+/// Check an event stream as it flows through.
+///
+/// Events are passed through unchanged. Violations are collected and available via
+/// [`Validate::violations`]; unclosed tags are only known once the inner iterator is
+/// exhausted.
+///
+/// The following is checked:
+///
+/// * `Start` and `End` events are balanced.
+/// * List items are only directly inside lists.
+/// * Table cells are only directly inside table rows or table heads.
+/// * Typst `document` set rules and calls come before any content.
+/// * Only allowed formats appear, if restricted with [`Validate::only`].
+#[derive(Debug)]
+pub struct Validate<'a, T> {
+    index: usize,
+    open: Vec<(usize, ParserEvent<'a>)>,
+    content: Option<usize>,
+    allowed: Vec<Format>,
+    violations: Vec<Violation<'a>>,
+    iter: T,
+}
+
+impl<'a, T> Validate<'a, T>
+where
+    T: Iterator<Item = ParserEvent<'a>>,
+{
+    pub fn new(iter: T) -> Self {
+        Self {
+            index: 0,
+            open: vec![],
+            content: None,
+            allowed: vec![],
+            violations: vec![],
+            iter,
+        }
+    }
+
+    /// Only allow events of `format`. May be called multiple times to allow several
+    /// formats. By default all formats are allowed.
+    pub fn only(mut self, format: Format) -> Self {
+        self.allowed.push(format);
+        self
+    }
+
+    /// The violations found so far.
+    pub fn violations(&self) -> &[Violation<'a>] {
+        &self.violations
+    }
+
+    /// Consume the adaptor and return the violations found.
+    pub fn into_violations(self) -> Vec<Violation<'a>> {
+        self.violations
+    }
+
+    fn violation(&mut self, index: usize, event: &ParserEvent<'a>, kind: ViolationKind) {
+        #[cfg(feature = "tracing")]
+        tracing::warn!("violation at event {}: {:?}", index, kind);
+
+        self.violations.push(Violation {
+            index,
+            event: event.clone(),
+            kind,
+        });
+    }
+
+    fn check(&mut self, event: &ParserEvent<'a>) {
+        let index = self.index;
+
+        if !self.allowed.is_empty() {
+            if let Some(format) = Format::of(event) {
+                if !self.allowed.contains(&format) {
+                    self.violation(index, event, ViolationKind::UnexpectedFormat);
+                }
+            }
+        }
+
+        if is_document(event) {
+            if let Some(content) = self.content {
+                self.violation(
+                    index,
+                    event,
+                    ViolationKind::DocumentAfterContent { content },
+                );
+            }
+        } else if self.content.is_none() && is_content(event) {
+            self.content = Some(index);
+        }
+
+        match shape(event) {
+            Shape::Start(tag) => {
+                let (in_list, in_row) = match self.open.last().map(|(_, e)| shape(e)) {
+                    Some(Shape::Start(parent)) => (parent.is_list(), parent.is_row()),
+                    _ => (false, false),
+                };
+                if tag.is_item() && !in_list {
+                    self.violation(index, event, ViolationKind::ItemOutsideList);
+                }
+                if tag.is_cell() && !in_row {
+                    self.violation(index, event, ViolationKind::TableCellOutsideRow);
+                }
+                self.open.push((index, event.clone()));
+            }
+            Shape::End(tag) => {
+                let pos = self
+                    .open
+                    .iter()
+                    .rposition(|(_, e)| matches!(shape(e), Shape::Start(s) if s == tag));
+                match pos {
+                    Some(pos) if pos + 1 == self.open.len() => {
+                        self.open.pop();
+                    }
+                    Some(pos) => {
+                        let start = self.open.last().map(|(i, _)| *i).expect("open start");
+                        self.violation(index, event, ViolationKind::MismatchedEnd { start });
+                        self.open.truncate(pos);
+                    }
+                    None => self.violation(index, event, ViolationKind::UnmatchedEnd),
+                }
+            }
+            Shape::Other => {}
+        }
+
+        self.index += 1;
+    }
+}
+
+impl<'a, T> Iterator for Validate<'a, T>
+where
+    T: Iterator<Item = ParserEvent<'a>>,
+{
+    type Item = ParserEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(event) => {
+                self.check(&event);
+                Some(event)
+            }
+            None => {
+                for (index, event) in core::mem::take(&mut self.open) {
+                    self.violation(index, &event, ViolationKind::Unclosed);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// A borrowed tag of any format.
+#[derive(PartialEq)]
+pub(crate) enum TagRef<'e, 'a> {
+    #[cfg(feature = "markdown")]
+    Markdown(&'e markdown::Tag<'a>),
+    #[cfg(feature = "mdbook")]
+    Mdbook(&'e mdbook::Tag<'a>),
+    #[cfg(feature = "typst")]
+    Typst(&'e typst::Tag<'a>),
+    #[cfg(not(any(feature = "markdown", feature = "mdbook", feature = "typst")))]
+    #[allow(dead_code)]
+    None(core::marker::PhantomData<(&'e (), &'a ())>),
+}
+
+impl TagRef<'_, '_> {
+    fn is_list(&self) -> bool {
+        match self {
+            #[cfg(feature = "markdown")]
+            TagRef::Markdown(t) => matches!(t, markdown::Tag::List(_)),
+            #[cfg(feature = "typst")]
+            TagRef::Typst(t) => {
+                matches!(t, typst::Tag::BulletList(..) | typst::Tag::NumberedList(..))
+            }
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    fn is_item(&self) -> bool {
+        match self {
+            #[cfg(feature = "markdown")]
+            TagRef::Markdown(t) => matches!(t, markdown::Tag::Item),
+            #[cfg(feature = "typst")]
+            TagRef::Typst(t) => matches!(t, typst::Tag::Item),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    fn is_row(&self) -> bool {
+        match self {
+            #[cfg(feature = "markdown")]
+            TagRef::Markdown(t) => {
+                matches!(t, markdown::Tag::TableRow | markdown::Tag::TableHead)
+            }
+            #[cfg(feature = "typst")]
+            TagRef::Typst(t) => matches!(t, typst::Tag::TableRow | typst::Tag::TableHead),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    fn is_cell(&self) -> bool {
+        match self {
+            #[cfg(feature = "markdown")]
+            TagRef::Markdown(t) => matches!(t, markdown::Tag::TableCell),
+            #[cfg(feature = "typst")]
+            TagRef::Typst(t) => matches!(t, typst::Tag::TableCell),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+
+#[cfg_attr(
+    not(any(feature = "markdown", feature = "mdbook", feature = "typst")),
+    allow(dead_code)
+)]
+/// Whether an event starts an element, ends one, or neither.
+pub(crate) enum Shape<'e, 'a> {
+    Start(TagRef<'e, 'a>),
+    End(TagRef<'e, 'a>),
+    Other,
+}
+
+pub(crate) fn shape<'e, 'a>(event: &'e ParserEvent<'a>) -> Shape<'e, 'a> {
+    match event {
+        #[cfg(feature = "markdown")]
+        ParserEvent::Markdown(markdown::Event::Start(t)) => Shape::Start(TagRef::Markdown(t)),
+        #[cfg(feature = "markdown")]
+        ParserEvent::Markdown(markdown::Event::End(t)) => Shape::End(TagRef::Markdown(t)),
+        // Markdown embedded in mdBook is checked like plain Markdown.
+        #[cfg(feature = "mdbook")]
+        ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(markdown::Event::Start(t))) => {
+            Shape::Start(TagRef::Markdown(t))
+        }
+        #[cfg(feature = "mdbook")]
+        ParserEvent::Mdbook(mdbook::Event::MarkdownContentEvent(markdown::Event::End(t))) => {
+            Shape::End(TagRef::Markdown(t))
+        }
+        #[cfg(feature = "mdbook")]
+        ParserEvent::Mdbook(mdbook::Event::Start(t)) => Shape::Start(TagRef::Mdbook(t)),
+        #[cfg(feature = "mdbook")]
+        ParserEvent::Mdbook(mdbook::Event::End(t)) => Shape::End(TagRef::Mdbook(t)),
+        #[cfg(feature = "typst")]
+        ParserEvent::Typst(typst::Event::Start(t)) => Shape::Start(TagRef::Typst(t)),
+        #[cfg(feature = "typst")]
+        ParserEvent::Typst(typst::Event::End(t)) => Shape::End(TagRef::Typst(t)),
+        _ => Shape::Other,
+    }
+}
+
+/// Whether the event is a Typst document-level event.
+fn is_document(event: &ParserEvent<'_>) -> bool {
+    match event {
+        #[cfg(feature = "typst")]
+        ParserEvent::Typst(e) => matches!(
+            e,
+            typst::Event::DocumentSet(..) | typst::Event::DocumentFunctionCall(..)
+        ),
+        #[allow(unreachable_patterns)]
+        _ => false,
+    }
+}
+
+/// Whether the event produces document content. Configuration and styling events do
+/// not. Raw Typst is opaque and treated as content.
+fn is_content(event: &ParserEvent<'_>) -> bool {
+    match event {
+        #[cfg(feature = "markdown")]
+        ParserEvent::Markdown(_) => true,
+        #[cfg(feature = "mdbook")]
+        ParserEvent::Mdbook(e) => match e {
+            mdbook::Event::Start(t) | mdbook::Event::End(t) => {
+                !matches!(t, mdbook::Tag::AuthorList | mdbook::Tag::BookConfiguration)
+            }
+            mdbook::Event::Root(_)
+            | mdbook::Event::Title(_)
+            | mdbook::Event::Author(_)
+            | mdbook::Event::Description(_)
+            | mdbook::Event::Language(_)
+            | mdbook::Event::TextDirection(_)
+            | mdbook::Event::Multilingual
+            | mdbook::Event::Keywords(_)
+            | mdbook::Event::Date(_)
+            | mdbook::Event::RendererConfig(_) => false,
+            mdbook::Event::Separator | mdbook::Event::MarkdownContentEvent(_) => true,
+        },
+        #[cfg(feature = "typst")]
+        ParserEvent::Typst(e) => match e {
+            typst::Event::Start(t) | typst::Event::End(t) => !matches!(t, typst::Tag::Show(..)),
+            typst::Event::Import(..)
+            | typst::Event::Let(..)
+            | typst::Event::Set(..)
+            | typst::Event::DocumentSet(..)
+            | typst::Event::DocumentFunctionCall(..) => false,
+            _ => true,
+        },
+        #[allow(unreachable_patterns)]
+        _ => false,
+    }
+}
+
+#[cfg(all(test, feature = "typst"))]
+mod tests {
+    use super::*;
+    use crate::typst::TypstIter;
+    use similar_asserts::assert_eq;
+
+    use typst::Event as TypstEvent;
+    use typst::Tag as TypstTag;
+
+    fn validate(events: Vec<TypstEvent<'_>>) -> Vec<Violation<'_>> {
+        let mut v = Validate::new(TypstIter(events.into_iter()));
+        v.by_ref().for_each(drop);
+        v.into_violations()
+    }
+
+    #[test]
+    fn valid() {
+        let violations = validate(vec![
+            TypstEvent::DocumentSet("title".into(), "\"Book\"".into()),
+            TypstEvent::Start(TypstTag::BulletList(None, false)),
+            TypstEvent::Start(TypstTag::Item),
+            TypstEvent::Text("foo".into()),
+            TypstEvent::End(TypstTag::Item),
+            TypstEvent::End(TypstTag::BulletList(None, false)),
+        ]);
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn passes_events_through() {
+        let events = vec![
+            TypstEvent::Start(TypstTag::Paragraph),
+            TypstEvent::End(TypstTag::Strong),
+        ];
+        let i = Validate::new(TypstIter(events.clone().into_iter()));
+        self::assert_eq!(
+            i.collect::<Vec<_>>(),
+            TypstIter(events.into_iter()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn unbalanced() {
+        let violations = validate(vec![
+            TypstEvent::End(TypstTag::Strong),
+            TypstEvent::Start(TypstTag::Paragraph),
+            TypstEvent::Start(TypstTag::Emphasis),
+            TypstEvent::End(TypstTag::Paragraph),
+            TypstEvent::Start(TypstTag::Strong),
+        ]);
+        self::assert_eq!(
+            violations
+                .iter()
+                .map(|v| (v.index, v.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, ViolationKind::UnmatchedEnd),
+                (3, ViolationKind::MismatchedEnd { start: 2 }),
+                (4, ViolationKind::Unclosed),
+            ]
+        );
+    }
+
+    #[test]
+    fn item_outside_list() {
+        let violations = validate(vec![
+            TypstEvent::Start(TypstTag::Paragraph),
+            TypstEvent::Start(TypstTag::Item),
+            TypstEvent::End(TypstTag::Item),
+            TypstEvent::End(TypstTag::Paragraph),
+        ]);
+        self::assert_eq!(
+            violations
+                .iter()
+                .map(|v| (v.index, v.kind))
+                .collect::<Vec<_>>(),
+            vec![(1, ViolationKind::ItemOutsideList)]
+        );
+    }
+
+    #[test]
+    fn cell_outside_row() {
+        let violations = validate(vec![
+            TypstEvent::Start(TypstTag::Table(vec![])),
+            TypstEvent::Start(TypstTag::TableCell),
+            TypstEvent::End(TypstTag::TableCell),
+            TypstEvent::End(TypstTag::Table(vec![])),
+        ]);
+        self::assert_eq!(
+            violations
+                .iter()
+                .map(|v| (v.index, v.kind))
+                .collect::<Vec<_>>(),
+            vec![(1, ViolationKind::TableCellOutsideRow)]
+        );
+    }
+
+    #[test]
+    fn document_after_content() {
+        let violations = validate(vec![
+            TypstEvent::Let("x".into(), "1".into()),
+            TypstEvent::Text("foo".into()),
+            TypstEvent::DocumentSet("title".into(), "\"Book\"".into()),
+        ]);
+        self::assert_eq!(
+            violations
+                .iter()
+                .map(|v| (v.index, v.kind))
+                .collect::<Vec<_>>(),
+            vec![(2, ViolationKind::DocumentAfterContent { content: 1 })]
+        );
+        self::assert_eq!(
+            violations[0].to_string(),
+            "event 2: document event after content at event 1 (Typst(DocumentSet(Borrowed(\"title\"), Borrowed(\"\\\"Book\\\"\"))))"
+        );
+    }
+
+    #[cfg(feature = "markdown")]
+    #[test]
+    fn unexpected_format() {
+        use crate::markdown::{MarkdownIter, Parser};
+
+        let mut v = Validate::new(MarkdownIter(Parser::new("foo"))).only(Format::Typst);
+        v.by_ref().for_each(drop);
+        self::assert_eq!(
+            v.violations()
+                .iter()
+                .map(|v| (v.index, v.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, ViolationKind::UnexpectedFormat),
+                (1, ViolationKind::UnexpectedFormat),
+                (2, ViolationKind::UnexpectedFormat),
+            ]
+        );
+    }
+}